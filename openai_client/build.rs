@@ -1,12 +1,54 @@
 use std::{
+    collections::{BTreeSet, HashMap},
     fs::{File, read_to_string},
     io::Write,
+    sync::OnceLock,
 };
 
 use yaml_rust::{Yaml, YamlLoader};
 
 const OPENAI_YML_FILE_PATH: &str = "./openapi.documented.yml";
 
+// Names of the component schemas which are declared as nullable. Fields of the stream objects
+// referencing them have to be optional even if they are marked as required.
+static NULLABLE_SCHEMAS: OnceLock<Vec<String>> = OnceLock::new();
+
+// The objects sent by the assistant streams. Their required fields referencing a nullable
+// schema, such as the `usage` of a queued run, are `null` until the run gets that far, so the
+// events could not be decoded with those fields required. The same objects are returned by the
+// non-streaming operations, which makes their fields optional there too. Other schemas keep
+// such fields required: the API fills them in, e.g. `metadata` is `{}` when empty, and making
+// them optional would change public types nothing needs changed.
+const STREAM_OBJECTS: &[&str] = &[
+    "MessageObject",
+    "RunObject",
+    "RunStepObject",
+    "ThreadObject",
+];
+
+// The cargo features which gate the API groups, with the `x-oaiMeta` groups of the operations
+// they contain. A group also matches the groups starting with its name and a dash, for example
+// `usage` matches `usage-completions`. Operations of the other groups are always available.
+const API_FEATURES: &[(&str, &[&str])] = &[
+    ("administration", &["administration", "audit-logs", "usage"]),
+    ("assistants", &["assistants", "threads"]),
+    ("audio", &["audio"]),
+    ("evals", &["evals"]),
+    ("fine-tuning", &["fine-tuning", "graders"]),
+    ("images", &["images"]),
+    ("realtime", &["realtime"]),
+    ("vector-stores", &["vector_stores"]),
+];
+
+// Schemas which are not referenced by any operation, matched by name prefix. They are used by
+// the hand-written modules or only make sense together with a feature.
+const EXTRA_SCHEMA_ROOTS: &[(&str, Option<&str>)] = &[
+    ("Error", None),
+    ("AssistantStreamEvent", Some("assistants")),
+    ("FineTune", Some("fine-tuning")),
+    ("Realtime", Some("realtime")),
+];
+
 fn camel_to_snake(s: &str) -> String {
     let mut snake = String::new();
 
@@ -274,11 +316,7 @@ fn parse_object_type(name: &str, schema: &Yaml, output_file: &mut File) {
                         } else if property_hash.get(&Yaml::String("format".to_string()))
                             == Some(&Yaml::String("binary".to_string()))
                         {
-                            if field_name == "file" {
-                                "crate::multipart::File".to_string()
-                            } else {
-                                "Vec<u8>".to_string()
-                            }
+                            "crate::multipart::File".to_string()
                         } else {
                             "String".to_string()
                         }
@@ -366,6 +404,22 @@ fn parse_object_type(name: &str, schema: &Yaml, output_file: &mut File) {
                 property_hash.get(&Yaml::String("nullable".to_string()))
             {
                 *n
+            } else if STREAM_OBJECTS.contains(&name)
+                && let Some(Yaml::Array(all_of_list)) =
+                    property_hash.get(&Yaml::String("allOf".to_string()))
+            {
+                all_of_list
+                    .iter()
+                    .any(|i| i["nullable"].as_bool() == Some(true))
+            } else if STREAM_OBJECTS.contains(&name)
+                && let Some(Yaml::String(field_ref)) =
+                    property_hash.get(&Yaml::String("$ref".to_string()))
+            {
+                NULLABLE_SCHEMAS
+                    .get()
+                    .unwrap()
+                    .iter()
+                    .any(|s| s == get_object_name_from_reference(field_ref))
             } else {
                 false
             };
@@ -430,6 +484,58 @@ fn parse_object_type(name: &str, schema: &Yaml, output_file: &mut File) {
     }
 }
 
+/// The event names, data types and descriptions of a union of events with an `event` name and
+/// `data`, e.g. the events of a run stream.
+fn get_named_events(one_of_list: &[Yaml]) -> Option<Vec<(&str, &str, Option<&str>)>> {
+    one_of_list
+        .iter()
+        .map(|variant| {
+            let event_name = match variant["properties"]["event"]["enum"].as_vec()?.as_slice() {
+                [Yaml::String(event_name)] => event_name.as_str(),
+                _ => return None,
+            };
+            let data_type =
+                get_object_name_from_reference(variant["properties"]["data"]["$ref"].as_str()?);
+            Some((event_name, data_type, variant["description"].as_str()))
+        })
+        .collect()
+}
+
+fn parse_named_event_type(
+    name: &str,
+    schema_map: &yaml_rust::yaml::Hash,
+    events: &[(&str, &str, Option<&str>)],
+    output_file: &mut File,
+) {
+    if let Some(doc) = schema_map
+        .get(&Yaml::String("description".to_string()))
+        .map(|x| x.as_str().unwrap().trim_end().replace("```", "***"))
+    {
+        writeln!(output_file, "/** {doc} */",).unwrap();
+    }
+
+    writeln!(
+        output_file,
+        "#[derive(Debug, PartialEq, Serialize, Deserialize)]"
+    )
+    .unwrap();
+    writeln!(output_file, "#[serde(tag = \"event\", content = \"data\")]").unwrap();
+    writeln!(output_file, "pub enum {name} {{",).unwrap();
+    for (event_name, data_type, doc) in events {
+        if let Some(doc) = doc {
+            writeln!(output_file, "\t/** {} */", doc.trim_end()).unwrap();
+        }
+        writeln!(output_file, "\t#[serde(rename = \"{event_name}\")]").unwrap();
+        writeln!(
+            output_file,
+            "\t{}({data_type}),",
+            str_to_camel_case(event_name)
+        )
+        .unwrap();
+    }
+    writeln!(output_file, "}}\n").unwrap();
+}
+
 fn parse_oneof_type(name: &str, schema: &Yaml, output_file: &mut File) {
     let schema_map = schema.as_hash().unwrap();
     let one_of_list = schema_map
@@ -439,6 +545,12 @@ fn parse_oneof_type(name: &str, schema: &Yaml, output_file: &mut File) {
         .as_vec()
         .unwrap();
 
+    // Events told apart by their name are tagged with it instead of guessed from their data
+    if let Some(events) = get_named_events(one_of_list) {
+        parse_named_event_type(name, schema_map, &events, output_file);
+        return;
+    }
+
     // First iterate through the variants to create the inner object types if needed
     for one_of_variant in one_of_list {
         let one_of_variant_hash = one_of_variant.as_hash().unwrap();
@@ -539,6 +651,12 @@ fn parse_oneof_type(name: &str, schema: &Yaml, output_file: &mut File) {
             one_of_variant_hash.get(&Yaml::String("type".to_string()))
         {
             match variant_type.as_str() {
+                "string"
+                    if one_of_variant_hash.get(&Yaml::String("format".to_string()))
+                        == Some(&Yaml::String("binary".to_string())) =>
+                {
+                    writeln!(output_file, "\tFile(crate::multipart::File),").unwrap();
+                }
                 "string" => {
                     if !string_enum_already_processed {
                         writeln!(output_file, "\tString(String),").unwrap();
@@ -649,6 +767,13 @@ fn parse_oneof_type(name: &str, schema: &Yaml, output_file: &mut File) {
                         items_hash.get(&Yaml::String("type".to_string()))
                     {
                         match item_type.as_str() {
+                            "string"
+                                if items_hash.get(&Yaml::String("format".to_string()))
+                                    == Some(&Yaml::String("binary".to_string())) =>
+                            {
+                                writeln!(output_file, "\tArrayFile(Vec<crate::multipart::File>),")
+                                    .unwrap()
+                            }
                             "string" => {
                                 writeln!(output_file, "\tArrayString(Vec<String>),").unwrap()
                             }
@@ -732,6 +857,16 @@ fn parse_allof_type(name: &str, schema: &Yaml, output_file: &mut File) {
         "#[derive(Debug, PartialEq, Serialize, Deserialize)]"
     )
     .unwrap();
+    // A wrapper of a single reference must also accept values which are not objects, so it
+    // can not be flattened
+    let field_items: Vec<_> = all_of_list
+        .iter()
+        .filter(|i| !i["$ref"].is_badvalue() || !i["type"].is_badvalue())
+        .collect();
+    let is_single_reference = field_items.len() == 1 && !field_items[0]["$ref"].is_badvalue();
+    if is_single_reference {
+        writeln!(output_file, "#[serde(transparent)]").unwrap();
+    }
     writeln!(output_file, "pub struct {name} {{",).unwrap();
 
     for all_of_item in all_of_list {
@@ -739,7 +874,9 @@ fn parse_allof_type(name: &str, schema: &Yaml, output_file: &mut File) {
         if let Some(item_ref) = all_of_item_hash.get(&Yaml::String("$ref".to_string())) {
             let item_name = get_object_name_from_reference(item_ref.as_str().unwrap());
             let field_name = camel_to_snake(item_name);
-            writeln!(output_file, "\t#[serde(flatten)]").unwrap();
+            if !is_single_reference {
+                writeln!(output_file, "\t#[serde(flatten)]").unwrap();
+            }
             writeln!(output_file, "\tpub {field_name}: {item_name},",).unwrap();
         } else if let Some(Yaml::String(variant_type)) =
             all_of_item_hash.get(&Yaml::String("type".to_string()))
@@ -790,10 +927,286 @@ fn parse_component_schema(schema_name: &str, schema_value: &Yaml, output_file: &
     }
 }
 
-fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
+fn get_event_stream_schema_names(path_schema: &Yaml) -> Vec<String> {
+    let mut event_stream_schema_names = Vec::new();
+    for (_, path_hash) in path_schema.as_hash().unwrap() {
+        for (_, path_operation_hash) in path_hash.as_hash().unwrap() {
+            for (_, response) in path_operation_hash["responses"].as_hash().unwrap() {
+                if let Some(schema_ref) =
+                    response["content"]["text/event-stream"]["schema"]["$ref"].as_str()
+                {
+                    event_stream_schema_names
+                        .push(get_object_name_from_reference(schema_ref).to_string());
+                }
+            }
+        }
+    }
+    event_stream_schema_names
+}
+
+fn parse_stream_event_type(
+    name: &str,
+    features: &[&str],
+    schema: &Yaml,
+    schema_list: &Yaml,
+    output_file: &mut File,
+) {
+    let Some(event_list) = schema["oneOf"].as_vec().or(schema["anyOf"].as_vec()) else {
+        // Events which are not a union of other objects don't need to be told apart
+        return;
+    };
+
+    if !features.is_empty() {
+        writeln!(output_file, "{}", feature_cfg(features)).unwrap();
+    }
+    writeln!(output_file, "impl {name} {{").unwrap();
+    writeln!(
+        output_file,
+        "\t/** Decodes the event data using the event type to select the variant. */"
+    )
+    .unwrap();
+    writeln!(
+        output_file,
+        "\tpub fn from_event_type(event_type: &str, data: &str) -> serde_json::Result<Self> {{"
+    )
+    .unwrap();
+    writeln!(output_file, "\t\tmatch event_type {{").unwrap();
+    for event in event_list {
+        let variant_name = get_object_name_from_reference(event["$ref"].as_str().unwrap());
+        let event_type = schema_list[variant_name]["properties"]["type"]["enum"][0]
+            .as_str()
+            .unwrap();
+        writeln!(
+            output_file,
+            "\t\t\t\"{event_type}\" => Ok(Self::{variant_name}(serde_json::from_str(data)?)),"
+        )
+        .unwrap();
+    }
+    writeln!(output_file, "\t\t\t_ => serde_json::from_str(data),").unwrap();
+    writeln!(output_file, "\t\t}}").unwrap();
+    writeln!(output_file, "\t}}").unwrap();
+    writeln!(output_file, "}}\n").unwrap();
+}
+
+fn yaml_contains_str(yaml: &Yaml, pattern: &str) -> bool {
+    match yaml {
+        Yaml::String(s) => s.contains(pattern),
+        Yaml::Array(a) => a.iter().any(|y| yaml_contains_str(y, pattern)),
+        Yaml::Hash(h) => h.values().any(|y| yaml_contains_str(y, pattern)),
+        _ => false,
+    }
+}
+
+// The beta operations of the Assistants API need the `OpenAI-Beta` header. Most are marked as
+// beta in the metadata, but the vector store operations are only recognizable by the header
+// in their request examples.
+// The `x-oaiMeta` groups whose beta operations need the `OpenAI-Beta` header. Other groups, such
+// as fine-tuning, mark operations as beta without using the header.
+const OPENAI_BETA_GROUPS: &[&str] = &["assistants", "threads", "vector_stores"];
+
+fn is_beta_operation(path_operation_hash: &Yaml) -> bool {
+    let meta = &path_operation_hash["x-oaiMeta"];
+    let in_beta_group = meta["group"]
+        .as_str()
+        .is_some_and(|group| OPENAI_BETA_GROUPS.contains(&group));
+    in_beta_group
+        && (meta["beta"].as_bool() == Some(true)
+            || yaml_contains_str(&meta["examples"], "OpenAI-Beta"))
+}
+
+fn is_success_status_code(status_code: &str) -> bool {
+    status_code.starts_with('2')
+}
+
+// Every 2xx status code declared for an operation is a success. Operations declaring more than
+// one of them are decoded with the first one which has content, the others have no body.
+fn success_response(responses_hash: &yaml_rust::yaml::Hash) -> &Yaml {
+    let mut success_responses = responses_hash
+        .iter()
+        .filter(|(code, _)| is_success_status_code(code.as_str().unwrap()))
+        .map(|(_, response)| response);
+    let first = success_responses.clone().next();
+    success_responses
+        .find(|response| !response["content"].is_badvalue())
+        .or(first)
+        .unwrap_or_else(|| unimplemented!("{:?}", responses_hash))
+}
+
+// The schema of an error response, which has to be a reference to a component schema so that it
+// can be a variant of `ApiErrorBody`.
+fn error_response_schema(response: &Yaml) -> Option<&str> {
+    let content_hash = response["content"].as_hash()?;
+    let (_, content) = content_hash.front()?;
+    content["schema"]["$ref"]
+        .as_str()
+        .map(get_object_name_from_reference)
+}
+
+fn parse_error_body_type(schema_list: &yaml_rust::yaml::Hash, client_output_file: &mut File) {
+    let mut error_schemas: Vec<&str> = Vec::new();
+    for (_, path_hash) in schema_list {
+        for (_, path_operation_hash) in path_hash.as_hash().unwrap() {
+            for (status_code, response) in path_operation_hash["responses"].as_hash().unwrap() {
+                if is_success_status_code(status_code.as_str().unwrap()) {
+                    continue;
+                }
+                if let Some(schema) = error_response_schema(response)
+                    && !error_schemas.contains(&schema)
+                {
+                    error_schemas.push(schema);
+                }
+            }
+        }
+    }
+
+    writeln!(
+        client_output_file,
+        "/** The body of an error response, decoded with the schema the operation declares for the status code */"
+    )
+    .unwrap();
+    writeln!(client_output_file, "#[derive(Debug, PartialEq)]").unwrap();
+    writeln!(client_output_file, "pub enum ApiErrorBody {{").unwrap();
+    for schema in error_schemas {
+        writeln!(client_output_file, "\t{schema}(crate::types::{schema}),").unwrap();
+    }
+    writeln!(client_output_file, "}}\n").unwrap();
+}
+
+fn operation_feature(path_operation_hash: &Yaml) -> Option<&'static str> {
+    let group = path_operation_hash["x-oaiMeta"]["group"].as_str()?;
+    API_FEATURES
+        .iter()
+        .find(|(_, groups)| {
+            groups.iter().any(|g| {
+                group == *g
+                    || group
+                        .strip_prefix(g)
+                        .is_some_and(|rest| rest.starts_with('-'))
+            })
+        })
+        .map(|(feature, _)| *feature)
+}
+
+fn collect_schema_references<'a>(yaml: &'a Yaml, references: &mut Vec<&'a str>) {
+    match yaml {
+        Yaml::Hash(h) => {
+            for (key, value) in h {
+                match (key.as_str(), value.as_str()) {
+                    (Some("$ref"), Some(reference)) => {
+                        references.push(get_object_name_from_reference(reference))
+                    }
+                    _ => collect_schema_references(value, references),
+                }
+            }
+        }
+        Yaml::Array(a) => a
+            .iter()
+            .for_each(|y| collect_schema_references(y, references)),
+        _ => (),
+    }
+}
+
+fn add_schema_users<'a>(
+    users: &mut HashMap<&'a str, BTreeSet<Option<&'static str>>>,
+    schema_list: &'a Yaml,
+    feature: Option<&'static str>,
+    mut pending: Vec<&'a str>,
+) {
+    while let Some(name) = pending.pop() {
+        if users.entry(name).or_default().insert(feature) {
+            collect_schema_references(&schema_list[name], &mut pending);
+        }
+    }
+}
+
+// Finds the features needed by each schema: the features of all the operations which use the
+// schema directly or through other schemas. Schemas used by any operation which is always
+// available, or by none, are not gated and get an empty list.
+fn schema_features<'a>(
+    schema_list: &'a Yaml,
+    path_schema: &'a Yaml,
+) -> HashMap<&'a str, Vec<&'static str>> {
+    let mut roots: Vec<(Option<&'static str>, Vec<&str>)> = Vec::new();
+    for (_, path_hash) in path_schema.as_hash().unwrap() {
+        for (_, path_operation_hash) in path_hash.as_hash().unwrap() {
+            let mut references = Vec::new();
+            collect_schema_references(path_operation_hash, &mut references);
+            roots.push((operation_feature(path_operation_hash), references));
+        }
+    }
+    for (name, _) in schema_list.as_hash().unwrap() {
+        let name = name.as_str().unwrap();
+        for (prefix, feature) in EXTRA_SCHEMA_ROOTS {
+            if name.starts_with(prefix) {
+                roots.push((*feature, vec![name]));
+            }
+        }
+    }
+
+    let mut users = HashMap::new();
+    for (feature, references) in roots {
+        add_schema_users(&mut users, schema_list, feature, references);
+    }
+    // The schemas which are still unused are always available, so are the schemas they use
+    for (name, _) in schema_list.as_hash().unwrap() {
+        let name = name.as_str().unwrap();
+        if !users.contains_key(name) {
+            add_schema_users(&mut users, schema_list, None, vec![name]);
+        }
+    }
+
+    users
+        .into_iter()
+        .map(|(name, features)| {
+            if features.contains(&None) {
+                (name, Vec::new())
+            } else {
+                (name, features.into_iter().flatten().collect())
+            }
+        })
+        .collect()
+}
+
+fn feature_cfg(features: &[&str]) -> String {
+    let features = features
+        .iter()
+        .map(|f| format!("feature = \"{f}\""))
+        .collect::<Vec<_>>();
+    if features.len() == 1 {
+        format!("#[cfg({})]", features[0])
+    } else {
+        format!("#[cfg(any({}))]", features.join(", "))
+    }
+}
+
+fn feature_module_name(features: &[&str]) -> String {
+    features.join("_").replace('-', "_")
+}
+
+// The gated items are generated in a private module per combination of features, which is
+// re-exported so that the items keep their path.
+fn open_feature_module(features: &[&str], output_file: &mut File) {
+    writeln!(output_file, "{}", feature_cfg(features)).unwrap();
+    writeln!(output_file, "mod {} {{", feature_module_name(features)).unwrap();
+    writeln!(output_file, "use super::*;\n").unwrap();
+}
+
+fn close_feature_module(features: &[&str], output_file: &mut File) {
+    writeln!(output_file, "}}").unwrap();
+    writeln!(output_file, "{}", feature_cfg(features)).unwrap();
+    writeln!(output_file, "#[allow(unused_imports)]").unwrap();
+    writeln!(
+        output_file,
+        "pub use {}::*;\n",
+        feature_module_name(features)
+    )
+    .unwrap();
+}
+
+fn parse_paths(path_schema: &Yaml, client_output_file: &mut File) {
     writeln!(
         client_output_file,
-        "use crate::{{ConversaError, ConversaResult, OpenAIClient}};"
+        "use crate::{{ApiError, ConversaResult, OpenAIClient, meta::{{ResponseMeta, WithMeta}}, transport::{{HttpRequest, Operation}}}};"
     )
     .unwrap();
     writeln!(client_output_file, "use crate::types::*;").unwrap();
@@ -804,12 +1217,26 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
     )
     .unwrap();
 
+    parse_error_body_type(path_schema.as_hash().unwrap(), client_output_file);
+
+    parse_endpoint_path(path_schema, None, client_output_file);
+    for (feature, _) in API_FEATURES {
+        open_feature_module(&[feature], client_output_file);
+        parse_endpoint_path(path_schema, Some(feature), client_output_file);
+        close_feature_module(&[feature], client_output_file);
+    }
+}
+
+fn parse_endpoint_path(path_schema: &Yaml, feature: Option<&str>, client_output_file: &mut File) {
     let schema_list = path_schema.as_hash().unwrap();
 
     // Before implementing the functions the additional types need to be defined
     for (_, path_hash) in schema_list {
         let path_operations = path_hash.as_hash().unwrap();
         for (_, path_operation_hash) in path_operations {
+            if operation_feature(path_operation_hash) != feature {
+                continue;
+            }
             let operation_name =
                 str_to_snake_case(path_operation_hash["operationId"].as_str().unwrap());
 
@@ -822,14 +1249,13 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
                     let parameter_schema = parameter["schema"].as_hash().unwrap();
                     if let Some(Yaml::String(schema_type)) =
                         parameter_schema.get(&Yaml::String("type".to_string()))
+                        && schema_type == "object"
                     {
-                        if schema_type == "object" {
-                            parse_object_type(
-                                &str_to_camel_case(&format!("{operation_name}_query")),
-                                &parameter["schema"],
-                                client_output_file,
-                            );
-                        }
+                        parse_object_type(
+                            &str_to_camel_case(&format!("{operation_name}_query")),
+                            &parameter["schema"],
+                            client_output_file,
+                        );
                     }
                 }
             }
@@ -856,121 +1282,12 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
             }
 
             let responses_hash = path_operation_hash["responses"].as_hash().unwrap();
-            if let Some(ok_response) = responses_hash.get(&Yaml::String("200".to_string())) {
-                if let Some(Yaml::Hash(response_content_hash)) = ok_response
-                    .as_hash()
-                    .unwrap()
-                    .get(&Yaml::String("content".to_string()))
-                {
-                    if response_content_hash.len() == 1 {
-                        let response_schema_hash =
-                            &response_content_hash.front().unwrap().1["schema"];
-                        if response_schema_hash
-                            .as_hash()
-                            .unwrap()
-                            .get(&Yaml::String("oneOf".to_string()))
-                            .is_some()
-                        {
-                            parse_oneof_type(
-                                &str_to_camel_case(&format!("{operation_name}_response")),
-                                response_schema_hash,
-                                client_output_file,
-                            );
-                        } else if response_schema_hash
-                            .as_hash()
-                            .unwrap()
-                            .get(&Yaml::String("type".to_string()))
-                            == Some(&Yaml::String("object".to_string()))
-                        {
-                            parse_object_type(
-                                &str_to_camel_case(&format!("{operation_name}_response")),
-                                response_schema_hash,
-                                client_output_file,
-                            );
-                        }
-                    } else {
-                        writeln!(
-                            client_output_file,
-                            "#[derive(Debug, PartialEq, Serialize, Deserialize)]"
-                        )
-                        .unwrap();
-
-                        writeln!(
-                            client_output_file,
-                            "pub enum {} {{",
-                            str_to_camel_case(&format!("{operation_name}_response"))
-                        )
-                        .unwrap();
-                        for (response_name, response_hash) in response_content_hash {
-                            let response_variant_hash = response_hash["schema"].as_hash().unwrap();
-
-                            let response_variant_type = if let Some(Yaml::String(response_type)) =
-                                response_variant_hash.get(&Yaml::String("type".to_string()))
-                            {
-                                if response_type == "string" {
-                                    let response_string_format = response_variant_hash
-                                        .get(&Yaml::String("format".to_string()))
-                                        .unwrap()
-                                        .as_str()
-                                        .unwrap();
-                                    if response_string_format == "binary" {
-                                        "Vec<u8>"
-                                    } else {
-                                        unimplemented!()
-                                    }
-                                } else {
-                                    unimplemented!()
-                                }
-                            } else if let Some(Yaml::String(response_ref)) =
-                                response_variant_hash.get(&Yaml::String("$ref".to_string()))
-                            {
-                                &format!(
-                                    "crate::types::{}",
-                                    get_object_name_from_reference(response_ref)
-                                )
-                            } else if let Some(Yaml::Array(response_oneof)) =
-                                response_variant_hash.get(&Yaml::String("oneOf".to_string()))
-                            {
-                                // HACK: This is fixed to a size of 2 and only works for the specific
-                                // case where it is currently used.
-                                assert_eq!(response_oneof.len(), 2);
-                                writeln!(
-                                    client_output_file,
-                                    "\t{}(crate::types::{}),",
-                                    str_to_camel_case(response_name.as_str().unwrap()),
-                                    get_object_name_from_reference(
-                                        response_oneof[0]["$ref"].as_str().unwrap()
-                                    )
-                                )
-                                .unwrap();
-                                writeln!(
-                                    client_output_file,
-                                    "\t{}Verbose(crate::types::{}),",
-                                    str_to_camel_case(response_name.as_str().unwrap()),
-                                    get_object_name_from_reference(
-                                        response_oneof[1]["$ref"].as_str().unwrap()
-                                    )
-                                )
-                                .unwrap();
-                                continue;
-                            } else {
-                                unimplemented!()
-                            };
-                            writeln!(
-                                client_output_file,
-                                "\t{}({}),",
-                                str_to_camel_case(response_name.as_str().unwrap()),
-                                response_variant_type,
-                            )
-                            .unwrap();
-                        }
-                        writeln!(client_output_file, "}}",).unwrap();
-                    }
-                }
-            } else if let Some(created_response) =
-                responses_hash.get(&Yaml::String("201".to_string()))
+            let ok_response = success_response(responses_hash);
+            if let Some(Yaml::Hash(response_content_hash)) = ok_response
+                .as_hash()
+                .unwrap()
+                .get(&Yaml::String("content".to_string()))
             {
-                let response_content_hash = created_response["content"].as_hash().unwrap();
                 if response_content_hash.len() == 1 {
                     let response_schema_hash = &response_content_hash.front().unwrap().1["schema"];
                     if response_schema_hash
@@ -987,8 +1304,8 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
                     } else if response_schema_hash
                         .as_hash()
                         .unwrap()
-                        .get(&Yaml::String("object".to_string()))
-                        .is_some()
+                        .get(&Yaml::String("type".to_string()))
+                        == Some(&Yaml::String("object".to_string()))
                     {
                         parse_object_type(
                             &str_to_camel_case(&format!("{operation_name}_response")),
@@ -997,7 +1314,82 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
                         );
                     }
                 } else {
-                    unimplemented!()
+                    writeln!(
+                        client_output_file,
+                        "#[derive(Debug, PartialEq, Serialize, Deserialize)]"
+                    )
+                    .unwrap();
+
+                    writeln!(
+                        client_output_file,
+                        "pub enum {} {{",
+                        str_to_camel_case(&format!("{operation_name}_response"))
+                    )
+                    .unwrap();
+                    for (response_name, response_hash) in response_content_hash {
+                        let response_variant_hash = response_hash["schema"].as_hash().unwrap();
+
+                        let response_variant_type = if let Some(Yaml::String(response_type)) =
+                            response_variant_hash.get(&Yaml::String("type".to_string()))
+                        {
+                            if response_type == "string" {
+                                let response_string_format = response_variant_hash
+                                    .get(&Yaml::String("format".to_string()))
+                                    .unwrap()
+                                    .as_str()
+                                    .unwrap();
+                                if response_string_format == "binary" {
+                                    "Vec<u8>"
+                                } else {
+                                    unimplemented!()
+                                }
+                            } else {
+                                unimplemented!()
+                            }
+                        } else if let Some(Yaml::String(response_ref)) =
+                            response_variant_hash.get(&Yaml::String("$ref".to_string()))
+                        {
+                            &format!(
+                                "crate::types::{}",
+                                get_object_name_from_reference(response_ref)
+                            )
+                        } else if let Some(Yaml::Array(response_oneof)) =
+                            response_variant_hash.get(&Yaml::String("oneOf".to_string()))
+                        {
+                            // HACK: This is fixed to a size of 2 and only works for the specific
+                            // case where it is currently used.
+                            assert_eq!(response_oneof.len(), 2);
+                            writeln!(
+                                client_output_file,
+                                "\t{}(crate::types::{}),",
+                                str_to_camel_case(response_name.as_str().unwrap()),
+                                get_object_name_from_reference(
+                                    response_oneof[0]["$ref"].as_str().unwrap()
+                                )
+                            )
+                            .unwrap();
+                            writeln!(
+                                client_output_file,
+                                "\t{}Verbose(crate::types::{}),",
+                                str_to_camel_case(response_name.as_str().unwrap()),
+                                get_object_name_from_reference(
+                                    response_oneof[1]["$ref"].as_str().unwrap()
+                                )
+                            )
+                            .unwrap();
+                            continue;
+                        } else {
+                            unimplemented!()
+                        };
+                        writeln!(
+                            client_output_file,
+                            "\t{}({}),",
+                            str_to_camel_case(response_name.as_str().unwrap()),
+                            response_variant_type,
+                        )
+                        .unwrap();
+                    }
+                    writeln!(client_output_file, "}}",).unwrap();
                 }
             }
         }
@@ -1010,25 +1402,20 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
     for (path_name, path_hash) in schema_list {
         let path_operations = path_hash.as_hash().unwrap();
         for (path_operation_name, path_operation_hash) in path_operations {
+            if operation_feature(path_operation_hash) != feature {
+                continue;
+            }
             let operation_name =
                 str_to_snake_case(path_operation_hash["operationId"].as_str().unwrap());
-            if let Some(d) = path_operation_hash
+            let summary = path_operation_hash
                 .as_hash()
                 .unwrap()
                 .get(&Yaml::String("summary".to_string()))
-            {
-                writeln!(
-                    client_output_file,
-                    "\t/** {} */",
-                    d.as_str().unwrap().trim_end()
-                )
-                .unwrap();
-            }
-            write!(
-                client_output_file,
-                "\tpub async fn {operation_name}(&self, ",
-            )
-            .unwrap();
+                .map(|d| d.as_str().unwrap().trim_end());
+            // The parameters are collected to write both the plain method and the one which
+            // also returns the response metadata
+            let mut parameters = String::new();
+            let mut arguments = String::new();
 
             if let Some(parameters_list) = path_operation_hash
                 .as_hash()
@@ -1088,12 +1475,9 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
                         parameter_type = format!("Option<{parameter_type}>",);
                     }
 
-                    write!(
-                        client_output_file,
-                        "{}: {parameter_type}, ",
-                        parameter["name"].as_str().unwrap().replace("[]", ""),
-                    )
-                    .unwrap();
+                    let parameter_name = parameter["name"].as_str().unwrap().replace("[]", "");
+                    parameters.push_str(&format!("{parameter_name}: {parameter_type}, "));
+                    arguments.push_str(&format!("{parameter_name}, "));
                 }
             }
 
@@ -1125,59 +1509,21 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
                 };
                 let request_body_is_required =
                     request_body_hash["required"].as_bool().unwrap_or(false);
-                write!(client_output_file, "request_body: ").unwrap();
                 if request_body_is_required {
-                    write!(client_output_file, "{request_body_type}, ",).unwrap();
+                    parameters.push_str(&format!("request_body: {request_body_type}, "));
                 } else {
-                    write!(client_output_file, "Option<{request_body_type}>, ",).unwrap();
+                    parameters.push_str(&format!("request_body: Option<{request_body_type}>, "));
                 }
+                arguments.push_str("request_body, ");
             }
 
             let responses_hash = path_operation_hash["responses"].as_hash().unwrap();
-            let result_type = if let Some(ok_response) =
-                responses_hash.get(&Yaml::String("200".to_string()))
-            {
-                if let Some(Yaml::Hash(response_content_hash)) = ok_response
-                    .as_hash()
-                    .unwrap()
-                    .get(&Yaml::String("content".to_string()))
-                {
-                    if response_content_hash.len() == 1 {
-                        let response_schema_hash =
-                            response_content_hash.front().unwrap().1["schema"]
-                                .as_hash()
-                                .unwrap();
-                        if let Some(Yaml::String(response_ref)) =
-                            response_schema_hash.get(&Yaml::String("$ref".to_string()))
-                        {
-                            format!(
-                                "crate::types::{}",
-                                get_object_name_from_reference(response_ref.as_str())
-                            )
-                        } else if response_schema_hash
-                            .get(&Yaml::String("oneOf".to_string()))
-                            .is_some()
-                            || response_schema_hash.get(&Yaml::String("type".to_string()))
-                                == Some(&Yaml::String("object".to_string()))
-                        {
-                            str_to_camel_case(&format!("{operation_name}_response"))
-                        } else if response_schema_hash.get(&Yaml::String("type".to_string()))
-                            == Some(&Yaml::String("string".to_string()))
-                        {
-                            "String".to_string()
-                        } else {
-                            unimplemented!("{:?}", response_schema_hash)
-                        }
-                    } else {
-                        str_to_camel_case(&format!("{operation_name}_response"))
-                    }
-                } else {
-                    "()".to_string()
-                }
-            } else if let Some(created_response) =
-                responses_hash.get(&Yaml::String("201".to_string()))
+            let ok_response = success_response(responses_hash);
+            let result_type = if let Some(Yaml::Hash(response_content_hash)) = ok_response
+                .as_hash()
+                .unwrap()
+                .get(&Yaml::String("content".to_string()))
             {
-                let response_content_hash = created_response["content"].as_hash().unwrap();
                 if response_content_hash.len() == 1 {
                     let response_schema_hash = response_content_hash.front().unwrap().1["schema"]
                         .as_hash()
@@ -1192,11 +1538,14 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
                     } else if response_schema_hash
                         .get(&Yaml::String("oneOf".to_string()))
                         .is_some()
-                        || response_schema_hash
-                            .get(&Yaml::String("object".to_string()))
-                            .is_some()
+                        || response_schema_hash.get(&Yaml::String("type".to_string()))
+                            == Some(&Yaml::String("object".to_string()))
                     {
                         str_to_camel_case(&format!("{operation_name}_response"))
+                    } else if response_schema_hash.get(&Yaml::String("type".to_string()))
+                        == Some(&Yaml::String("string".to_string()))
+                    {
+                        "String".to_string()
                     } else {
                         unimplemented!("{:?}", response_schema_hash)
                     }
@@ -1204,28 +1553,58 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
                     str_to_camel_case(&format!("{operation_name}_response"))
                 }
             } else {
-                unimplemented!("{:?}", responses_hash)
+                "()".to_string()
             };
-            writeln!(client_output_file, ") -> ConversaResult<{result_type}> {{",).unwrap();
-
+            if let Some(summary) = summary {
+                writeln!(client_output_file, "\t/** {summary} */").unwrap();
+            }
             writeln!(
                 client_output_file,
-                "\t\tlet address = format!(\"{{}}{}\", self.base_address);",
-                path_name.as_str().unwrap()
+                "\tpub async fn {operation_name}(&self, {parameters}) -> ConversaResult<{result_type}> {{\n\t\tself.{operation_name}_with_meta({arguments}).await.map(|r| r.data)\n\t}}\n",
             )
             .unwrap();
             writeln!(
                 client_output_file,
-                "\t\tlet mut request = self.client.{}(&address);",
-                path_operation_name.as_str().unwrap(),
+                "\t/** Same as [`Self::{operation_name}`] but also returns the metadata of the response. */"
             )
             .unwrap();
             writeln!(
                 client_output_file,
-                "\t\trequest = request.bearer_auth(&self.api_key);",
+                "\tpub async fn {operation_name}_with_meta(&self, {parameters}) -> ConversaResult<WithMeta<{result_type}>> {{",
             )
             .unwrap();
 
+            writeln!(
+                client_output_file,
+                "\t\tlet address = format!(\"{{}}{}\", self.base_address);",
+                path_name.as_str().unwrap()
+            )
+            .unwrap();
+            // The request is only modified by headers, query arguments or a body
+            let operation_hash = path_operation_hash.as_hash().unwrap();
+            let has_query = matches!(
+                operation_hash.get(&Yaml::String("parameters".to_string())),
+                Some(Yaml::Array(parameters_list))
+                    if parameters_list.iter().any(|p| p["in"].as_str() == Some("query"))
+            );
+            let request_is_modified = is_beta_operation(path_operation_hash)
+                || has_query
+                || operation_hash.contains_key(&Yaml::String("requestBody".to_string()));
+            writeln!(
+                client_output_file,
+                "\t\tlet {}request = HttpRequest::new(reqwest::Method::{}, address);",
+                if request_is_modified { "mut " } else { "" },
+                path_operation_name.as_str().unwrap().to_uppercase(),
+            )
+            .unwrap();
+            if is_beta_operation(path_operation_hash) {
+                writeln!(
+                    client_output_file,
+                    "\t\trequest = self.with_openai_beta(request);",
+                )
+                .unwrap();
+            }
+
             // Add the query arguments to the request
             if let Some(Yaml::Array(parameters_list)) = path_operation_hash
                 .as_hash()
@@ -1238,7 +1617,7 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
                         if parameter_required {
                             writeln!(
                                 client_output_file,
-                                "\t\trequest = request.query(&[(\"{}\",{})]);",
+                                "\t\trequest = request.query(&[(\"{}\",{})])?;",
                                 parameter["name"].as_str().unwrap().replace("[]", ""),
                                 parameter["name"].as_str().unwrap().replace("[]", "")
                             )
@@ -1246,7 +1625,7 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
                         } else {
                             writeln!(
                                 client_output_file,
-                                "\t\tif let Some(q) = {} {{\n\t\t\trequest = request.query(&[(\"{}\", q)]);\n\t\t}}",
+                                "\t\tif let Some(q) = {} {{\n\t\t\trequest = request.query(&[(\"{}\", q)])?;\n\t\t}}",
                                 parameter["name"].as_str().unwrap().replace("[]", ""),
                                 parameter["name"].as_str().unwrap().replace("[]", "")
                             )
@@ -1273,20 +1652,20 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
                     if request_body_is_required {
                         writeln!(
                             client_output_file,
-                            "\t\trequest = request.json(&request_body);",
+                            "\t\trequest = request.json(&request_body)?;",
                         )
                         .unwrap();
                     } else {
                         writeln!(
                         client_output_file,
-                        "\t\tif let Some(b) = request_body {{\n\t\t\trequest = request.body(serde_json::to_string(&b)?);\n\t\t}}",
+                        "\t\tif let Some(b) = request_body {{\n\t\t\trequest = request.json(&b)?;\n\t\t}}",
                     )
                     .unwrap();
                     }
                 } else if request_body_content_type == "multipart/form-data" {
                     writeln!(
                         client_output_file,
-                        "\t\trequest = request.multipart(request_body.into_multipart_form());",
+                        "\t\trequest = request.multipart(request_body.into_multipart_form()?);",
                     )
                     .unwrap();
                 } else {
@@ -1296,7 +1675,9 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
 
             writeln!(
                 client_output_file,
-                "\t\tlet result = request.send().await?;",
+                "\t\tlet result = self.send(request, Operation {{ id: \"{}\", name: \"{operation_name}\", path: \"{}\" }}).await?;",
+                path_operation_hash["operationId"].as_str().unwrap(),
+                path_name.as_str().unwrap()
             )
             .unwrap();
 
@@ -1308,7 +1689,7 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
 
             writeln!(
                 client_output_file,
-                "\t\tlet _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();"
+                "\t\tlet meta = ResponseMeta::from_response(&result);",
             )
             .unwrap();
 
@@ -1320,31 +1701,49 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
 
             let responses_hash = path_operation_hash["responses"].as_hash().unwrap();
 
-            // Handle the Error cases first so that we can have an early return
+            // Any status code which is not a declared success is an error. The body of the error
+            // responses declared by the operation is decoded with their schema.
+            let success_status_codes = responses_hash
+                .keys()
+                .map(|code| code.as_str().unwrap())
+                .filter(|code| is_success_status_code(code))
+                .collect::<Vec<_>>()
+                .join(" | ");
             writeln!(
+                client_output_file,
+                "\t\tif !matches!(status_code, {success_status_codes}) {{"
+            )
+            .unwrap();
+            let error_schemas = responses_hash
+                .iter()
+                .map(|(code, response)| (code.as_str().unwrap(), response))
+                .filter(|(code, _)| !is_success_status_code(code))
+                .filter_map(|(code, response)| Some((code, error_response_schema(response)?)))
+                .collect::<Vec<_>>();
+            if error_schemas.is_empty() {
+                writeln!(
                     client_output_file,
-                    "\t\tif status_code == 400 {{\n\t\t\treturn Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))\n\t\t}}"
+                    "\t\t\treturn Err(ApiError::from_response(meta, &response_bytes).into());"
                 )
                 .unwrap();
-
-            writeln!(
+            } else {
+                writeln!(
                     client_output_file,
-                    "\t\tif status_code == 404 {{\n\t\t\treturn Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))\n\t\t}}"
+                    "\t\t\tlet mut error = ApiError::from_response(meta, &response_bytes);"
                 )
                 .unwrap();
+                for (status_code, schema) in error_schemas {
+                    writeln!(
+                        client_output_file,
+                        "\t\t\tif status_code == {status_code} {{\n\t\t\t\terror.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::{schema});\n\t\t\t}}"
+                    )
+                    .unwrap();
+                }
+                writeln!(client_output_file, "\t\t\treturn Err(error.into());").unwrap();
+            }
+            writeln!(client_output_file, "\t\t}}").unwrap();
 
-            // After checking for error check that the status code is actually what we are expecting
-            let (ok_response_code, ok_response) = responses_hash
-                .iter()
-                .find(|(x, _)| x.as_str().unwrap() == "200" || x.as_str().unwrap() == "201")
-                .unwrap();
-
-            writeln!(
-                    client_output_file,
-                    "\t\tif status_code != {} {{\n\t\t\treturn Err(ConversaError::UnexpectedStatusCode{{code: status_code, response: String::from_utf8(response_bytes.to_vec())?}})\n\t\t}}",
-                    ok_response_code.as_str().unwrap()
-                )
-                .unwrap();
+            let ok_response = success_response(responses_hash);
 
             if let Some(response_content_hash) = ok_response["content"].as_hash() {
                 if response_content_hash.len() == 1 {
@@ -1352,38 +1751,53 @@ fn parse_endpoint_path(path_schema: &Yaml, client_output_file: &mut File) {
                     if result_type == "String" {
                         writeln!(
                             client_output_file,
-                            "\t\tOk(String::from_utf8(response_bytes.to_vec())?)"
+                            "\t\tlet data = String::from_utf8(response_bytes.to_vec())?;"
                         )
                         .unwrap();
                     } else {
                         writeln!(
                             client_output_file,
-                            "\t\tOk(serde_json::from_slice(&response_bytes)?)"
+                            "\t\tlet data = serde_json::from_slice(&response_bytes)?;"
                         )
                         .unwrap();
                     }
                 } else {
-                    writeln!(client_output_file, "\t\tmatch _content_type.as_str() {{").unwrap();
-                    for (response_name, _) in response_content_hash {
+                    writeln!(
+                        client_output_file,
+                        "\t\tlet data = match meta.content_type.as_deref() {{"
+                    )
+                    .unwrap();
+                    // The header is missing on some proxies or has an unknown value, in which
+                    // case the body is decoded with the first content type the spec declares
+                    for (i, (response_name, response_hash)) in
+                        response_content_hash.iter().enumerate().rev()
+                    {
+                        // Binary content is returned as is instead of being decoded
+                        let response_value =
+                            if response_hash["schema"]["format"].as_str() == Some("binary") {
+                                "response_bytes.to_vec()"
+                            } else {
+                                "serde_json::from_slice(&response_bytes)?"
+                            };
+                        let pattern = if i == 0 {
+                            "_".to_string()
+                        } else {
+                            format!("Some(\"{}\")", response_name.as_str().unwrap())
+                        };
                         writeln!(
                             client_output_file,
-                            "\t\t\t\"{}\" => Ok({}::{}(serde_json::from_slice(&response_bytes)?)),",
-                            response_name.as_str().unwrap(),
+                            "\t\t\t{pattern} => {}::{}({response_value}),",
                             str_to_camel_case(&format!("{operation_name}_response")),
                             str_to_camel_case(response_name.as_str().unwrap()),
                         )
                         .unwrap();
                     }
-                    writeln!(
-                        client_output_file,
-                        "\t\t\t_ => Err(ConversaError::UnexpectedContentType(_content_type)),"
-                    )
-                    .unwrap();
-                    writeln!(client_output_file, "\t\t}}").unwrap();
+                    writeln!(client_output_file, "\t\t}};").unwrap();
                 }
             } else {
-                writeln!(client_output_file, "\t\tOk(())").unwrap();
+                writeln!(client_output_file, "\t\tlet data = ();").unwrap();
             }
+            writeln!(client_output_file, "\t\tOk(WithMeta {{ data, meta }})").unwrap();
 
             writeln!(client_output_file, "\t}}\n",).unwrap();
             println!("\t{path_operation_name:?}",);
@@ -1407,15 +1821,55 @@ fn main() {
 
     let schema_list = openai_yml[0]["components"]["schemas"].as_hash().unwrap();
 
+    NULLABLE_SCHEMAS
+        .set(
+            schema_list
+                .iter()
+                .filter(|(_, s)| s["nullable"].as_bool() == Some(true))
+                .map(|(n, _)| n.as_str().unwrap().to_string())
+                .collect(),
+        )
+        .unwrap();
+
     writeln!(output_file, "use std::collections::HashMap;").unwrap();
     writeln!(output_file, "use serde::{{Deserialize, Serialize}};\n").unwrap();
 
-    for (schema_name, schema_value) in schema_list {
-        let name = schema_name.as_str().unwrap();
-        parse_component_schema(name, schema_value, &mut output_file);
+    let path_schema = &openai_yml[0]["paths"];
+    let features = schema_features(&openai_yml[0]["components"]["schemas"], path_schema);
+    let features_of = |name: &str| features.get(name).cloned().unwrap_or_default();
+
+    let mut feature_groups: Vec<Vec<&str>> = vec![Vec::new()];
+    for (schema_name, _) in schema_list {
+        let schema_features = features_of(schema_name.as_str().unwrap());
+        if !feature_groups.contains(&schema_features) {
+            feature_groups.push(schema_features);
+        }
+    }
+    for feature_group in &feature_groups {
+        if !feature_group.is_empty() {
+            open_feature_module(feature_group, &mut output_file);
+        }
+        for (schema_name, schema_value) in schema_list {
+            let name = schema_name.as_str().unwrap();
+            if &features_of(name) == feature_group {
+                parse_component_schema(name, schema_value, &mut output_file);
+            }
+        }
+        if !feature_group.is_empty() {
+            close_feature_module(feature_group, &mut output_file);
+        }
+    }
+
+    for name in get_event_stream_schema_names(path_schema) {
+        parse_stream_event_type(
+            &name,
+            &features_of(&name),
+            &openai_yml[0]["components"]["schemas"][name.as_str()],
+            &openai_yml[0]["components"]["schemas"],
+            &mut output_file,
+        );
     }
 
     let mut client_output_file = File::create("src/client.rs").unwrap();
-    let path_schema = &openai_yml[0]["paths"];
-    parse_endpoint_path(path_schema, &mut client_output_file);
+    parse_paths(path_schema, &mut client_output_file);
 }
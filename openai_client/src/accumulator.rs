@@ -0,0 +1,292 @@
+use futures::{Stream, StreamExt};
+
+use crate::{
+    ConversaError, ConversaResult,
+    types::{
+        ChatCompletionMessageToolCall, ChatCompletionMessageToolCallFunction,
+        ChatCompletionMessageToolCallType, ChatCompletionResponseMessage,
+        ChatCompletionResponseMessageFunctionCall, ChatCompletionResponseMessageRole,
+        ChatCompletionTokenLogprob, CompletionUsage, CreateChatCompletionResponse,
+        CreateChatCompletionResponseChoices, CreateChatCompletionResponseChoicesFinishReason,
+        CreateChatCompletionResponseChoicesLogprobs, CreateChatCompletionResponseObject,
+        CreateChatCompletionStreamResponse, CreateChatCompletionStreamResponseChoicesFinishReason,
+        ServiceTier,
+    },
+};
+
+// The chunks of a streamed chat completion only contain the changes of each choice. The
+// accumulator stitches them back together into the same response that is returned when the
+// completion is not streamed.
+
+/// A tool call which is still being streamed. The arguments are only valid JSON once the
+/// choice has finished.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PartialToolCall {
+    pub index: u64,
+    pub id: Option<String>,
+    pub name: String,
+    pub arguments: String,
+}
+
+/// The deprecated function call which is still being streamed.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PartialFunctionCall {
+    pub name: String,
+    pub arguments: String,
+}
+
+/// The state of a choice after the chunks received so far.
+#[derive(Debug, Default, PartialEq)]
+pub struct PartialChoice {
+    pub index: u64,
+    pub content: Option<String>,
+    pub refusal: Option<String>,
+    pub tool_calls: Vec<PartialToolCall>,
+    pub function_call: Option<PartialFunctionCall>,
+    pub content_logprobs: Option<Vec<ChatCompletionTokenLogprob>>,
+    pub refusal_logprobs: Option<Vec<ChatCompletionTokenLogprob>>,
+    pub finish_reason: Option<CreateChatCompletionStreamResponseChoicesFinishReason>,
+}
+
+/// Folds the chunks of a streamed chat completion into a [`CreateChatCompletionResponse`].
+///
+/// The partial state can be inspected after every [`push`](Self::push), for example to render
+/// the content and tool calls while they are being generated.
+#[derive(Debug, Default)]
+pub struct ChatCompletionAccumulator {
+    id: String,
+    created: u64,
+    model: String,
+    service_tier: Option<ServiceTier>,
+    system_fingerprint: Option<String>,
+    usage: Option<CompletionUsage>,
+    choices: Vec<PartialChoice>,
+}
+
+fn append(target: &mut Option<String>, fragment: Option<String>) {
+    if let Some(fragment) = fragment {
+        target.get_or_insert_default().push_str(&fragment);
+    }
+}
+
+fn append_logprobs(
+    target: &mut Option<Vec<ChatCompletionTokenLogprob>>,
+    logprobs: Option<Vec<ChatCompletionTokenLogprob>>,
+) {
+    if let Some(logprobs) = logprobs {
+        target.get_or_insert_default().extend(logprobs);
+    }
+}
+
+impl ChatCompletionAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Merges the next chunk of the stream into the accumulated state.
+    pub fn push(&mut self, chunk: CreateChatCompletionStreamResponse) {
+        self.id = chunk.id;
+        self.created = chunk.created;
+        self.model = chunk.model;
+        if chunk.service_tier.is_some() {
+            self.service_tier = chunk.service_tier;
+        }
+        if chunk.system_fingerprint.is_some() {
+            self.system_fingerprint = chunk.system_fingerprint;
+        }
+        if chunk.usage.is_some() {
+            self.usage = chunk.usage;
+        }
+
+        for chunk_choice in chunk.choices {
+            let choice = match self
+                .choices
+                .iter()
+                .position(|c| c.index == chunk_choice.index)
+            {
+                Some(i) => &mut self.choices[i],
+                None => {
+                    self.choices.push(PartialChoice {
+                        index: chunk_choice.index,
+                        ..Default::default()
+                    });
+                    self.choices.last_mut().unwrap()
+                }
+            };
+
+            let delta = chunk_choice.delta;
+            append(&mut choice.content, delta.content);
+            append(&mut choice.refusal, delta.refusal);
+
+            for tool_call_chunk in delta.tool_calls.unwrap_or_default() {
+                let tool_call = match choice
+                    .tool_calls
+                    .iter()
+                    .position(|t| t.index == tool_call_chunk.index)
+                {
+                    Some(i) => &mut choice.tool_calls[i],
+                    None => {
+                        choice.tool_calls.push(PartialToolCall {
+                            index: tool_call_chunk.index,
+                            ..Default::default()
+                        });
+                        choice.tool_calls.last_mut().unwrap()
+                    }
+                };
+                if tool_call_chunk.id.is_some() {
+                    tool_call.id = tool_call_chunk.id;
+                }
+                if let Some(function) = tool_call_chunk.function {
+                    tool_call.name.push_str(&function.name.unwrap_or_default());
+                    tool_call
+                        .arguments
+                        .push_str(&function.arguments.unwrap_or_default());
+                }
+            }
+
+            if let Some(function_call_chunk) = delta.function_call {
+                let function_call = choice.function_call.get_or_insert_default();
+                function_call
+                    .name
+                    .push_str(&function_call_chunk.name.unwrap_or_default());
+                function_call
+                    .arguments
+                    .push_str(&function_call_chunk.arguments.unwrap_or_default());
+            }
+
+            if let Some(logprobs) = chunk_choice.logprobs {
+                append_logprobs(&mut choice.content_logprobs, logprobs.content);
+                append_logprobs(&mut choice.refusal_logprobs, logprobs.refusal);
+            }
+
+            if chunk_choice.finish_reason.is_some() {
+                choice.finish_reason = chunk_choice.finish_reason;
+            }
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// The choices received so far, ordered by the time their first chunk arrived.
+    pub fn choices(&self) -> &[PartialChoice] {
+        &self.choices
+    }
+
+    /// The token usage, which is only sent in the last chunk when it is requested with
+    /// `stream_options`.
+    pub fn usage(&self) -> Option<&CompletionUsage> {
+        self.usage.as_ref()
+    }
+
+    /// Builds the complete response. Fails if the stream ended before every choice finished.
+    pub fn finish(mut self) -> ConversaResult<CreateChatCompletionResponse> {
+        self.choices.sort_by_key(|c| c.index);
+        let mut choices = Vec::with_capacity(self.choices.len());
+        for mut choice in self.choices {
+            let finish_reason = match choice.finish_reason {
+                Some(CreateChatCompletionStreamResponseChoicesFinishReason::Stop) => {
+                    CreateChatCompletionResponseChoicesFinishReason::Stop
+                }
+                Some(CreateChatCompletionStreamResponseChoicesFinishReason::Length) => {
+                    CreateChatCompletionResponseChoicesFinishReason::Length
+                }
+                Some(CreateChatCompletionStreamResponseChoicesFinishReason::ToolCalls) => {
+                    CreateChatCompletionResponseChoicesFinishReason::ToolCalls
+                }
+                Some(CreateChatCompletionStreamResponseChoicesFinishReason::ContentFilter) => {
+                    CreateChatCompletionResponseChoicesFinishReason::ContentFilter
+                }
+                Some(CreateChatCompletionStreamResponseChoicesFinishReason::FunctionCall) => {
+                    CreateChatCompletionResponseChoicesFinishReason::FunctionCall
+                }
+                None => {
+                    return Err(ConversaError::InvalidData(format!(
+                        "Choice {} did not finish",
+                        choice.index
+                    )));
+                }
+            };
+
+            let tool_calls = if choice.tool_calls.is_empty() {
+                None
+            } else {
+                choice.tool_calls.sort_by_key(|t| t.index);
+                let mut tool_calls = Vec::with_capacity(choice.tool_calls.len());
+                for tool_call in choice.tool_calls {
+                    tool_calls.push(ChatCompletionMessageToolCall {
+                        id: tool_call.id.ok_or(ConversaError::InvalidData(format!(
+                            "Tool call {} has no id",
+                            tool_call.index
+                        )))?,
+                        r#type: ChatCompletionMessageToolCallType::Function,
+                        function: ChatCompletionMessageToolCallFunction {
+                            name: tool_call.name,
+                            arguments: tool_call.arguments,
+                        },
+                    });
+                }
+                Some(tool_calls)
+            };
+
+            let logprobs = if choice.content_logprobs.is_some() || choice.refusal_logprobs.is_some()
+            {
+                Some(CreateChatCompletionResponseChoicesLogprobs {
+                    content: choice.content_logprobs,
+                    refusal: choice.refusal_logprobs,
+                })
+            } else {
+                None
+            };
+
+            choices.push(CreateChatCompletionResponseChoices {
+                finish_reason,
+                index: choice.index,
+                message: ChatCompletionResponseMessage {
+                    content: choice.content,
+                    refusal: choice.refusal,
+                    tool_calls,
+                    annotations: None,
+                    role: ChatCompletionResponseMessageRole::Assistant,
+                    function_call: choice.function_call.map(|f| {
+                        ChatCompletionResponseMessageFunctionCall {
+                            arguments: f.arguments,
+                            name: f.name,
+                        }
+                    }),
+                    audio: None,
+                },
+                logprobs,
+            });
+        }
+
+        Ok(CreateChatCompletionResponse {
+            id: self.id,
+            choices,
+            created: self.created,
+            model: self.model,
+            service_tier: self.service_tier,
+            system_fingerprint: self.system_fingerprint,
+            object: CreateChatCompletionResponseObject::ChatCompletion,
+            usage: self.usage,
+        })
+    }
+}
+
+/// Consumes a stream of chat completion chunks and returns the complete response.
+pub async fn accumulate<S>(stream: S) -> ConversaResult<CreateChatCompletionResponse>
+where
+    S: Stream<Item = ConversaResult<CreateChatCompletionStreamResponse>>,
+{
+    let mut stream = std::pin::pin!(stream);
+    let mut accumulator = ChatCompletionAccumulator::new();
+    while let Some(chunk) = stream.next().await {
+        accumulator.push(chunk?);
+    }
+    accumulator.finish()
+}
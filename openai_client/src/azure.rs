@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use reqwest::header::HeaderName;
+
+use crate::{ConversaError, ConversaResult, transport::HttpRequest};
+
+// Azure OpenAI serves the same operations as the OpenAI API under other paths. The operations
+// using a model are sent to the deployment of the model and every request carries the version
+// of the API. The generated methods build their requests for the OpenAI API and the requests are
+// rewritten here before they are sent.
+
+/// The operations which are sent to a deployment.
+const DEPLOYMENT_OPERATIONS: &[&str] = &[
+    "createChatCompletion",
+    "createCompletion",
+    "createEmbedding",
+    "createImage",
+    "createImageEdit",
+    "createImageVariation",
+    "createSpeech",
+    "createTranscription",
+    "createTranslation",
+];
+
+/// How the client authenticates with Azure OpenAI.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AzureAuth {
+    /// The token of the client is sent in the `api-key` header.
+    #[default]
+    ApiKey,
+    /// The token of the client is a Microsoft Entra ID token, sent as bearer token.
+    EntraId,
+}
+
+/// Sends the requests to Azure OpenAI.
+///
+/// The address of the client is the endpoint of the Azure OpenAI resource, e.g.
+/// `https://my-resource.openai.azure.com`. The operations using a model are sent to
+/// `/openai/deployments/{deployment}/...`, the others to `/openai/...`. The deployment is looked
+/// up by the `model` of the request and defaults to the name of the model.
+#[derive(Debug, Clone)]
+pub struct AzureConfig {
+    api_version: String,
+    auth: AzureAuth,
+    deployments: HashMap<String, String>,
+}
+
+impl AzureConfig {
+    /// Sends the requests with the `api-version` query parameter, e.g. `2024-10-21`.
+    pub fn new(api_version: impl Into<String>) -> Self {
+        Self {
+            api_version: api_version.into(),
+            auth: AzureAuth::default(),
+            deployments: HashMap::new(),
+        }
+    }
+
+    pub fn with_auth(mut self, auth: AzureAuth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Sends the requests using `model` to `deployment`.
+    pub fn with_deployment(
+        mut self,
+        model: impl Into<String>,
+        deployment: impl Into<String>,
+    ) -> Self {
+        self.deployments.insert(model.into(), deployment.into());
+        self
+    }
+
+    /// Rewrites a request built for the OpenAI API at `base_address` for Azure OpenAI.
+    pub(crate) fn prepare(
+        &self,
+        request: &mut HttpRequest,
+        base_address: &str,
+    ) -> ConversaResult<()> {
+        let Some(path) = request.url.strip_prefix(base_address) else {
+            return Ok(());
+        };
+        let path = if DEPLOYMENT_OPERATIONS.contains(&request.operation.id) {
+            let model = request.model().ok_or_else(|| {
+                ConversaError::ClientError(format!(
+                    "{} needs a model to select the Azure deployment",
+                    request.operation.name
+                ))
+            })?;
+            let deployment = self.deployments.get(&model).unwrap_or(&model);
+            format!("/openai/deployments/{deployment}{path}")
+        } else {
+            format!("/openai{path}")
+        };
+        request.url = format!("{base_address}{path}");
+        request
+            .query
+            .push(("api-version".to_string(), self.api_version.clone()));
+        Ok(())
+    }
+
+    /// Authenticates the request with `token` as configured.
+    pub(crate) fn authorize(
+        &self,
+        request: HttpRequest,
+        token: &str,
+    ) -> ConversaResult<HttpRequest> {
+        match self.auth {
+            AzureAuth::ApiKey => request.secret_header(HeaderName::from_static("api-key"), token),
+            AzureAuth::EntraId => request.bearer_auth(token),
+        }
+    }
+}
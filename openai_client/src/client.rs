@@ -1,23 +1,12 @@
-use crate::{ConversaError, ConversaResult, OpenAIClient};
+use crate::{ApiError, ConversaResult, OpenAIClient, meta::{ResponseMeta, WithMeta}, transport::{HttpRequest, Operation}};
 use crate::types::*;
 use serde::{Serialize, Deserialize};
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub enum CreateSpeechResponse {
-	ApplicationOctetStream(Vec<u8>),
-	TextEventStream(crate::types::CreateSpeechResponseStreamEvent),
-}
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub enum CreateTranscriptionResponse {
-	ApplicationJson(crate::types::CreateTranscriptionResponseJson),
-	ApplicationJsonVerbose(crate::types::CreateTranscriptionResponseVerboseJson),
-	TextEventStream(crate::types::CreateTranscriptionResponseStreamEvent),
-}
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum CreateTranslationResponse {
-	CreateTranslationResponseJson(CreateTranslationResponseJson),
-	CreateTranslationResponseVerboseJson(CreateTranslationResponseVerboseJson),
+/** The body of an error response, decoded with the schema the operation declares for the status code */
+#[derive(Debug, PartialEq)]
+pub enum ApiErrorBody {
+	Error(crate::types::Error),
+	ErrorResponse(crate::types::ErrorResponse),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -64,391 +53,133 @@ pub struct UpdateChatCompletionRequestBody {
 	pub metadata: Metadata,
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct UpdateEvalRequestBody {
-	/** Rename the evaluation. */
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub name: Option<String>,
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub metadata: Option<Metadata>,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct DeleteEvalResponse {
-	pub object: String,
-	pub deleted: bool,
-	pub eval_id: String,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct DeleteEvalRunResponse {
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub object: Option<String>,
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub deleted: Option<bool>,
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub run_id: Option<String>,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct ListPaginatedFineTuningJobsQuery(pub String);
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct AdminApiKeysCreateRequestBody {
-	pub name: String,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct AdminApiKeysDeleteResponse {
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub id: Option<String>,
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub object: Option<String>,
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub deleted: Option<bool>,
-}
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct ListAuditLogsQuery {
-	/** Return only events whose `effective_at` (Unix seconds) is greater than this value. */
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub gt: Option<u64>,
-	/** Return only events whose `effective_at` (Unix seconds) is greater than or equal to this value. */
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub gte: Option<u64>,
-	/** Return only events whose `effective_at` (Unix seconds) is less than this value. */
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub lt: Option<u64>,
-	/** Return only events whose `effective_at` (Unix seconds) is less than or equal to this value. */
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub lte: Option<u64>,
-}
-
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub enum CreateResponseResponse {
 	ApplicationJson(crate::types::Response),
 	TextEventStream(crate::types::ResponseStreamEvent),
 }
 impl OpenAIClient {
-	/** Returns a list of assistants. */
-	pub async fn list_assistants(&self, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, ) -> ConversaResult<crate::types::ListAssistantsResponse> {
-		let address = format!("{}/assistants", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
-		}
-		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
-		}
-		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
-		}
-		if let Some(q) = before {
-			request = request.query(&[("before", q)]);
-		}
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Create an assistant with a model and instructions. */
-	pub async fn create_assistant(&self, request_body: crate::types::CreateAssistantRequest, ) -> ConversaResult<crate::types::AssistantObject> {
-		let address = format!("{}/assistants", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Retrieves an assistant. */
-	pub async fn get_assistant(&self, assistant_id: &str, ) -> ConversaResult<crate::types::AssistantObject> {
-		let address = format!("{}/assistants/{assistant_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Modifies an assistant. */
-	pub async fn modify_assistant(&self, assistant_id: &str, request_body: crate::types::ModifyAssistantRequest, ) -> ConversaResult<crate::types::AssistantObject> {
-		let address = format!("{}/assistants/{assistant_id}", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Delete an assistant. */
-	pub async fn delete_assistant(&self, assistant_id: &str, ) -> ConversaResult<crate::types::DeleteAssistantResponse> {
-		let address = format!("{}/assistants/{assistant_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Generates audio from the input text. */
-	pub async fn create_speech(&self, request_body: crate::types::CreateSpeechRequest, ) -> ConversaResult<CreateSpeechResponse> {
-		let address = format!("{}/audio/speech", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		match _content_type.as_str() {
-			"application/octet-stream" => Ok(CreateSpeechResponse::ApplicationOctetStream(serde_json::from_slice(&response_bytes)?)),
-			"text/event-stream" => Ok(CreateSpeechResponse::TextEventStream(serde_json::from_slice(&response_bytes)?)),
-			_ => Err(ConversaError::UnexpectedContentType(_content_type)),
-		}
-	}
-
-	/** Transcribes audio into the input language. */
-	pub async fn create_transcription(&self, request_body: crate::types::CreateTranscriptionRequest, ) -> ConversaResult<CreateTranscriptionResponse> {
-		let address = format!("{}/audio/transcriptions", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.multipart(request_body.into_multipart_form());
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		match _content_type.as_str() {
-			"application/json" => Ok(CreateTranscriptionResponse::ApplicationJson(serde_json::from_slice(&response_bytes)?)),
-			"text/event-stream" => Ok(CreateTranscriptionResponse::TextEventStream(serde_json::from_slice(&response_bytes)?)),
-			_ => Err(ConversaError::UnexpectedContentType(_content_type)),
-		}
-	}
-
-	/** Translates audio into English. */
-	pub async fn create_translation(&self, request_body: crate::types::CreateTranslationRequest, ) -> ConversaResult<CreateTranslationResponse> {
-		let address = format!("{}/audio/translations", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.multipart(request_body.into_multipart_form());
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
 	/** Creates and executes a batch from an uploaded file of requests */
 	pub async fn create_batch(&self, request_body: CreateBatchRequestBody, ) -> ConversaResult<crate::types::Batch> {
+		self.create_batch_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_batch`] but also returns the metadata of the response. */
+	pub async fn create_batch_with_meta(&self, request_body: CreateBatchRequestBody, ) -> ConversaResult<WithMeta<crate::types::Batch>> {
 		let address = format!("{}/batches", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createBatch", name: "create_batch", path: "/batches" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** List your organization's batches. */
 	pub async fn list_batches(&self, after: Option<&str>, limit: Option<u64>, ) -> ConversaResult<crate::types::ListBatchesResponse> {
+		self.list_batches_with_meta(after, limit, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_batches`] but also returns the metadata of the response. */
+	pub async fn list_batches_with_meta(&self, after: Option<&str>, limit: Option<u64>, ) -> ConversaResult<WithMeta<crate::types::ListBatchesResponse>> {
 		let address = format!("{}/batches", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "listBatches", name: "list_batches", path: "/batches" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Retrieves a batch. */
 	pub async fn retrieve_batch(&self, batch_id: &str, ) -> ConversaResult<crate::types::Batch> {
+		self.retrieve_batch_with_meta(batch_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::retrieve_batch`] but also returns the metadata of the response. */
+	pub async fn retrieve_batch_with_meta(&self, batch_id: &str, ) -> ConversaResult<WithMeta<crate::types::Batch>> {
 		let address = format!("{}/batches/{batch_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "retrieveBatch", name: "retrieve_batch", path: "/batches/{batch_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Cancels an in-progress batch. The batch will be in status `cancelling` for up to 10 minutes, before changing to `cancelled`, where it will have partial results (if any) available in the output file. */
 	pub async fn cancel_batch(&self, batch_id: &str, ) -> ConversaResult<crate::types::Batch> {
+		self.cancel_batch_with_meta(batch_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::cancel_batch`] but also returns the metadata of the response. */
+	pub async fn cancel_batch_with_meta(&self, batch_id: &str, ) -> ConversaResult<WithMeta<crate::types::Batch>> {
 		let address = format!("{}/batches/{batch_id}/cancel", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::POST, address);
+		let result = self.send(request, Operation { id: "cancelBatch", name: "cancel_batch", path: "/batches/{batch_id}/cancel" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** List stored Chat Completions. Only Chat Completions that have been stored
 with the `store` parameter set to `true` will be returned. */
 	pub async fn list_chat_completions(&self, model: Option<&str>, metadata: Option<&crate::types::Metadata>, after: Option<&str>, limit: Option<u64>, order: Option<&str>, ) -> ConversaResult<crate::types::ChatCompletionList> {
+		self.list_chat_completions_with_meta(model, metadata, after, limit, order, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_chat_completions`] but also returns the metadata of the response. */
+	pub async fn list_chat_completions_with_meta(&self, model: Option<&str>, metadata: Option<&crate::types::Metadata>, after: Option<&str>, limit: Option<u64>, order: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ChatCompletionList>> {
 		let address = format!("{}/chat/completions", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = model {
-			request = request.query(&[("model", q)]);
+			request = request.query(&[("model", q)])?;
 		}
 		if let Some(q) = metadata {
-			request = request.query(&[("metadata", q)]);
+			request = request.query(&[("metadata", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+			request = request.query(&[("order", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "listChatCompletions", name: "list_chat_completions", path: "/chat/completions" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** **Starting a new project?** We recommend trying [Responses](/docs/api-reference/responses) 
@@ -467,3851 +198,4149 @@ supported for reasoning models are noted below. For the current state of
 unsupported parameters in reasoning models, 
 [refer to the reasoning guide](/docs/guides/reasoning). */
 	pub async fn create_chat_completion(&self, request_body: crate::types::CreateChatCompletionRequest, ) -> ConversaResult<CreateChatCompletionResponse> {
+		self.create_chat_completion_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_chat_completion`] but also returns the metadata of the response. */
+	pub async fn create_chat_completion_with_meta(&self, request_body: crate::types::CreateChatCompletionRequest, ) -> ConversaResult<WithMeta<CreateChatCompletionResponse>> {
 		let address = format!("{}/chat/completions", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createChatCompletion", name: "create_chat_completion", path: "/chat/completions" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		match _content_type.as_str() {
-			"application/json" => Ok(CreateChatCompletionResponse::ApplicationJson(serde_json::from_slice(&response_bytes)?)),
-			"text/event-stream" => Ok(CreateChatCompletionResponse::TextEventStream(serde_json::from_slice(&response_bytes)?)),
-			_ => Err(ConversaError::UnexpectedContentType(_content_type)),
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
+		let data = match meta.content_type.as_deref() {
+			Some("text/event-stream") => CreateChatCompletionResponse::TextEventStream(serde_json::from_slice(&response_bytes)?),
+			_ => CreateChatCompletionResponse::ApplicationJson(serde_json::from_slice(&response_bytes)?),
+		};
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Get a stored chat completion. Only Chat Completions that have been created
 with the `store` parameter set to `true` will be returned. */
 	pub async fn get_chat_completion(&self, completion_id: &str, ) -> ConversaResult<crate::types::CreateChatCompletionResponse> {
+		self.get_chat_completion_with_meta(completion_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_chat_completion`] but also returns the metadata of the response. */
+	pub async fn get_chat_completion_with_meta(&self, completion_id: &str, ) -> ConversaResult<WithMeta<crate::types::CreateChatCompletionResponse>> {
 		let address = format!("{}/chat/completions/{completion_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "getChatCompletion", name: "get_chat_completion", path: "/chat/completions/{completion_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Modify a stored chat completion. Only Chat Completions that have been
 created with the `store` parameter set to `true` can be modified. Currently,
 the only supported modification is to update the `metadata` field. */
 	pub async fn update_chat_completion(&self, completion_id: &str, request_body: UpdateChatCompletionRequestBody, ) -> ConversaResult<crate::types::CreateChatCompletionResponse> {
+		self.update_chat_completion_with_meta(completion_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::update_chat_completion`] but also returns the metadata of the response. */
+	pub async fn update_chat_completion_with_meta(&self, completion_id: &str, request_body: UpdateChatCompletionRequestBody, ) -> ConversaResult<WithMeta<crate::types::CreateChatCompletionResponse>> {
 		let address = format!("{}/chat/completions/{completion_id}", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "updateChatCompletion", name: "update_chat_completion", path: "/chat/completions/{completion_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Delete a stored chat completion. Only Chat Completions that have been
 created with the `store` parameter set to `true` can be deleted. */
 	pub async fn delete_chat_completion(&self, completion_id: &str, ) -> ConversaResult<crate::types::ChatCompletionDeleted> {
+		self.delete_chat_completion_with_meta(completion_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_chat_completion`] but also returns the metadata of the response. */
+	pub async fn delete_chat_completion_with_meta(&self, completion_id: &str, ) -> ConversaResult<WithMeta<crate::types::ChatCompletionDeleted>> {
 		let address = format!("{}/chat/completions/{completion_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "deleteChatCompletion", name: "delete_chat_completion", path: "/chat/completions/{completion_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Get the messages in a stored chat completion. Only Chat Completions that
 have been created with the `store` parameter set to `true` will be
 returned. */
 	pub async fn get_chat_completion_messages(&self, completion_id: &str, after: Option<&str>, limit: Option<u64>, order: Option<&str>, ) -> ConversaResult<crate::types::ChatCompletionMessageList> {
+		self.get_chat_completion_messages_with_meta(completion_id, after, limit, order, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_chat_completion_messages`] but also returns the metadata of the response. */
+	pub async fn get_chat_completion_messages_with_meta(&self, completion_id: &str, after: Option<&str>, limit: Option<u64>, order: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ChatCompletionMessageList>> {
 		let address = format!("{}/chat/completions/{completion_id}/messages", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+			request = request.query(&[("order", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "getChatCompletionMessages", name: "get_chat_completion_messages", path: "/chat/completions/{completion_id}/messages" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Creates a completion for the provided prompt and parameters. */
 	pub async fn create_completion(&self, request_body: crate::types::CreateCompletionRequest, ) -> ConversaResult<crate::types::CreateCompletionResponse> {
+		self.create_completion_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_completion`] but also returns the metadata of the response. */
+	pub async fn create_completion_with_meta(&self, request_body: crate::types::CreateCompletionRequest, ) -> ConversaResult<WithMeta<crate::types::CreateCompletionResponse>> {
 		let address = format!("{}/completions", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createCompletion", name: "create_completion", path: "/completions" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** List Containers */
 	pub async fn list_containers(&self, limit: Option<u64>, order: Option<&str>, after: Option<&str>, ) -> ConversaResult<crate::types::ContainerListResource> {
+		self.list_containers_with_meta(limit, order, after, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_containers`] but also returns the metadata of the response. */
+	pub async fn list_containers_with_meta(&self, limit: Option<u64>, order: Option<&str>, after: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ContainerListResource>> {
 		let address = format!("{}/containers", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+			request = request.query(&[("order", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "ListContainers", name: "list_containers", path: "/containers" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Create Container */
 	pub async fn create_container(&self, request_body: Option<crate::types::CreateContainerBody>, ) -> ConversaResult<crate::types::ContainerResource> {
+		self.create_container_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_container`] but also returns the metadata of the response. */
+	pub async fn create_container_with_meta(&self, request_body: Option<crate::types::CreateContainerBody>, ) -> ConversaResult<WithMeta<crate::types::ContainerResource>> {
 		let address = format!("{}/containers", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
 		if let Some(b) = request_body {
-			request = request.body(serde_json::to_string(&b)?);
+			request = request.json(&b)?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "CreateContainer", name: "create_container", path: "/containers" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Retrieve Container */
 	pub async fn retrieve_container(&self, container_id: &str, ) -> ConversaResult<crate::types::ContainerResource> {
+		self.retrieve_container_with_meta(container_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::retrieve_container`] but also returns the metadata of the response. */
+	pub async fn retrieve_container_with_meta(&self, container_id: &str, ) -> ConversaResult<WithMeta<crate::types::ContainerResource>> {
 		let address = format!("{}/containers/{container_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "RetrieveContainer", name: "retrieve_container", path: "/containers/{container_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Delete Container */
 	pub async fn delete_container(&self, container_id: &str, ) -> ConversaResult<()> {
+		self.delete_container_with_meta(container_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_container`] but also returns the metadata of the response. */
+	pub async fn delete_container_with_meta(&self, container_id: &str, ) -> ConversaResult<WithMeta<()>> {
 		let address = format!("{}/containers/{container_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "DeleteContainer", name: "delete_container", path: "/containers/{container_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(())
+		let data = ();
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Create a Container File
 
 You can send either a multipart/form-data request with the raw file content, or a JSON request with a file ID. */
 	pub async fn create_container_file(&self, container_id: &str, request_body: crate::types::CreateContainerFileBody, ) -> ConversaResult<crate::types::ContainerFileResource> {
+		self.create_container_file_with_meta(container_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_container_file`] but also returns the metadata of the response. */
+	pub async fn create_container_file_with_meta(&self, container_id: &str, request_body: crate::types::CreateContainerFileBody, ) -> ConversaResult<WithMeta<crate::types::ContainerFileResource>> {
 		let address = format!("{}/containers/{container_id}/files", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.multipart(request_body.into_multipart_form());
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.multipart(request_body.into_multipart_form()?);
+		let result = self.send(request, Operation { id: "CreateContainerFile", name: "create_container_file", path: "/containers/{container_id}/files" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** List Container files */
 	pub async fn list_container_files(&self, container_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, ) -> ConversaResult<crate::types::ContainerFileListResource> {
+		self.list_container_files_with_meta(container_id, limit, order, after, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_container_files`] but also returns the metadata of the response. */
+	pub async fn list_container_files_with_meta(&self, container_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ContainerFileListResource>> {
 		let address = format!("{}/containers/{container_id}/files", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+			request = request.query(&[("order", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "ListContainerFiles", name: "list_container_files", path: "/containers/{container_id}/files" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Retrieve Container File */
 	pub async fn retrieve_container_file(&self, container_id: &str, file_id: &str, ) -> ConversaResult<crate::types::ContainerFileResource> {
+		self.retrieve_container_file_with_meta(container_id, file_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::retrieve_container_file`] but also returns the metadata of the response. */
+	pub async fn retrieve_container_file_with_meta(&self, container_id: &str, file_id: &str, ) -> ConversaResult<WithMeta<crate::types::ContainerFileResource>> {
 		let address = format!("{}/containers/{container_id}/files/{file_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "RetrieveContainerFile", name: "retrieve_container_file", path: "/containers/{container_id}/files/{file_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Delete Container File */
 	pub async fn delete_container_file(&self, container_id: &str, file_id: &str, ) -> ConversaResult<()> {
+		self.delete_container_file_with_meta(container_id, file_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_container_file`] but also returns the metadata of the response. */
+	pub async fn delete_container_file_with_meta(&self, container_id: &str, file_id: &str, ) -> ConversaResult<WithMeta<()>> {
 		let address = format!("{}/containers/{container_id}/files/{file_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "DeleteContainerFile", name: "delete_container_file", path: "/containers/{container_id}/files/{file_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(())
+		let data = ();
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Retrieve Container File Content */
 	pub async fn retrieve_container_file_content(&self, container_id: &str, file_id: &str, ) -> ConversaResult<()> {
+		self.retrieve_container_file_content_with_meta(container_id, file_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::retrieve_container_file_content`] but also returns the metadata of the response. */
+	pub async fn retrieve_container_file_content_with_meta(&self, container_id: &str, file_id: &str, ) -> ConversaResult<WithMeta<()>> {
 		let address = format!("{}/containers/{container_id}/files/{file_id}/content", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "RetrieveContainerFileContent", name: "retrieve_container_file_content", path: "/containers/{container_id}/files/{file_id}/content" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(())
+		let data = ();
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Creates an embedding vector representing the input text. */
 	pub async fn create_embedding(&self, request_body: crate::types::CreateEmbeddingRequest, ) -> ConversaResult<crate::types::CreateEmbeddingResponse> {
+		self.create_embedding_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_embedding`] but also returns the metadata of the response. */
+	pub async fn create_embedding_with_meta(&self, request_body: crate::types::CreateEmbeddingRequest, ) -> ConversaResult<WithMeta<crate::types::CreateEmbeddingResponse>> {
 		let address = format!("{}/embeddings", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createEmbedding", name: "create_embedding", path: "/embeddings" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** List evaluations for a project. */
-	pub async fn list_evals(&self, after: Option<&str>, limit: Option<u64>, order: Option<&str>, order_by: Option<&str>, ) -> ConversaResult<crate::types::EvalList> {
-		let address = format!("{}/evals", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
-		}
+	/** Returns a list of files. */
+	pub async fn list_files(&self, purpose: Option<&str>, limit: Option<u64>, order: Option<&str>, after: Option<&str>, ) -> ConversaResult<crate::types::ListFilesResponse> {
+		self.list_files_with_meta(purpose, limit, order, after, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_files`] but also returns the metadata of the response. */
+	pub async fn list_files_with_meta(&self, purpose: Option<&str>, limit: Option<u64>, order: Option<&str>, after: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ListFilesResponse>> {
+		let address = format!("{}/files", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		if let Some(q) = purpose {
+			request = request.query(&[("purpose", q)])?;
+		}
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+			request = request.query(&[("order", q)])?;
 		}
-		if let Some(q) = order_by {
-			request = request.query(&[("order_by", q)]);
+		if let Some(q) = after {
+			request = request.query(&[("after", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "listFiles", name: "list_files", path: "/files" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Create the structure of an evaluation that can be used to test a model's performance.
-An evaluation is a set of testing criteria and the config for a data source, which dictates the schema of the data used in the evaluation. After creating an evaluation, you can run it on different models and model parameters. We support several types of graders and datasources.
-For more information, see the [Evals guide](/docs/guides/evals). */
-	pub async fn create_eval(&self, request_body: crate::types::CreateEvalRequest, ) -> ConversaResult<crate::types::Eval> {
-		let address = format!("{}/evals", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** Upload a file that can be used across various endpoints. Individual files can be up to 512 MB, and the size of all files uploaded by one organization can be up to 100 GB.
+
+The Assistants API supports files up to 2 million tokens and of specific file types. See the [Assistants Tools guide](/docs/assistants/tools) for details.
+
+The Fine-tuning API only supports `.jsonl` files. The input also has certain required formats for fine-tuning [chat](/docs/api-reference/fine-tuning/chat-input) or [completions](/docs/api-reference/fine-tuning/completions-input) models.
+
+The Batch API only supports `.jsonl` files up to 200 MB in size. The input also has a specific required [format](/docs/api-reference/batch/request-input).
+
+Please [contact us](https://help.openai.com/) if you need to increase these storage limits. */
+	pub async fn create_file(&self, request_body: crate::types::CreateFileRequest, ) -> ConversaResult<crate::types::OpenAIFile> {
+		self.create_file_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_file`] but also returns the metadata of the response. */
+	pub async fn create_file_with_meta(&self, request_body: crate::types::CreateFileRequest, ) -> ConversaResult<WithMeta<crate::types::OpenAIFile>> {
+		let address = format!("{}/files", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.multipart(request_body.into_multipart_form()?);
+		let result = self.send(request, Operation { id: "createFile", name: "create_file", path: "/files" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 201 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Get an evaluation by ID. */
-	pub async fn get_eval(&self, eval_id: &str, ) -> ConversaResult<crate::types::Eval> {
-		let address = format!("{}/evals/{eval_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Delete a file. */
+	pub async fn delete_file(&self, file_id: &str, ) -> ConversaResult<crate::types::DeleteFileResponse> {
+		self.delete_file_with_meta(file_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_file`] but also returns the metadata of the response. */
+	pub async fn delete_file_with_meta(&self, file_id: &str, ) -> ConversaResult<WithMeta<crate::types::DeleteFileResponse>> {
+		let address = format!("{}/files/{file_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "deleteFile", name: "delete_file", path: "/files/{file_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Update certain properties of an evaluation. */
-	pub async fn update_eval(&self, eval_id: &str, request_body: UpdateEvalRequestBody, ) -> ConversaResult<crate::types::Eval> {
-		let address = format!("{}/evals/{eval_id}", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** Returns information about a specific file. */
+	pub async fn retrieve_file(&self, file_id: &str, ) -> ConversaResult<crate::types::OpenAIFile> {
+		self.retrieve_file_with_meta(file_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::retrieve_file`] but also returns the metadata of the response. */
+	pub async fn retrieve_file_with_meta(&self, file_id: &str, ) -> ConversaResult<WithMeta<crate::types::OpenAIFile>> {
+		let address = format!("{}/files/{file_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "retrieveFile", name: "retrieve_file", path: "/files/{file_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Delete an evaluation. */
-	pub async fn delete_eval(&self, eval_id: &str, ) -> ConversaResult<DeleteEvalResponse> {
-		let address = format!("{}/evals/{eval_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Returns the contents of the specified file. */
+	pub async fn download_file(&self, file_id: &str, ) -> ConversaResult<String> {
+		self.download_file_with_meta(file_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::download_file`] but also returns the metadata of the response. */
+	pub async fn download_file_with_meta(&self, file_id: &str, ) -> ConversaResult<WithMeta<String>> {
+		let address = format!("{}/files/{file_id}/content", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "downloadFile", name: "download_file", path: "/files/{file_id}/content" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = String::from_utf8(response_bytes.to_vec())?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Get a list of runs for an evaluation. */
-	pub async fn get_eval_runs(&self, eval_id: &str, after: Option<&str>, limit: Option<u64>, order: Option<&str>, status: Option<&str>, ) -> ConversaResult<crate::types::EvalRunList> {
-		let address = format!("{}/evals/{eval_id}/runs", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
-		}
-		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
-		}
-		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
-		}
-		if let Some(q) = status {
-			request = request.query(&[("status", q)]);
-		}
-		let result = request.send().await?;
+	/** Lists the currently available models, and provides basic information about each one such as the owner and availability. */
+	pub async fn list_models(&self, ) -> ConversaResult<crate::types::ListModelsResponse> {
+		self.list_models_with_meta().await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_models`] but also returns the metadata of the response. */
+	pub async fn list_models_with_meta(&self, ) -> ConversaResult<WithMeta<crate::types::ListModelsResponse>> {
+		let address = format!("{}/models", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "listModels", name: "list_models", path: "/models" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Kicks off a new run for a given evaluation, specifying the data source, and what model configuration to use to test. The datasource will be validated against the schema specified in the config of the evaluation. */
-	pub async fn create_eval_run(&self, eval_id: &str, request_body: crate::types::CreateEvalRunRequest, ) -> ConversaResult<crate::types::EvalRun> {
-		let address = format!("{}/evals/{eval_id}/runs", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** Retrieves a model instance, providing basic information about the model such as the owner and permissioning. */
+	pub async fn retrieve_model(&self, model: &str, ) -> ConversaResult<crate::types::Model> {
+		self.retrieve_model_with_meta(model, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::retrieve_model`] but also returns the metadata of the response. */
+	pub async fn retrieve_model_with_meta(&self, model: &str, ) -> ConversaResult<WithMeta<crate::types::Model>> {
+		let address = format!("{}/models/{model}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "retrieveModel", name: "retrieve_model", path: "/models/{model}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 201 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Get an evaluation run by ID. */
-	pub async fn get_eval_run(&self, eval_id: &str, run_id: &str, ) -> ConversaResult<crate::types::EvalRun> {
-		let address = format!("{}/evals/{eval_id}/runs/{run_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Delete a fine-tuned model. You must have the Owner role in your organization to delete a model. */
+	pub async fn delete_model(&self, model: &str, ) -> ConversaResult<crate::types::DeleteModelResponse> {
+		self.delete_model_with_meta(model, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_model`] but also returns the metadata of the response. */
+	pub async fn delete_model_with_meta(&self, model: &str, ) -> ConversaResult<WithMeta<crate::types::DeleteModelResponse>> {
+		let address = format!("{}/models/{model}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "deleteModel", name: "delete_model", path: "/models/{model}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Cancel an ongoing evaluation run. */
-	pub async fn cancel_eval_run(&self, eval_id: &str, run_id: &str, ) -> ConversaResult<crate::types::EvalRun> {
-		let address = format!("{}/evals/{eval_id}/runs/{run_id}", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Classifies if text and/or image inputs are potentially harmful. Learn
+more in the [moderation guide](/docs/guides/moderation). */
+	pub async fn create_moderation(&self, request_body: crate::types::CreateModerationRequest, ) -> ConversaResult<crate::types::CreateModerationResponse> {
+		self.create_moderation_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_moderation`] but also returns the metadata of the response. */
+	pub async fn create_moderation_with_meta(&self, request_body: crate::types::CreateModerationRequest, ) -> ConversaResult<WithMeta<crate::types::CreateModerationResponse>> {
+		let address = format!("{}/moderations", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createModeration", name: "create_moderation", path: "/moderations" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Delete an eval run. */
-	pub async fn delete_eval_run(&self, eval_id: &str, run_id: &str, ) -> ConversaResult<DeleteEvalRunResponse> {
-		let address = format!("{}/evals/{eval_id}/runs/{run_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Creates a model response. Provide [text](/docs/guides/text) or
+[image](/docs/guides/images) inputs to generate [text](/docs/guides/text)
+or [JSON](/docs/guides/structured-outputs) outputs. Have the model call
+your own [custom code](/docs/guides/function-calling) or use built-in
+[tools](/docs/guides/tools) like [web search](/docs/guides/tools-web-search)
+or [file search](/docs/guides/tools-file-search) to use your own data
+as input for the model's response. */
+	pub async fn create_response(&self, request_body: crate::types::CreateResponse, ) -> ConversaResult<CreateResponseResponse> {
+		self.create_response_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_response`] but also returns the metadata of the response. */
+	pub async fn create_response_with_meta(&self, request_body: crate::types::CreateResponse, ) -> ConversaResult<WithMeta<CreateResponseResponse>> {
+		let address = format!("{}/responses", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createResponse", name: "create_response", path: "/responses" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = match meta.content_type.as_deref() {
+			Some("text/event-stream") => CreateResponseResponse::TextEventStream(serde_json::from_slice(&response_bytes)?),
+			_ => CreateResponseResponse::ApplicationJson(serde_json::from_slice(&response_bytes)?),
+		};
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Get a list of output items for an evaluation run. */
-	pub async fn get_eval_run_output_items(&self, eval_id: &str, run_id: &str, after: Option<&str>, limit: Option<u64>, status: Option<&str>, order: Option<&str>, ) -> ConversaResult<crate::types::EvalRunOutputItemList> {
-		let address = format!("{}/evals/{eval_id}/runs/{run_id}/output_items", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
-		}
-		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+	/** Retrieves a model response with the given ID. */
+	pub async fn get_response(&self, response_id: &str, include: Option<&[crate::types::Includable]>, stream: Option<bool>, starting_after: Option<u64>, ) -> ConversaResult<crate::types::Response> {
+		self.get_response_with_meta(response_id, include, stream, starting_after, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_response`] but also returns the metadata of the response. */
+	pub async fn get_response_with_meta(&self, response_id: &str, include: Option<&[crate::types::Includable]>, stream: Option<bool>, starting_after: Option<u64>, ) -> ConversaResult<WithMeta<crate::types::Response>> {
+		let address = format!("{}/responses/{response_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		if let Some(q) = include {
+			request = request.query(&[("include", q)])?;
 		}
-		if let Some(q) = status {
-			request = request.query(&[("status", q)]);
+		if let Some(q) = stream {
+			request = request.query(&[("stream", q)])?;
 		}
-		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+		if let Some(q) = starting_after {
+			request = request.query(&[("starting_after", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "getResponse", name: "get_response", path: "/responses/{response_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Get an evaluation run output item by ID. */
-	pub async fn get_eval_run_output_item(&self, eval_id: &str, run_id: &str, output_item_id: &str, ) -> ConversaResult<crate::types::EvalRunOutputItem> {
-		let address = format!("{}/evals/{eval_id}/runs/{run_id}/output_items/{output_item_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Deletes a model response with the given ID. */
+	pub async fn delete_response(&self, response_id: &str, ) -> ConversaResult<()> {
+		self.delete_response_with_meta(response_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_response`] but also returns the metadata of the response. */
+	pub async fn delete_response_with_meta(&self, response_id: &str, ) -> ConversaResult<WithMeta<()>> {
+		let address = format!("{}/responses/{response_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "deleteResponse", name: "delete_response", path: "/responses/{response_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			let mut error = ApiError::from_response(meta, &response_bytes);
+			if status_code == 404 {
+				error.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::Error);
+			}
+			return Err(error.into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = ();
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Returns a list of files. */
-	pub async fn list_files(&self, purpose: Option<&str>, limit: Option<u64>, order: Option<&str>, after: Option<&str>, ) -> ConversaResult<crate::types::ListFilesResponse> {
-		let address = format!("{}/files", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = purpose {
-			request = request.query(&[("purpose", q)]);
+	/** Cancels a model response with the given ID. Only responses created with
+the `background` parameter set to `true` can be cancelled. 
+[Learn more](/docs/guides/background). */
+	pub async fn cancel_response(&self, response_id: &str, ) -> ConversaResult<crate::types::Response> {
+		self.cancel_response_with_meta(response_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::cancel_response`] but also returns the metadata of the response. */
+	pub async fn cancel_response_with_meta(&self, response_id: &str, ) -> ConversaResult<WithMeta<crate::types::Response>> {
+		let address = format!("{}/responses/{response_id}/cancel", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::POST, address);
+		let result = self.send(request, Operation { id: "cancelResponse", name: "cancel_response", path: "/responses/{response_id}/cancel" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			let mut error = ApiError::from_response(meta, &response_bytes);
+			if status_code == 404 {
+				error.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::Error);
+			}
+			return Err(error.into());
 		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Returns a list of input items for a given response. */
+	pub async fn list_input_items(&self, response_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, include: Option<&[crate::types::Includable]>, ) -> ConversaResult<crate::types::ResponseItemList> {
+		self.list_input_items_with_meta(response_id, limit, order, after, before, include, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_input_items`] but also returns the metadata of the response. */
+	pub async fn list_input_items_with_meta(&self, response_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, include: Option<&[crate::types::Includable]>, ) -> ConversaResult<WithMeta<crate::types::ResponseItemList>> {
+		let address = format!("{}/responses/{response_id}/input_items", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+			request = request.query(&[("order", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if let Some(q) = before {
+			request = request.query(&[("before", q)])?;
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if let Some(q) = include {
+			request = request.query(&[("include", q)])?;
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		let result = self.send(request, Operation { id: "listInputItems", name: "list_input_items", path: "/responses/{response_id}/input_items" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Upload a file that can be used across various endpoints. Individual files can be up to 512 MB, and the size of all files uploaded by one organization can be up to 100 GB.
+	/** Creates an intermediate [Upload](/docs/api-reference/uploads/object) object
+that you can add [Parts](/docs/api-reference/uploads/part-object) to.
+Currently, an Upload can accept at most 8 GB in total and expires after an
+hour after you create it.
 
-The Assistants API supports files up to 2 million tokens and of specific file types. See the [Assistants Tools guide](/docs/assistants/tools) for details.
+Once you complete the Upload, we will create a
+[File](/docs/api-reference/files/object) object that contains all the parts
+you uploaded. This File is usable in the rest of our platform as a regular
+File object.
 
-The Fine-tuning API only supports `.jsonl` files. The input also has certain required formats for fine-tuning [chat](/docs/api-reference/fine-tuning/chat-input) or [completions](/docs/api-reference/fine-tuning/completions-input) models.
+For certain `purpose` values, the correct `mime_type` must be specified. 
+Please refer to documentation for the 
+[supported MIME types for your use case](/docs/assistants/tools/file-search#supported-files).
 
-The Batch API only supports `.jsonl` files up to 200 MB in size. The input also has a specific required [format](/docs/api-reference/batch/request-input).
+For guidance on the proper filename extensions for each purpose, please
+follow the documentation on [creating a
+File](/docs/api-reference/files/create). */
+	pub async fn create_upload(&self, request_body: crate::types::CreateUploadRequest, ) -> ConversaResult<crate::types::Upload> {
+		self.create_upload_with_meta(request_body, ).await.map(|r| r.data)
+	}
 
-Please [contact us](https://help.openai.com/) if you need to increase these storage limits. */
-	pub async fn create_file(&self, request_body: crate::types::CreateFileRequest, ) -> ConversaResult<crate::types::OpenAIFile> {
-		let address = format!("{}/files", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.multipart(request_body.into_multipart_form());
-		let result = request.send().await?;
+	/** Same as [`Self::create_upload`] but also returns the metadata of the response. */
+	pub async fn create_upload_with_meta(&self, request_body: crate::types::CreateUploadRequest, ) -> ConversaResult<WithMeta<crate::types::Upload>> {
+		let address = format!("{}/uploads", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createUpload", name: "create_upload", path: "/uploads" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Delete a file. */
-	pub async fn delete_file(&self, file_id: &str, ) -> ConversaResult<crate::types::DeleteFileResponse> {
-		let address = format!("{}/files/{file_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+	/** Cancels the Upload. No Parts may be added after an Upload is cancelled. */
+	pub async fn cancel_upload(&self, upload_id: &str, ) -> ConversaResult<crate::types::Upload> {
+		self.cancel_upload_with_meta(upload_id, ).await.map(|r| r.data)
 	}
 
-	/** Returns information about a specific file. */
-	pub async fn retrieve_file(&self, file_id: &str, ) -> ConversaResult<crate::types::OpenAIFile> {
-		let address = format!("{}/files/{file_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Same as [`Self::cancel_upload`] but also returns the metadata of the response. */
+	pub async fn cancel_upload_with_meta(&self, upload_id: &str, ) -> ConversaResult<WithMeta<crate::types::Upload>> {
+		let address = format!("{}/uploads/{upload_id}/cancel", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::POST, address);
+		let result = self.send(request, Operation { id: "cancelUpload", name: "cancel_upload", path: "/uploads/{upload_id}/cancel" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Returns the contents of the specified file. */
-	pub async fn download_file(&self, file_id: &str, ) -> ConversaResult<String> {
-		let address = format!("{}/files/{file_id}/content", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(String::from_utf8(response_bytes.to_vec())?)
+	/** Completes the [Upload](/docs/api-reference/uploads/object). 
+
+Within the returned Upload object, there is a nested [File](/docs/api-reference/files/object) object that is ready to use in the rest of the platform.
+
+You can specify the order of the Parts by passing in an ordered list of the Part IDs.
+
+The number of bytes uploaded upon completion must match the number of bytes initially specified when creating the Upload object. No Parts may be added after an Upload is completed. */
+	pub async fn complete_upload(&self, upload_id: &str, request_body: crate::types::CompleteUploadRequest, ) -> ConversaResult<crate::types::Upload> {
+		self.complete_upload_with_meta(upload_id, request_body, ).await.map(|r| r.data)
 	}
 
-	/** Run a grader. */
-	pub async fn run_grader(&self, request_body: crate::types::RunGraderRequest, ) -> ConversaResult<crate::types::RunGraderResponse> {
-		let address = format!("{}/fine_tuning/alpha/graders/run", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** Same as [`Self::complete_upload`] but also returns the metadata of the response. */
+	pub async fn complete_upload_with_meta(&self, upload_id: &str, request_body: crate::types::CompleteUploadRequest, ) -> ConversaResult<WithMeta<crate::types::Upload>> {
+		let address = format!("{}/uploads/{upload_id}/complete", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "completeUpload", name: "complete_upload", path: "/uploads/{upload_id}/complete" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Validate a grader. */
-	pub async fn validate_grader(&self, request_body: crate::types::ValidateGraderRequest, ) -> ConversaResult<crate::types::ValidateGraderResponse> {
-		let address = format!("{}/fine_tuning/alpha/graders/validate", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** Adds a [Part](/docs/api-reference/uploads/part-object) to an [Upload](/docs/api-reference/uploads/object) object. A Part represents a chunk of bytes from the file you are trying to upload. 
+
+Each Part can be at most 64 MB, and you can add Parts until you hit the Upload maximum of 8 GB.
+
+It is possible to add multiple Parts in parallel. You can decide the intended order of the Parts when you [complete the Upload](/docs/api-reference/uploads/complete). */
+	pub async fn add_upload_part(&self, upload_id: &str, request_body: crate::types::AddUploadPartRequest, ) -> ConversaResult<crate::types::UploadPart> {
+		self.add_upload_part_with_meta(upload_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::add_upload_part`] but also returns the metadata of the response. */
+	pub async fn add_upload_part_with_meta(&self, upload_id: &str, request_body: crate::types::AddUploadPartRequest, ) -> ConversaResult<WithMeta<crate::types::UploadPart>> {
+		let address = format!("{}/uploads/{upload_id}/parts", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.multipart(request_body.into_multipart_form()?);
+		let result = self.send(request, Operation { id: "addUploadPart", name: "add_upload_part", path: "/uploads/{upload_id}/parts" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** **NOTE:** This endpoint requires an [admin API key](../admin-api-keys).
 
-Organization owners can use this endpoint to view all permissions for a fine-tuned model checkpoint. */
-	pub async fn list_fine_tuning_checkpoint_permissions(&self, fine_tuned_model_checkpoint: &str, project_id: Option<&str>, after: Option<&str>, limit: Option<u64>, order: Option<&str>, ) -> ConversaResult<crate::types::ListFineTuningCheckpointPermissionResponse> {
-		let address = format!("{}/fine_tuning/checkpoints/{fine_tuned_model_checkpoint}/permissions", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = project_id {
-			request = request.query(&[("project_id", q)]);
-		}
+}
+#[cfg(feature = "administration")]
+mod administration {
+use super::*;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct AdminApiKeysCreateRequestBody {
+	pub name: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct AdminApiKeysDeleteResponse {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub object: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub deleted: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListAuditLogsQuery {
+	/** Return only events whose `effective_at` (Unix seconds) is greater than this value. */
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub gt: Option<u64>,
+	/** Return only events whose `effective_at` (Unix seconds) is greater than or equal to this value. */
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub gte: Option<u64>,
+	/** Return only events whose `effective_at` (Unix seconds) is less than this value. */
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub lt: Option<u64>,
+	/** Return only events whose `effective_at` (Unix seconds) is less than or equal to this value. */
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub lte: Option<u64>,
+}
+
+impl OpenAIClient {
+	/** List organization API keys */
+	pub async fn admin_api_keys_list(&self, after: Option<&str>, order: Option<&str>, limit: Option<u64>, ) -> ConversaResult<crate::types::ApiKeyList> {
+		self.admin_api_keys_list_with_meta(after, order, limit, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::admin_api_keys_list`] but also returns the metadata of the response. */
+	pub async fn admin_api_keys_list_with_meta(&self, after: Option<&str>, order: Option<&str>, limit: Option<u64>, ) -> ConversaResult<WithMeta<crate::types::ApiKeyList>> {
+		let address = format!("{}/organization/admin_api_keys", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
-		}
-		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("after", q)])?;
 		}
 		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+			request = request.query(&[("order", q)])?;
+		}
+		if let Some(q) = limit {
+			request = request.query(&[("limit", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "admin-api-keys-list", name: "admin_api_keys_list", path: "/organization/admin_api_keys" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** **NOTE:** Calling this endpoint requires an [admin API key](../admin-api-keys).
+	/** Create an organization admin API key */
+	pub async fn admin_api_keys_create(&self, request_body: AdminApiKeysCreateRequestBody, ) -> ConversaResult<crate::types::AdminApiKey> {
+		self.admin_api_keys_create_with_meta(request_body, ).await.map(|r| r.data)
+	}
 
-This enables organization owners to share fine-tuned models with other projects in their organization. */
-	pub async fn create_fine_tuning_checkpoint_permission(&self, fine_tuned_model_checkpoint: &str, request_body: crate::types::CreateFineTuningCheckpointPermissionRequest, ) -> ConversaResult<crate::types::ListFineTuningCheckpointPermissionResponse> {
-		let address = format!("{}/fine_tuning/checkpoints/{fine_tuned_model_checkpoint}/permissions", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** Same as [`Self::admin_api_keys_create`] but also returns the metadata of the response. */
+	pub async fn admin_api_keys_create_with_meta(&self, request_body: AdminApiKeysCreateRequestBody, ) -> ConversaResult<WithMeta<crate::types::AdminApiKey>> {
+		let address = format!("{}/organization/admin_api_keys", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "admin-api-keys-create", name: "admin_api_keys_create", path: "/organization/admin_api_keys" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** **NOTE:** This endpoint requires an [admin API key](../admin-api-keys).
+	/** Retrieve a single organization API key */
+	pub async fn admin_api_keys_get(&self, key_id: &str, ) -> ConversaResult<crate::types::AdminApiKey> {
+		self.admin_api_keys_get_with_meta(key_id, ).await.map(|r| r.data)
+	}
 
-Organization owners can use this endpoint to delete a permission for a fine-tuned model checkpoint. */
-	pub async fn delete_fine_tuning_checkpoint_permission(&self, fine_tuned_model_checkpoint: &str, permission_id: &str, ) -> ConversaResult<crate::types::DeleteFineTuningCheckpointPermissionResponse> {
-		let address = format!("{}/fine_tuning/checkpoints/{fine_tuned_model_checkpoint}/permissions/{permission_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Same as [`Self::admin_api_keys_get`] but also returns the metadata of the response. */
+	pub async fn admin_api_keys_get_with_meta(&self, key_id: &str, ) -> ConversaResult<WithMeta<crate::types::AdminApiKey>> {
+		let address = format!("{}/organization/admin_api_keys/{key_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "admin-api-keys-get", name: "admin_api_keys_get", path: "/organization/admin_api_keys/{key_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Creates a fine-tuning job which begins the process of creating a new model from a given dataset.
-
-Response includes details of the enqueued job including job status and the name of the fine-tuned models once complete.
-
-[Learn more about fine-tuning](/docs/guides/model-optimization) */
-	pub async fn create_fine_tuning_job(&self, request_body: crate::types::CreateFineTuningJobRequest, ) -> ConversaResult<crate::types::FineTuningJob> {
-		let address = format!("{}/fine_tuning/jobs", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+	/** Delete an organization admin API key */
+	pub async fn admin_api_keys_delete(&self, key_id: &str, ) -> ConversaResult<AdminApiKeysDeleteResponse> {
+		self.admin_api_keys_delete_with_meta(key_id, ).await.map(|r| r.data)
 	}
 
-	/** List your organization's fine-tuning jobs */
-	pub async fn list_paginated_fine_tuning_jobs(&self, after: Option<&str>, limit: Option<u64>, metadata: Option<ListPaginatedFineTuningJobsQuery>, ) -> ConversaResult<crate::types::ListPaginatedFineTuningJobsResponse> {
-		let address = format!("{}/fine_tuning/jobs", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
-		}
-		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
-		}
-		if let Some(q) = metadata {
-			request = request.query(&[("metadata", q)]);
-		}
-		let result = request.send().await?;
+	/** Same as [`Self::admin_api_keys_delete`] but also returns the metadata of the response. */
+	pub async fn admin_api_keys_delete_with_meta(&self, key_id: &str, ) -> ConversaResult<WithMeta<AdminApiKeysDeleteResponse>> {
+		let address = format!("{}/organization/admin_api_keys/{key_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "admin-api-keys-delete", name: "admin_api_keys_delete", path: "/organization/admin_api_keys/{key_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Get info about a fine-tuning job.
+	/** List user actions and configuration changes within this organization. */
+	pub async fn list_audit_logs(&self, effective_at: Option<ListAuditLogsQuery>, project_ids: Option<&[String]>, event_types: Option<&[crate::types::AuditLogEventType]>, actor_ids: Option<&[String]>, actor_emails: Option<&[String]>, resource_ids: Option<&[String]>, limit: Option<u64>, after: Option<&str>, before: Option<&str>, ) -> ConversaResult<crate::types::ListAuditLogsResponse> {
+		self.list_audit_logs_with_meta(effective_at, project_ids, event_types, actor_ids, actor_emails, resource_ids, limit, after, before, ).await.map(|r| r.data)
+	}
 
-[Learn more about fine-tuning](/docs/guides/model-optimization) */
-	pub async fn retrieve_fine_tuning_job(&self, fine_tuning_job_id: &str, ) -> ConversaResult<crate::types::FineTuningJob> {
-		let address = format!("{}/fine_tuning/jobs/{fine_tuning_job_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+	/** Same as [`Self::list_audit_logs`] but also returns the metadata of the response. */
+	pub async fn list_audit_logs_with_meta(&self, effective_at: Option<ListAuditLogsQuery>, project_ids: Option<&[String]>, event_types: Option<&[crate::types::AuditLogEventType]>, actor_ids: Option<&[String]>, actor_emails: Option<&[String]>, resource_ids: Option<&[String]>, limit: Option<u64>, after: Option<&str>, before: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ListAuditLogsResponse>> {
+		let address = format!("{}/organization/audit_logs", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		if let Some(q) = effective_at {
+			request = request.query(&[("effective_at", q)])?;
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if let Some(q) = project_ids {
+			request = request.query(&[("project_ids", q)])?;
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if let Some(q) = event_types {
+			request = request.query(&[("event_types", q)])?;
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Immediately cancel a fine-tune job. */
-	pub async fn cancel_fine_tuning_job(&self, fine_tuning_job_id: &str, ) -> ConversaResult<crate::types::FineTuningJob> {
-		let address = format!("{}/fine_tuning/jobs/{fine_tuning_job_id}/cancel", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if let Some(q) = actor_ids {
+			request = request.query(&[("actor_ids", q)])?;
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if let Some(q) = actor_emails {
+			request = request.query(&[("actor_emails", q)])?;
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if let Some(q) = resource_ids {
+			request = request.query(&[("resource_ids", q)])?;
+		}
+		if let Some(q) = limit {
+			request = request.query(&[("limit", q)])?;
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** List checkpoints for a fine-tuning job. */
-	pub async fn list_fine_tuning_job_checkpoints(&self, fine_tuning_job_id: &str, after: Option<&str>, limit: Option<u64>, ) -> ConversaResult<crate::types::ListFineTuningJobCheckpointsResponse> {
-		let address = format!("{}/fine_tuning/jobs/{fine_tuning_job_id}/checkpoints", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
-		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+		if let Some(q) = before {
+			request = request.query(&[("before", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "list-audit-logs", name: "list_audit_logs", path: "/organization/audit_logs" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Get status updates for a fine-tuning job. */
-	pub async fn list_fine_tuning_events(&self, fine_tuning_job_id: &str, after: Option<&str>, limit: Option<u64>, ) -> ConversaResult<crate::types::ListFineTuningJobEventsResponse> {
-		let address = format!("{}/fine_tuning/jobs/{fine_tuning_job_id}/events", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+	/** List uploaded certificates for this organization. */
+	pub async fn list_organization_certificates(&self, limit: Option<u64>, after: Option<&str>, order: Option<&str>, ) -> ConversaResult<crate::types::ListCertificatesResponse> {
+		self.list_organization_certificates_with_meta(limit, after, order, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_organization_certificates`] but also returns the metadata of the response. */
+	pub async fn list_organization_certificates_with_meta(&self, limit: Option<u64>, after: Option<&str>, order: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ListCertificatesResponse>> {
+		let address = format!("{}/organization/certificates", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		if let Some(q) = limit {
+			request = request.query(&[("limit", q)])?;
+		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
-		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+		if let Some(q) = order {
+			request = request.query(&[("order", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "listOrganizationCertificates", name: "list_organization_certificates", path: "/organization/certificates" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Pause a fine-tune job. */
-	pub async fn pause_fine_tuning_job(&self, fine_tuning_job_id: &str, ) -> ConversaResult<crate::types::FineTuningJob> {
-		let address = format!("{}/fine_tuning/jobs/{fine_tuning_job_id}/pause", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+	/** Upload a certificate to the organization. This does **not** automatically activate the certificate.
+
+Organizations can upload up to 50 certificates. */
+	pub async fn upload_certificate(&self, request_body: crate::types::UploadCertificateRequest, ) -> ConversaResult<crate::types::Certificate> {
+		self.upload_certificate_with_meta(request_body, ).await.map(|r| r.data)
 	}
 
-	/** Resume a fine-tune job. */
-	pub async fn resume_fine_tuning_job(&self, fine_tuning_job_id: &str, ) -> ConversaResult<crate::types::FineTuningJob> {
-		let address = format!("{}/fine_tuning/jobs/{fine_tuning_job_id}/resume", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Same as [`Self::upload_certificate`] but also returns the metadata of the response. */
+	pub async fn upload_certificate_with_meta(&self, request_body: crate::types::UploadCertificateRequest, ) -> ConversaResult<WithMeta<crate::types::Certificate>> {
+		let address = format!("{}/organization/certificates", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "uploadCertificate", name: "upload_certificate", path: "/organization/certificates" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Creates an edited or extended image given one or more source images and a prompt. This endpoint only supports `gpt-image-1` and `dall-e-2`. */
-	pub async fn create_image_edit(&self, request_body: crate::types::CreateImageEditRequest, ) -> ConversaResult<crate::types::ImagesResponse> {
-		let address = format!("{}/images/edits", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.multipart(request_body.into_multipart_form());
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+	/** Activate certificates at the organization level.
+
+You can atomically and idempotently activate up to 10 certificates at a time. */
+	pub async fn activate_organization_certificates(&self, request_body: crate::types::ToggleCertificatesRequest, ) -> ConversaResult<crate::types::ListCertificatesResponse> {
+		self.activate_organization_certificates_with_meta(request_body, ).await.map(|r| r.data)
 	}
 
-	/** Creates an image given a prompt. [Learn more](/docs/guides/images). */
-	pub async fn create_image(&self, request_body: crate::types::CreateImageRequest, ) -> ConversaResult<crate::types::ImagesResponse> {
-		let address = format!("{}/images/generations", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** Same as [`Self::activate_organization_certificates`] but also returns the metadata of the response. */
+	pub async fn activate_organization_certificates_with_meta(&self, request_body: crate::types::ToggleCertificatesRequest, ) -> ConversaResult<WithMeta<crate::types::ListCertificatesResponse>> {
+		let address = format!("{}/organization/certificates/activate", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "activateOrganizationCertificates", name: "activate_organization_certificates", path: "/organization/certificates/activate" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Creates a variation of a given image. This endpoint only supports `dall-e-2`. */
-	pub async fn create_image_variation(&self, request_body: crate::types::CreateImageVariationRequest, ) -> ConversaResult<crate::types::ImagesResponse> {
-		let address = format!("{}/images/variations", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.multipart(request_body.into_multipart_form());
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+	/** Deactivate certificates at the organization level.
+
+You can atomically and idempotently deactivate up to 10 certificates at a time. */
+	pub async fn deactivate_organization_certificates(&self, request_body: crate::types::ToggleCertificatesRequest, ) -> ConversaResult<crate::types::ListCertificatesResponse> {
+		self.deactivate_organization_certificates_with_meta(request_body, ).await.map(|r| r.data)
 	}
 
-	/** Lists the currently available models, and provides basic information about each one such as the owner and availability. */
-	pub async fn list_models(&self, ) -> ConversaResult<crate::types::ListModelsResponse> {
-		let address = format!("{}/models", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Same as [`Self::deactivate_organization_certificates`] but also returns the metadata of the response. */
+	pub async fn deactivate_organization_certificates_with_meta(&self, request_body: crate::types::ToggleCertificatesRequest, ) -> ConversaResult<WithMeta<crate::types::ListCertificatesResponse>> {
+		let address = format!("{}/organization/certificates/deactivate", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "deactivateOrganizationCertificates", name: "deactivate_organization_certificates", path: "/organization/certificates/deactivate" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Retrieves a model instance, providing basic information about the model such as the owner and permissioning. */
-	pub async fn retrieve_model(&self, model: &str, ) -> ConversaResult<crate::types::Model> {
-		let address = format!("{}/models/{model}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Get a certificate that has been uploaded to the organization.
+
+You can get a certificate regardless of whether it is active or not. */
+	pub async fn get_certificate(&self, certificate_id: &str, include: Option<&[String]>, ) -> ConversaResult<crate::types::Certificate> {
+		self.get_certificate_with_meta(certificate_id, include, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_certificate`] but also returns the metadata of the response. */
+	pub async fn get_certificate_with_meta(&self, certificate_id: &str, include: Option<&[String]>, ) -> ConversaResult<WithMeta<crate::types::Certificate>> {
+		let address = format!("{}/organization/certificates/{certificate_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		if let Some(q) = include {
+			request = request.query(&[("include", q)])?;
+		}
+		let result = self.send(request, Operation { id: "getCertificate", name: "get_certificate", path: "/organization/certificates/{certificate_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Delete a fine-tuned model. You must have the Owner role in your organization to delete a model. */
-	pub async fn delete_model(&self, model: &str, ) -> ConversaResult<crate::types::DeleteModelResponse> {
-		let address = format!("{}/models/{model}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Modify a certificate. Note that only the name can be modified. */
+	pub async fn modify_certificate(&self, certificate_id: &str, request_body: crate::types::ModifyCertificateRequest, ) -> ConversaResult<crate::types::Certificate> {
+		self.modify_certificate_with_meta(certificate_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::modify_certificate`] but also returns the metadata of the response. */
+	pub async fn modify_certificate_with_meta(&self, certificate_id: &str, request_body: crate::types::ModifyCertificateRequest, ) -> ConversaResult<WithMeta<crate::types::Certificate>> {
+		let address = format!("{}/organization/certificates/{certificate_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "modifyCertificate", name: "modify_certificate", path: "/organization/certificates/{certificate_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Classifies if text and/or image inputs are potentially harmful. Learn
-more in the [moderation guide](/docs/guides/moderation). */
-	pub async fn create_moderation(&self, request_body: crate::types::CreateModerationRequest, ) -> ConversaResult<crate::types::CreateModerationResponse> {
-		let address = format!("{}/moderations", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** Delete a certificate from the organization.
+
+The certificate must be inactive for the organization and all projects. */
+	pub async fn delete_certificate(&self, certificate_id: &str, ) -> ConversaResult<crate::types::DeleteCertificateResponse> {
+		self.delete_certificate_with_meta(certificate_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_certificate`] but also returns the metadata of the response. */
+	pub async fn delete_certificate_with_meta(&self, certificate_id: &str, ) -> ConversaResult<WithMeta<crate::types::DeleteCertificateResponse>> {
+		let address = format!("{}/organization/certificates/{certificate_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "deleteCertificate", name: "delete_certificate", path: "/organization/certificates/{certificate_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** List organization API keys */
-	pub async fn admin_api_keys_list(&self, after: Option<&str>, order: Option<&str>, limit: Option<u64>, ) -> ConversaResult<crate::types::ApiKeyList> {
-		let address = format!("{}/organization/admin_api_keys", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+	/** Get costs details for the organization. */
+	pub async fn usage_costs(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<crate::types::UsageResponse> {
+		self.usage_costs_with_meta(start_time, end_time, bucket_width, project_ids, group_by, limit, page, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::usage_costs`] but also returns the metadata of the response. */
+	pub async fn usage_costs_with_meta(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::UsageResponse>> {
+		let address = format!("{}/organization/costs", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = request.query(&[("start_time",start_time)])?;
+		if let Some(q) = end_time {
+			request = request.query(&[("end_time", q)])?;
 		}
-		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+		if let Some(q) = bucket_width {
+			request = request.query(&[("bucket_width", q)])?;
+		}
+		if let Some(q) = project_ids {
+			request = request.query(&[("project_ids", q)])?;
+		}
+		if let Some(q) = group_by {
+			request = request.query(&[("group_by", q)])?;
 		}
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
+		}
+		if let Some(q) = page {
+			request = request.query(&[("page", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "usage-costs", name: "usage_costs", path: "/organization/costs" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Create an organization admin API key */
-	pub async fn admin_api_keys_create(&self, request_body: AdminApiKeysCreateRequestBody, ) -> ConversaResult<crate::types::AdminApiKey> {
-		let address = format!("{}/organization/admin_api_keys", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Retrieve a single organization API key */
-	pub async fn admin_api_keys_get(&self, key_id: &str, ) -> ConversaResult<crate::types::AdminApiKey> {
-		let address = format!("{}/organization/admin_api_keys/{key_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Delete an organization admin API key */
-	pub async fn admin_api_keys_delete(&self, key_id: &str, ) -> ConversaResult<AdminApiKeysDeleteResponse> {
-		let address = format!("{}/organization/admin_api_keys/{key_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** List user actions and configuration changes within this organization. */
-	pub async fn list_audit_logs(&self, effective_at: Option<ListAuditLogsQuery>, project_ids: Option<&[String]>, event_types: Option<&[crate::types::AuditLogEventType]>, actor_ids: Option<&[String]>, actor_emails: Option<&[String]>, resource_ids: Option<&[String]>, limit: Option<u64>, after: Option<&str>, before: Option<&str>, ) -> ConversaResult<crate::types::ListAuditLogsResponse> {
-		let address = format!("{}/organization/audit_logs", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = effective_at {
-			request = request.query(&[("effective_at", q)]);
-		}
-		if let Some(q) = project_ids {
-			request = request.query(&[("project_ids", q)]);
-		}
-		if let Some(q) = event_types {
-			request = request.query(&[("event_types", q)]);
-		}
-		if let Some(q) = actor_ids {
-			request = request.query(&[("actor_ids", q)]);
-		}
-		if let Some(q) = actor_emails {
-			request = request.query(&[("actor_emails", q)]);
-		}
-		if let Some(q) = resource_ids {
-			request = request.query(&[("resource_ids", q)]);
-		}
-		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
-		}
-		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
-		}
-		if let Some(q) = before {
-			request = request.query(&[("before", q)]);
-		}
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** List uploaded certificates for this organization. */
-	pub async fn list_organization_certificates(&self, limit: Option<u64>, after: Option<&str>, order: Option<&str>, ) -> ConversaResult<crate::types::ListCertificatesResponse> {
-		let address = format!("{}/organization/certificates", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
-		}
-		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
-		}
-		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
-		}
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Upload a certificate to the organization. This does **not** automatically activate the certificate.
-
-Organizations can upload up to 50 certificates. */
-	pub async fn upload_certificate(&self, request_body: crate::types::UploadCertificateRequest, ) -> ConversaResult<crate::types::Certificate> {
-		let address = format!("{}/organization/certificates", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Activate certificates at the organization level.
-
-You can atomically and idempotently activate up to 10 certificates at a time. */
-	pub async fn activate_organization_certificates(&self, request_body: crate::types::ToggleCertificatesRequest, ) -> ConversaResult<crate::types::ListCertificatesResponse> {
-		let address = format!("{}/organization/certificates/activate", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Deactivate certificates at the organization level.
-
-You can atomically and idempotently deactivate up to 10 certificates at a time. */
-	pub async fn deactivate_organization_certificates(&self, request_body: crate::types::ToggleCertificatesRequest, ) -> ConversaResult<crate::types::ListCertificatesResponse> {
-		let address = format!("{}/organization/certificates/deactivate", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Get a certificate that has been uploaded to the organization.
-
-You can get a certificate regardless of whether it is active or not. */
-	pub async fn get_certificate(&self, certificate_id: &str, include: Option<&[String]>, ) -> ConversaResult<crate::types::Certificate> {
-		let address = format!("{}/organization/certificates/{certificate_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = include {
-			request = request.query(&[("include", q)]);
-		}
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Modify a certificate. Note that only the name can be modified. */
-	pub async fn modify_certificate(&self, certificate_id: &str, request_body: crate::types::ModifyCertificateRequest, ) -> ConversaResult<crate::types::Certificate> {
-		let address = format!("{}/organization/certificates/{certificate_id}", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Delete a certificate from the organization.
-
-The certificate must be inactive for the organization and all projects. */
-	pub async fn delete_certificate(&self, certificate_id: &str, ) -> ConversaResult<crate::types::DeleteCertificateResponse> {
-		let address = format!("{}/organization/certificates/{certificate_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
-	}
-
-	/** Get costs details for the organization. */
-	pub async fn usage_costs(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<crate::types::UsageResponse> {
-		let address = format!("{}/organization/costs", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.query(&[("start_time",start_time)]);
-		if let Some(q) = end_time {
-			request = request.query(&[("end_time", q)]);
-		}
-		if let Some(q) = bucket_width {
-			request = request.query(&[("bucket_width", q)]);
-		}
-		if let Some(q) = project_ids {
-			request = request.query(&[("project_ids", q)]);
-		}
-		if let Some(q) = group_by {
-			request = request.query(&[("group_by", q)]);
-		}
-		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
-		}
-		if let Some(q) = page {
-			request = request.query(&[("page", q)]);
-		}
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Returns a list of invites in the organization. */
 	pub async fn list_invites(&self, limit: Option<u64>, after: Option<&str>, ) -> ConversaResult<crate::types::InviteListResponse> {
+		self.list_invites_with_meta(limit, after, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_invites`] but also returns the metadata of the response. */
+	pub async fn list_invites_with_meta(&self, limit: Option<u64>, after: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::InviteListResponse>> {
 		let address = format!("{}/organization/invites", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "list-invites", name: "list_invites", path: "/organization/invites" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Create an invite for a user to the organization. The invite must be accepted by the user before they have access to the organization. */
 	pub async fn invite_user(&self, request_body: crate::types::InviteRequest, ) -> ConversaResult<crate::types::Invite> {
+		self.invite_user_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::invite_user`] but also returns the metadata of the response. */
+	pub async fn invite_user_with_meta(&self, request_body: crate::types::InviteRequest, ) -> ConversaResult<WithMeta<crate::types::Invite>> {
 		let address = format!("{}/organization/invites", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "inviteUser", name: "invite_user", path: "/organization/invites" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Retrieves an invite. */
 	pub async fn retrieve_invite(&self, invite_id: &str, ) -> ConversaResult<crate::types::Invite> {
+		self.retrieve_invite_with_meta(invite_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::retrieve_invite`] but also returns the metadata of the response. */
+	pub async fn retrieve_invite_with_meta(&self, invite_id: &str, ) -> ConversaResult<WithMeta<crate::types::Invite>> {
 		let address = format!("{}/organization/invites/{invite_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "retrieve-invite", name: "retrieve_invite", path: "/organization/invites/{invite_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Delete an invite. If the invite has already been accepted, it cannot be deleted. */
 	pub async fn delete_invite(&self, invite_id: &str, ) -> ConversaResult<crate::types::InviteDeleteResponse> {
+		self.delete_invite_with_meta(invite_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_invite`] but also returns the metadata of the response. */
+	pub async fn delete_invite_with_meta(&self, invite_id: &str, ) -> ConversaResult<WithMeta<crate::types::InviteDeleteResponse>> {
 		let address = format!("{}/organization/invites/{invite_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "delete-invite", name: "delete_invite", path: "/organization/invites/{invite_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Returns a list of projects. */
 	pub async fn list_projects(&self, limit: Option<u64>, after: Option<&str>, include_archived: Option<bool>, ) -> ConversaResult<crate::types::ProjectListResponse> {
+		self.list_projects_with_meta(limit, after, include_archived, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_projects`] but also returns the metadata of the response. */
+	pub async fn list_projects_with_meta(&self, limit: Option<u64>, after: Option<&str>, include_archived: Option<bool>, ) -> ConversaResult<WithMeta<crate::types::ProjectListResponse>> {
 		let address = format!("{}/organization/projects", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
 		if let Some(q) = include_archived {
-			request = request.query(&[("include_archived", q)]);
+			request = request.query(&[("include_archived", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "list-projects", name: "list_projects", path: "/organization/projects" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Create a new project in the organization. Projects can be created and archived, but cannot be deleted. */
 	pub async fn create_project(&self, request_body: crate::types::ProjectCreateRequest, ) -> ConversaResult<crate::types::Project> {
+		self.create_project_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_project`] but also returns the metadata of the response. */
+	pub async fn create_project_with_meta(&self, request_body: crate::types::ProjectCreateRequest, ) -> ConversaResult<WithMeta<crate::types::Project>> {
 		let address = format!("{}/organization/projects", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "create-project", name: "create_project", path: "/organization/projects" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Retrieves a project. */
 	pub async fn retrieve_project(&self, project_id: &str, ) -> ConversaResult<crate::types::Project> {
+		self.retrieve_project_with_meta(project_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::retrieve_project`] but also returns the metadata of the response. */
+	pub async fn retrieve_project_with_meta(&self, project_id: &str, ) -> ConversaResult<WithMeta<crate::types::Project>> {
 		let address = format!("{}/organization/projects/{project_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "retrieve-project", name: "retrieve_project", path: "/organization/projects/{project_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Modifies a project in the organization. */
 	pub async fn modify_project(&self, project_id: &str, request_body: crate::types::ProjectUpdateRequest, ) -> ConversaResult<crate::types::Project> {
+		self.modify_project_with_meta(project_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::modify_project`] but also returns the metadata of the response. */
+	pub async fn modify_project_with_meta(&self, project_id: &str, request_body: crate::types::ProjectUpdateRequest, ) -> ConversaResult<WithMeta<crate::types::Project>> {
 		let address = format!("{}/organization/projects/{project_id}", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "modify-project", name: "modify_project", path: "/organization/projects/{project_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			let mut error = ApiError::from_response(meta, &response_bytes);
+			if status_code == 400 {
+				error.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::ErrorResponse);
+			}
+			return Err(error.into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Returns a list of API keys in the project. */
 	pub async fn list_project_api_keys(&self, project_id: &str, limit: Option<u64>, after: Option<&str>, ) -> ConversaResult<crate::types::ProjectApiKeyListResponse> {
+		self.list_project_api_keys_with_meta(project_id, limit, after, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_project_api_keys`] but also returns the metadata of the response. */
+	pub async fn list_project_api_keys_with_meta(&self, project_id: &str, limit: Option<u64>, after: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ProjectApiKeyListResponse>> {
 		let address = format!("{}/organization/projects/{project_id}/api_keys", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "list-project-api-keys", name: "list_project_api_keys", path: "/organization/projects/{project_id}/api_keys" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Retrieves an API key in the project. */
 	pub async fn retrieve_project_api_key(&self, project_id: &str, key_id: &str, ) -> ConversaResult<crate::types::ProjectApiKey> {
+		self.retrieve_project_api_key_with_meta(project_id, key_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::retrieve_project_api_key`] but also returns the metadata of the response. */
+	pub async fn retrieve_project_api_key_with_meta(&self, project_id: &str, key_id: &str, ) -> ConversaResult<WithMeta<crate::types::ProjectApiKey>> {
 		let address = format!("{}/organization/projects/{project_id}/api_keys/{key_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "retrieve-project-api-key", name: "retrieve_project_api_key", path: "/organization/projects/{project_id}/api_keys/{key_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Deletes an API key from the project. */
 	pub async fn delete_project_api_key(&self, project_id: &str, key_id: &str, ) -> ConversaResult<crate::types::ProjectApiKeyDeleteResponse> {
+		self.delete_project_api_key_with_meta(project_id, key_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_project_api_key`] but also returns the metadata of the response. */
+	pub async fn delete_project_api_key_with_meta(&self, project_id: &str, key_id: &str, ) -> ConversaResult<WithMeta<crate::types::ProjectApiKeyDeleteResponse>> {
 		let address = format!("{}/organization/projects/{project_id}/api_keys/{key_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "delete-project-api-key", name: "delete_project_api_key", path: "/organization/projects/{project_id}/api_keys/{key_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			let mut error = ApiError::from_response(meta, &response_bytes);
+			if status_code == 400 {
+				error.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::ErrorResponse);
+			}
+			return Err(error.into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Archives a project in the organization. Archived projects cannot be used or updated. */
 	pub async fn archive_project(&self, project_id: &str, ) -> ConversaResult<crate::types::Project> {
+		self.archive_project_with_meta(project_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::archive_project`] but also returns the metadata of the response. */
+	pub async fn archive_project_with_meta(&self, project_id: &str, ) -> ConversaResult<WithMeta<crate::types::Project>> {
 		let address = format!("{}/organization/projects/{project_id}/archive", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::POST, address);
+		let result = self.send(request, Operation { id: "archive-project", name: "archive_project", path: "/organization/projects/{project_id}/archive" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** List certificates for this project. */
 	pub async fn list_project_certificates(&self, project_id: &str, limit: Option<u64>, after: Option<&str>, order: Option<&str>, ) -> ConversaResult<crate::types::ListCertificatesResponse> {
+		self.list_project_certificates_with_meta(project_id, limit, after, order, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_project_certificates`] but also returns the metadata of the response. */
+	pub async fn list_project_certificates_with_meta(&self, project_id: &str, limit: Option<u64>, after: Option<&str>, order: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ListCertificatesResponse>> {
 		let address = format!("{}/organization/projects/{project_id}/certificates", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
 		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+			request = request.query(&[("order", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "listProjectCertificates", name: "list_project_certificates", path: "/organization/projects/{project_id}/certificates" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Activate certificates at the project level.
 
 You can atomically and idempotently activate up to 10 certificates at a time. */
 	pub async fn activate_project_certificates(&self, project_id: &str, request_body: crate::types::ToggleCertificatesRequest, ) -> ConversaResult<crate::types::ListCertificatesResponse> {
+		self.activate_project_certificates_with_meta(project_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::activate_project_certificates`] but also returns the metadata of the response. */
+	pub async fn activate_project_certificates_with_meta(&self, project_id: &str, request_body: crate::types::ToggleCertificatesRequest, ) -> ConversaResult<WithMeta<crate::types::ListCertificatesResponse>> {
 		let address = format!("{}/organization/projects/{project_id}/certificates/activate", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "activateProjectCertificates", name: "activate_project_certificates", path: "/organization/projects/{project_id}/certificates/activate" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Deactivate certificates at the project level. You can atomically and 
 idempotently deactivate up to 10 certificates at a time. */
 	pub async fn deactivate_project_certificates(&self, project_id: &str, request_body: crate::types::ToggleCertificatesRequest, ) -> ConversaResult<crate::types::ListCertificatesResponse> {
+		self.deactivate_project_certificates_with_meta(project_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::deactivate_project_certificates`] but also returns the metadata of the response. */
+	pub async fn deactivate_project_certificates_with_meta(&self, project_id: &str, request_body: crate::types::ToggleCertificatesRequest, ) -> ConversaResult<WithMeta<crate::types::ListCertificatesResponse>> {
 		let address = format!("{}/organization/projects/{project_id}/certificates/deactivate", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "deactivateProjectCertificates", name: "deactivate_project_certificates", path: "/organization/projects/{project_id}/certificates/deactivate" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Returns the rate limits per model for a project. */
 	pub async fn list_project_rate_limits(&self, project_id: &str, limit: Option<u64>, after: Option<&str>, before: Option<&str>, ) -> ConversaResult<crate::types::ProjectRateLimitListResponse> {
+		self.list_project_rate_limits_with_meta(project_id, limit, after, before, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_project_rate_limits`] but also returns the metadata of the response. */
+	pub async fn list_project_rate_limits_with_meta(&self, project_id: &str, limit: Option<u64>, after: Option<&str>, before: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ProjectRateLimitListResponse>> {
 		let address = format!("{}/organization/projects/{project_id}/rate_limits", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
 		if let Some(q) = before {
-			request = request.query(&[("before", q)]);
+			request = request.query(&[("before", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "list-project-rate-limits", name: "list_project_rate_limits", path: "/organization/projects/{project_id}/rate_limits" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Updates a project rate limit. */
 	pub async fn update_project_rate_limits(&self, project_id: &str, rate_limit_id: &str, request_body: crate::types::ProjectRateLimitUpdateRequest, ) -> ConversaResult<crate::types::ProjectRateLimit> {
+		self.update_project_rate_limits_with_meta(project_id, rate_limit_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::update_project_rate_limits`] but also returns the metadata of the response. */
+	pub async fn update_project_rate_limits_with_meta(&self, project_id: &str, rate_limit_id: &str, request_body: crate::types::ProjectRateLimitUpdateRequest, ) -> ConversaResult<WithMeta<crate::types::ProjectRateLimit>> {
 		let address = format!("{}/organization/projects/{project_id}/rate_limits/{rate_limit_id}", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "update-project-rate-limits", name: "update_project_rate_limits", path: "/organization/projects/{project_id}/rate_limits/{rate_limit_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			let mut error = ApiError::from_response(meta, &response_bytes);
+			if status_code == 400 {
+				error.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::ErrorResponse);
+			}
+			return Err(error.into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Returns a list of service accounts in the project. */
 	pub async fn list_project_service_accounts(&self, project_id: &str, limit: Option<u64>, after: Option<&str>, ) -> ConversaResult<crate::types::ProjectServiceAccountListResponse> {
+		self.list_project_service_accounts_with_meta(project_id, limit, after, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_project_service_accounts`] but also returns the metadata of the response. */
+	pub async fn list_project_service_accounts_with_meta(&self, project_id: &str, limit: Option<u64>, after: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ProjectServiceAccountListResponse>> {
 		let address = format!("{}/organization/projects/{project_id}/service_accounts", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "list-project-service-accounts", name: "list_project_service_accounts", path: "/organization/projects/{project_id}/service_accounts" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			let mut error = ApiError::from_response(meta, &response_bytes);
+			if status_code == 400 {
+				error.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::ErrorResponse);
+			}
+			return Err(error.into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Creates a new service account in the project. This also returns an unredacted API key for the service account. */
 	pub async fn create_project_service_account(&self, project_id: &str, request_body: crate::types::ProjectServiceAccountCreateRequest, ) -> ConversaResult<crate::types::ProjectServiceAccountCreateResponse> {
+		self.create_project_service_account_with_meta(project_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_project_service_account`] but also returns the metadata of the response. */
+	pub async fn create_project_service_account_with_meta(&self, project_id: &str, request_body: crate::types::ProjectServiceAccountCreateRequest, ) -> ConversaResult<WithMeta<crate::types::ProjectServiceAccountCreateResponse>> {
 		let address = format!("{}/organization/projects/{project_id}/service_accounts", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "create-project-service-account", name: "create_project_service_account", path: "/organization/projects/{project_id}/service_accounts" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			let mut error = ApiError::from_response(meta, &response_bytes);
+			if status_code == 400 {
+				error.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::ErrorResponse);
+			}
+			return Err(error.into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Retrieves a service account in the project. */
 	pub async fn retrieve_project_service_account(&self, project_id: &str, service_account_id: &str, ) -> ConversaResult<crate::types::ProjectServiceAccount> {
+		self.retrieve_project_service_account_with_meta(project_id, service_account_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::retrieve_project_service_account`] but also returns the metadata of the response. */
+	pub async fn retrieve_project_service_account_with_meta(&self, project_id: &str, service_account_id: &str, ) -> ConversaResult<WithMeta<crate::types::ProjectServiceAccount>> {
 		let address = format!("{}/organization/projects/{project_id}/service_accounts/{service_account_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "retrieve-project-service-account", name: "retrieve_project_service_account", path: "/organization/projects/{project_id}/service_accounts/{service_account_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Deletes a service account from the project. */
 	pub async fn delete_project_service_account(&self, project_id: &str, service_account_id: &str, ) -> ConversaResult<crate::types::ProjectServiceAccountDeleteResponse> {
+		self.delete_project_service_account_with_meta(project_id, service_account_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_project_service_account`] but also returns the metadata of the response. */
+	pub async fn delete_project_service_account_with_meta(&self, project_id: &str, service_account_id: &str, ) -> ConversaResult<WithMeta<crate::types::ProjectServiceAccountDeleteResponse>> {
 		let address = format!("{}/organization/projects/{project_id}/service_accounts/{service_account_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "delete-project-service-account", name: "delete_project_service_account", path: "/organization/projects/{project_id}/service_accounts/{service_account_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Returns a list of users in the project. */
 	pub async fn list_project_users(&self, project_id: &str, limit: Option<u64>, after: Option<&str>, ) -> ConversaResult<crate::types::ProjectUserListResponse> {
+		self.list_project_users_with_meta(project_id, limit, after, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_project_users`] but also returns the metadata of the response. */
+	pub async fn list_project_users_with_meta(&self, project_id: &str, limit: Option<u64>, after: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ProjectUserListResponse>> {
 		let address = format!("{}/organization/projects/{project_id}/users", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "list-project-users", name: "list_project_users", path: "/organization/projects/{project_id}/users" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			let mut error = ApiError::from_response(meta, &response_bytes);
+			if status_code == 400 {
+				error.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::ErrorResponse);
+			}
+			return Err(error.into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Adds a user to the project. Users must already be members of the organization to be added to a project. */
 	pub async fn create_project_user(&self, project_id: &str, request_body: crate::types::ProjectUserCreateRequest, ) -> ConversaResult<crate::types::ProjectUser> {
+		self.create_project_user_with_meta(project_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_project_user`] but also returns the metadata of the response. */
+	pub async fn create_project_user_with_meta(&self, project_id: &str, request_body: crate::types::ProjectUserCreateRequest, ) -> ConversaResult<WithMeta<crate::types::ProjectUser>> {
 		let address = format!("{}/organization/projects/{project_id}/users", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "create-project-user", name: "create_project_user", path: "/organization/projects/{project_id}/users" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			let mut error = ApiError::from_response(meta, &response_bytes);
+			if status_code == 400 {
+				error.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::ErrorResponse);
+			}
+			return Err(error.into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Retrieves a user in the project. */
 	pub async fn retrieve_project_user(&self, project_id: &str, user_id: &str, ) -> ConversaResult<crate::types::ProjectUser> {
+		self.retrieve_project_user_with_meta(project_id, user_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::retrieve_project_user`] but also returns the metadata of the response. */
+	pub async fn retrieve_project_user_with_meta(&self, project_id: &str, user_id: &str, ) -> ConversaResult<WithMeta<crate::types::ProjectUser>> {
 		let address = format!("{}/organization/projects/{project_id}/users/{user_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "retrieve-project-user", name: "retrieve_project_user", path: "/organization/projects/{project_id}/users/{user_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Modifies a user's role in the project. */
 	pub async fn modify_project_user(&self, project_id: &str, user_id: &str, request_body: crate::types::ProjectUserUpdateRequest, ) -> ConversaResult<crate::types::ProjectUser> {
+		self.modify_project_user_with_meta(project_id, user_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::modify_project_user`] but also returns the metadata of the response. */
+	pub async fn modify_project_user_with_meta(&self, project_id: &str, user_id: &str, request_body: crate::types::ProjectUserUpdateRequest, ) -> ConversaResult<WithMeta<crate::types::ProjectUser>> {
 		let address = format!("{}/organization/projects/{project_id}/users/{user_id}", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "modify-project-user", name: "modify_project_user", path: "/organization/projects/{project_id}/users/{user_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			let mut error = ApiError::from_response(meta, &response_bytes);
+			if status_code == 400 {
+				error.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::ErrorResponse);
+			}
+			return Err(error.into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Deletes a user from the project. */
 	pub async fn delete_project_user(&self, project_id: &str, user_id: &str, ) -> ConversaResult<crate::types::ProjectUserDeleteResponse> {
+		self.delete_project_user_with_meta(project_id, user_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_project_user`] but also returns the metadata of the response. */
+	pub async fn delete_project_user_with_meta(&self, project_id: &str, user_id: &str, ) -> ConversaResult<WithMeta<crate::types::ProjectUserDeleteResponse>> {
 		let address = format!("{}/organization/projects/{project_id}/users/{user_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "delete-project-user", name: "delete_project_user", path: "/organization/projects/{project_id}/users/{user_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			let mut error = ApiError::from_response(meta, &response_bytes);
+			if status_code == 400 {
+				error.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::ErrorResponse);
+			}
+			return Err(error.into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Get audio speeches usage details for the organization. */
 	pub async fn usage_audio_speeches(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, user_ids: Option<&[String]>, api_key_ids: Option<&[String]>, models: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<crate::types::UsageResponse> {
+		self.usage_audio_speeches_with_meta(start_time, end_time, bucket_width, project_ids, user_ids, api_key_ids, models, group_by, limit, page, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::usage_audio_speeches`] but also returns the metadata of the response. */
+	pub async fn usage_audio_speeches_with_meta(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, user_ids: Option<&[String]>, api_key_ids: Option<&[String]>, models: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::UsageResponse>> {
 		let address = format!("{}/organization/usage/audio_speeches", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.query(&[("start_time",start_time)]);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = request.query(&[("start_time",start_time)])?;
 		if let Some(q) = end_time {
-			request = request.query(&[("end_time", q)]);
+			request = request.query(&[("end_time", q)])?;
 		}
 		if let Some(q) = bucket_width {
-			request = request.query(&[("bucket_width", q)]);
+			request = request.query(&[("bucket_width", q)])?;
 		}
 		if let Some(q) = project_ids {
-			request = request.query(&[("project_ids", q)]);
+			request = request.query(&[("project_ids", q)])?;
 		}
 		if let Some(q) = user_ids {
-			request = request.query(&[("user_ids", q)]);
+			request = request.query(&[("user_ids", q)])?;
 		}
 		if let Some(q) = api_key_ids {
-			request = request.query(&[("api_key_ids", q)]);
+			request = request.query(&[("api_key_ids", q)])?;
 		}
 		if let Some(q) = models {
-			request = request.query(&[("models", q)]);
+			request = request.query(&[("models", q)])?;
 		}
 		if let Some(q) = group_by {
-			request = request.query(&[("group_by", q)]);
+			request = request.query(&[("group_by", q)])?;
 		}
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = page {
-			request = request.query(&[("page", q)]);
+			request = request.query(&[("page", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "usage-audio-speeches", name: "usage_audio_speeches", path: "/organization/usage/audio_speeches" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Get audio transcriptions usage details for the organization. */
 	pub async fn usage_audio_transcriptions(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, user_ids: Option<&[String]>, api_key_ids: Option<&[String]>, models: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<crate::types::UsageResponse> {
+		self.usage_audio_transcriptions_with_meta(start_time, end_time, bucket_width, project_ids, user_ids, api_key_ids, models, group_by, limit, page, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::usage_audio_transcriptions`] but also returns the metadata of the response. */
+	pub async fn usage_audio_transcriptions_with_meta(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, user_ids: Option<&[String]>, api_key_ids: Option<&[String]>, models: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::UsageResponse>> {
 		let address = format!("{}/organization/usage/audio_transcriptions", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.query(&[("start_time",start_time)]);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = request.query(&[("start_time",start_time)])?;
 		if let Some(q) = end_time {
-			request = request.query(&[("end_time", q)]);
+			request = request.query(&[("end_time", q)])?;
 		}
 		if let Some(q) = bucket_width {
-			request = request.query(&[("bucket_width", q)]);
+			request = request.query(&[("bucket_width", q)])?;
 		}
 		if let Some(q) = project_ids {
-			request = request.query(&[("project_ids", q)]);
+			request = request.query(&[("project_ids", q)])?;
 		}
 		if let Some(q) = user_ids {
-			request = request.query(&[("user_ids", q)]);
+			request = request.query(&[("user_ids", q)])?;
 		}
 		if let Some(q) = api_key_ids {
-			request = request.query(&[("api_key_ids", q)]);
+			request = request.query(&[("api_key_ids", q)])?;
 		}
 		if let Some(q) = models {
-			request = request.query(&[("models", q)]);
+			request = request.query(&[("models", q)])?;
 		}
 		if let Some(q) = group_by {
-			request = request.query(&[("group_by", q)]);
+			request = request.query(&[("group_by", q)])?;
 		}
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = page {
-			request = request.query(&[("page", q)]);
+			request = request.query(&[("page", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "usage-audio-transcriptions", name: "usage_audio_transcriptions", path: "/organization/usage/audio_transcriptions" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Get code interpreter sessions usage details for the organization. */
 	pub async fn usage_code_interpreter_sessions(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<crate::types::UsageResponse> {
+		self.usage_code_interpreter_sessions_with_meta(start_time, end_time, bucket_width, project_ids, group_by, limit, page, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::usage_code_interpreter_sessions`] but also returns the metadata of the response. */
+	pub async fn usage_code_interpreter_sessions_with_meta(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::UsageResponse>> {
 		let address = format!("{}/organization/usage/code_interpreter_sessions", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.query(&[("start_time",start_time)]);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = request.query(&[("start_time",start_time)])?;
 		if let Some(q) = end_time {
-			request = request.query(&[("end_time", q)]);
+			request = request.query(&[("end_time", q)])?;
 		}
 		if let Some(q) = bucket_width {
-			request = request.query(&[("bucket_width", q)]);
+			request = request.query(&[("bucket_width", q)])?;
 		}
 		if let Some(q) = project_ids {
-			request = request.query(&[("project_ids", q)]);
+			request = request.query(&[("project_ids", q)])?;
 		}
 		if let Some(q) = group_by {
-			request = request.query(&[("group_by", q)]);
+			request = request.query(&[("group_by", q)])?;
 		}
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = page {
-			request = request.query(&[("page", q)]);
+			request = request.query(&[("page", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "usage-code-interpreter-sessions", name: "usage_code_interpreter_sessions", path: "/organization/usage/code_interpreter_sessions" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Get completions usage details for the organization. */
 	pub async fn usage_completions(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, user_ids: Option<&[String]>, api_key_ids: Option<&[String]>, models: Option<&[String]>, batch: Option<bool>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<crate::types::UsageResponse> {
+		self.usage_completions_with_meta(start_time, end_time, bucket_width, project_ids, user_ids, api_key_ids, models, batch, group_by, limit, page, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::usage_completions`] but also returns the metadata of the response. */
+	pub async fn usage_completions_with_meta(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, user_ids: Option<&[String]>, api_key_ids: Option<&[String]>, models: Option<&[String]>, batch: Option<bool>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::UsageResponse>> {
 		let address = format!("{}/organization/usage/completions", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.query(&[("start_time",start_time)]);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = request.query(&[("start_time",start_time)])?;
 		if let Some(q) = end_time {
-			request = request.query(&[("end_time", q)]);
+			request = request.query(&[("end_time", q)])?;
 		}
 		if let Some(q) = bucket_width {
-			request = request.query(&[("bucket_width", q)]);
+			request = request.query(&[("bucket_width", q)])?;
 		}
 		if let Some(q) = project_ids {
-			request = request.query(&[("project_ids", q)]);
+			request = request.query(&[("project_ids", q)])?;
 		}
 		if let Some(q) = user_ids {
-			request = request.query(&[("user_ids", q)]);
+			request = request.query(&[("user_ids", q)])?;
 		}
 		if let Some(q) = api_key_ids {
-			request = request.query(&[("api_key_ids", q)]);
+			request = request.query(&[("api_key_ids", q)])?;
 		}
 		if let Some(q) = models {
-			request = request.query(&[("models", q)]);
+			request = request.query(&[("models", q)])?;
 		}
 		if let Some(q) = batch {
-			request = request.query(&[("batch", q)]);
+			request = request.query(&[("batch", q)])?;
 		}
 		if let Some(q) = group_by {
-			request = request.query(&[("group_by", q)]);
+			request = request.query(&[("group_by", q)])?;
 		}
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = page {
-			request = request.query(&[("page", q)]);
+			request = request.query(&[("page", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "usage-completions", name: "usage_completions", path: "/organization/usage/completions" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Get embeddings usage details for the organization. */
 	pub async fn usage_embeddings(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, user_ids: Option<&[String]>, api_key_ids: Option<&[String]>, models: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<crate::types::UsageResponse> {
+		self.usage_embeddings_with_meta(start_time, end_time, bucket_width, project_ids, user_ids, api_key_ids, models, group_by, limit, page, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::usage_embeddings`] but also returns the metadata of the response. */
+	pub async fn usage_embeddings_with_meta(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, user_ids: Option<&[String]>, api_key_ids: Option<&[String]>, models: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::UsageResponse>> {
 		let address = format!("{}/organization/usage/embeddings", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.query(&[("start_time",start_time)]);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = request.query(&[("start_time",start_time)])?;
 		if let Some(q) = end_time {
-			request = request.query(&[("end_time", q)]);
+			request = request.query(&[("end_time", q)])?;
 		}
 		if let Some(q) = bucket_width {
-			request = request.query(&[("bucket_width", q)]);
+			request = request.query(&[("bucket_width", q)])?;
 		}
 		if let Some(q) = project_ids {
-			request = request.query(&[("project_ids", q)]);
+			request = request.query(&[("project_ids", q)])?;
 		}
 		if let Some(q) = user_ids {
-			request = request.query(&[("user_ids", q)]);
+			request = request.query(&[("user_ids", q)])?;
 		}
 		if let Some(q) = api_key_ids {
-			request = request.query(&[("api_key_ids", q)]);
+			request = request.query(&[("api_key_ids", q)])?;
 		}
 		if let Some(q) = models {
-			request = request.query(&[("models", q)]);
+			request = request.query(&[("models", q)])?;
 		}
 		if let Some(q) = group_by {
-			request = request.query(&[("group_by", q)]);
+			request = request.query(&[("group_by", q)])?;
 		}
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = page {
-			request = request.query(&[("page", q)]);
+			request = request.query(&[("page", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "usage-embeddings", name: "usage_embeddings", path: "/organization/usage/embeddings" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Get images usage details for the organization. */
 	pub async fn usage_images(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, sources: Option<&[String]>, sizes: Option<&[String]>, project_ids: Option<&[String]>, user_ids: Option<&[String]>, api_key_ids: Option<&[String]>, models: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<crate::types::UsageResponse> {
+		self.usage_images_with_meta(start_time, end_time, bucket_width, sources, sizes, project_ids, user_ids, api_key_ids, models, group_by, limit, page, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::usage_images`] but also returns the metadata of the response. */
+	pub async fn usage_images_with_meta(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, sources: Option<&[String]>, sizes: Option<&[String]>, project_ids: Option<&[String]>, user_ids: Option<&[String]>, api_key_ids: Option<&[String]>, models: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::UsageResponse>> {
 		let address = format!("{}/organization/usage/images", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.query(&[("start_time",start_time)]);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = request.query(&[("start_time",start_time)])?;
 		if let Some(q) = end_time {
-			request = request.query(&[("end_time", q)]);
+			request = request.query(&[("end_time", q)])?;
 		}
 		if let Some(q) = bucket_width {
-			request = request.query(&[("bucket_width", q)]);
+			request = request.query(&[("bucket_width", q)])?;
 		}
 		if let Some(q) = sources {
-			request = request.query(&[("sources", q)]);
+			request = request.query(&[("sources", q)])?;
 		}
 		if let Some(q) = sizes {
-			request = request.query(&[("sizes", q)]);
+			request = request.query(&[("sizes", q)])?;
 		}
 		if let Some(q) = project_ids {
-			request = request.query(&[("project_ids", q)]);
+			request = request.query(&[("project_ids", q)])?;
 		}
 		if let Some(q) = user_ids {
-			request = request.query(&[("user_ids", q)]);
+			request = request.query(&[("user_ids", q)])?;
 		}
 		if let Some(q) = api_key_ids {
-			request = request.query(&[("api_key_ids", q)]);
+			request = request.query(&[("api_key_ids", q)])?;
 		}
 		if let Some(q) = models {
-			request = request.query(&[("models", q)]);
+			request = request.query(&[("models", q)])?;
 		}
 		if let Some(q) = group_by {
-			request = request.query(&[("group_by", q)]);
+			request = request.query(&[("group_by", q)])?;
 		}
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = page {
-			request = request.query(&[("page", q)]);
+			request = request.query(&[("page", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "usage-images", name: "usage_images", path: "/organization/usage/images" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Get moderations usage details for the organization. */
 	pub async fn usage_moderations(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, user_ids: Option<&[String]>, api_key_ids: Option<&[String]>, models: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<crate::types::UsageResponse> {
+		self.usage_moderations_with_meta(start_time, end_time, bucket_width, project_ids, user_ids, api_key_ids, models, group_by, limit, page, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::usage_moderations`] but also returns the metadata of the response. */
+	pub async fn usage_moderations_with_meta(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, user_ids: Option<&[String]>, api_key_ids: Option<&[String]>, models: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::UsageResponse>> {
 		let address = format!("{}/organization/usage/moderations", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.query(&[("start_time",start_time)]);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = request.query(&[("start_time",start_time)])?;
 		if let Some(q) = end_time {
-			request = request.query(&[("end_time", q)]);
+			request = request.query(&[("end_time", q)])?;
 		}
 		if let Some(q) = bucket_width {
-			request = request.query(&[("bucket_width", q)]);
+			request = request.query(&[("bucket_width", q)])?;
 		}
 		if let Some(q) = project_ids {
-			request = request.query(&[("project_ids", q)]);
+			request = request.query(&[("project_ids", q)])?;
 		}
 		if let Some(q) = user_ids {
-			request = request.query(&[("user_ids", q)]);
+			request = request.query(&[("user_ids", q)])?;
 		}
 		if let Some(q) = api_key_ids {
-			request = request.query(&[("api_key_ids", q)]);
+			request = request.query(&[("api_key_ids", q)])?;
 		}
 		if let Some(q) = models {
-			request = request.query(&[("models", q)]);
+			request = request.query(&[("models", q)])?;
 		}
 		if let Some(q) = group_by {
-			request = request.query(&[("group_by", q)]);
+			request = request.query(&[("group_by", q)])?;
 		}
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = page {
-			request = request.query(&[("page", q)]);
+			request = request.query(&[("page", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "usage-moderations", name: "usage_moderations", path: "/organization/usage/moderations" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Get vector stores usage details for the organization. */
 	pub async fn usage_vector_stores(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<crate::types::UsageResponse> {
+		self.usage_vector_stores_with_meta(start_time, end_time, bucket_width, project_ids, group_by, limit, page, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::usage_vector_stores`] but also returns the metadata of the response. */
+	pub async fn usage_vector_stores_with_meta(&self, start_time: u64, end_time: Option<u64>, bucket_width: Option<&str>, project_ids: Option<&[String]>, group_by: Option<&[String]>, limit: Option<u64>, page: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::UsageResponse>> {
 		let address = format!("{}/organization/usage/vector_stores", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.query(&[("start_time",start_time)]);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = request.query(&[("start_time",start_time)])?;
 		if let Some(q) = end_time {
-			request = request.query(&[("end_time", q)]);
+			request = request.query(&[("end_time", q)])?;
 		}
 		if let Some(q) = bucket_width {
-			request = request.query(&[("bucket_width", q)]);
+			request = request.query(&[("bucket_width", q)])?;
 		}
 		if let Some(q) = project_ids {
-			request = request.query(&[("project_ids", q)]);
+			request = request.query(&[("project_ids", q)])?;
 		}
 		if let Some(q) = group_by {
-			request = request.query(&[("group_by", q)]);
+			request = request.query(&[("group_by", q)])?;
 		}
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = page {
-			request = request.query(&[("page", q)]);
+			request = request.query(&[("page", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "usage-vector-stores", name: "usage_vector_stores", path: "/organization/usage/vector_stores" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Lists all of the users in the organization. */
 	pub async fn list_users(&self, limit: Option<u64>, after: Option<&str>, emails: Option<&[String]>, ) -> ConversaResult<crate::types::UserListResponse> {
+		self.list_users_with_meta(limit, after, emails, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_users`] but also returns the metadata of the response. */
+	pub async fn list_users_with_meta(&self, limit: Option<u64>, after: Option<&str>, emails: Option<&[String]>, ) -> ConversaResult<WithMeta<crate::types::UserListResponse>> {
 		let address = format!("{}/organization/users", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
 		if let Some(q) = emails {
-			request = request.query(&[("emails", q)]);
+			request = request.query(&[("emails", q)])?;
+		}
+		let result = self.send(request, Operation { id: "list-users", name: "list_users", path: "/organization/users" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Retrieves a user by their identifier. */
+	pub async fn retrieve_user(&self, user_id: &str, ) -> ConversaResult<crate::types::User> {
+		self.retrieve_user_with_meta(user_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::retrieve_user`] but also returns the metadata of the response. */
+	pub async fn retrieve_user_with_meta(&self, user_id: &str, ) -> ConversaResult<WithMeta<crate::types::User>> {
+		let address = format!("{}/organization/users/{user_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "retrieve-user", name: "retrieve_user", path: "/organization/users/{user_id}" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Modifies a user's role in the organization. */
+	pub async fn modify_user(&self, user_id: &str, request_body: crate::types::UserRoleUpdateRequest, ) -> ConversaResult<crate::types::User> {
+		self.modify_user_with_meta(user_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::modify_user`] but also returns the metadata of the response. */
+	pub async fn modify_user_with_meta(&self, user_id: &str, request_body: crate::types::UserRoleUpdateRequest, ) -> ConversaResult<WithMeta<crate::types::User>> {
+		let address = format!("{}/organization/users/{user_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "modify-user", name: "modify_user", path: "/organization/users/{user_id}" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Deletes a user from the organization. */
+	pub async fn delete_user(&self, user_id: &str, ) -> ConversaResult<crate::types::UserDeleteResponse> {
+		self.delete_user_with_meta(user_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_user`] but also returns the metadata of the response. */
+	pub async fn delete_user_with_meta(&self, user_id: &str, ) -> ConversaResult<WithMeta<crate::types::UserDeleteResponse>> {
+		let address = format!("{}/organization/users/{user_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "delete-user", name: "delete_user", path: "/organization/users/{user_id}" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+
+}
+}
+#[cfg(feature = "administration")]
+#[allow(unused_imports)]
+pub use administration::*;
+
+#[cfg(feature = "assistants")]
+mod assistants {
+use super::*;
+
+impl OpenAIClient {
+	/** Returns a list of assistants. */
+	pub async fn list_assistants(&self, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, ) -> ConversaResult<crate::types::ListAssistantsResponse> {
+		self.list_assistants_with_meta(limit, order, after, before, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_assistants`] but also returns the metadata of the response. */
+	pub async fn list_assistants_with_meta(&self, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ListAssistantsResponse>> {
+		let address = format!("{}/assistants", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
+		if let Some(q) = limit {
+			request = request.query(&[("limit", q)])?;
+		}
+		if let Some(q) = order {
+			request = request.query(&[("order", q)])?;
+		}
+		if let Some(q) = after {
+			request = request.query(&[("after", q)])?;
+		}
+		if let Some(q) = before {
+			request = request.query(&[("before", q)])?;
+		}
+		let result = self.send(request, Operation { id: "listAssistants", name: "list_assistants", path: "/assistants" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Create an assistant with a model and instructions. */
+	pub async fn create_assistant(&self, request_body: crate::types::CreateAssistantRequest, ) -> ConversaResult<crate::types::AssistantObject> {
+		self.create_assistant_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_assistant`] but also returns the metadata of the response. */
+	pub async fn create_assistant_with_meta(&self, request_body: crate::types::CreateAssistantRequest, ) -> ConversaResult<WithMeta<crate::types::AssistantObject>> {
+		let address = format!("{}/assistants", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createAssistant", name: "create_assistant", path: "/assistants" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Retrieves an assistant. */
+	pub async fn get_assistant(&self, assistant_id: &str, ) -> ConversaResult<crate::types::AssistantObject> {
+		self.get_assistant_with_meta(assistant_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_assistant`] but also returns the metadata of the response. */
+	pub async fn get_assistant_with_meta(&self, assistant_id: &str, ) -> ConversaResult<WithMeta<crate::types::AssistantObject>> {
+		let address = format!("{}/assistants/{assistant_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
+		let result = self.send(request, Operation { id: "getAssistant", name: "get_assistant", path: "/assistants/{assistant_id}" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Modifies an assistant. */
+	pub async fn modify_assistant(&self, assistant_id: &str, request_body: crate::types::ModifyAssistantRequest, ) -> ConversaResult<crate::types::AssistantObject> {
+		self.modify_assistant_with_meta(assistant_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::modify_assistant`] but also returns the metadata of the response. */
+	pub async fn modify_assistant_with_meta(&self, assistant_id: &str, request_body: crate::types::ModifyAssistantRequest, ) -> ConversaResult<WithMeta<crate::types::AssistantObject>> {
+		let address = format!("{}/assistants/{assistant_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "modifyAssistant", name: "modify_assistant", path: "/assistants/{assistant_id}" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Delete an assistant. */
+	pub async fn delete_assistant(&self, assistant_id: &str, ) -> ConversaResult<crate::types::DeleteAssistantResponse> {
+		self.delete_assistant_with_meta(assistant_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_assistant`] but also returns the metadata of the response. */
+	pub async fn delete_assistant_with_meta(&self, assistant_id: &str, ) -> ConversaResult<WithMeta<crate::types::DeleteAssistantResponse>> {
+		let address = format!("{}/assistants/{assistant_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::DELETE, address);
+		request = self.with_openai_beta(request);
+		let result = self.send(request, Operation { id: "deleteAssistant", name: "delete_assistant", path: "/assistants/{assistant_id}" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Create a thread. */
+	pub async fn create_thread(&self, request_body: Option<crate::types::CreateThreadRequest>, ) -> ConversaResult<crate::types::ThreadObject> {
+		self.create_thread_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_thread`] but also returns the metadata of the response. */
+	pub async fn create_thread_with_meta(&self, request_body: Option<crate::types::CreateThreadRequest>, ) -> ConversaResult<WithMeta<crate::types::ThreadObject>> {
+		let address = format!("{}/threads", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		if let Some(b) = request_body {
+			request = request.json(&b)?;
+		}
+		let result = self.send(request, Operation { id: "createThread", name: "create_thread", path: "/threads" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Create a thread and run it in one request. */
+	pub async fn create_thread_and_run(&self, request_body: crate::types::CreateThreadAndRunRequest, ) -> ConversaResult<crate::types::RunObject> {
+		self.create_thread_and_run_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_thread_and_run`] but also returns the metadata of the response. */
+	pub async fn create_thread_and_run_with_meta(&self, request_body: crate::types::CreateThreadAndRunRequest, ) -> ConversaResult<WithMeta<crate::types::RunObject>> {
+		let address = format!("{}/threads/runs", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createThreadAndRun", name: "create_thread_and_run", path: "/threads/runs" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Retrieves a thread. */
+	pub async fn get_thread(&self, thread_id: &str, ) -> ConversaResult<crate::types::ThreadObject> {
+		self.get_thread_with_meta(thread_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_thread`] but also returns the metadata of the response. */
+	pub async fn get_thread_with_meta(&self, thread_id: &str, ) -> ConversaResult<WithMeta<crate::types::ThreadObject>> {
+		let address = format!("{}/threads/{thread_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
+		let result = self.send(request, Operation { id: "getThread", name: "get_thread", path: "/threads/{thread_id}" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Modifies a thread. */
+	pub async fn modify_thread(&self, thread_id: &str, request_body: crate::types::ModifyThreadRequest, ) -> ConversaResult<crate::types::ThreadObject> {
+		self.modify_thread_with_meta(thread_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::modify_thread`] but also returns the metadata of the response. */
+	pub async fn modify_thread_with_meta(&self, thread_id: &str, request_body: crate::types::ModifyThreadRequest, ) -> ConversaResult<WithMeta<crate::types::ThreadObject>> {
+		let address = format!("{}/threads/{thread_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "modifyThread", name: "modify_thread", path: "/threads/{thread_id}" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Delete a thread. */
+	pub async fn delete_thread(&self, thread_id: &str, ) -> ConversaResult<crate::types::DeleteThreadResponse> {
+		self.delete_thread_with_meta(thread_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_thread`] but also returns the metadata of the response. */
+	pub async fn delete_thread_with_meta(&self, thread_id: &str, ) -> ConversaResult<WithMeta<crate::types::DeleteThreadResponse>> {
+		let address = format!("{}/threads/{thread_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::DELETE, address);
+		request = self.with_openai_beta(request);
+		let result = self.send(request, Operation { id: "deleteThread", name: "delete_thread", path: "/threads/{thread_id}" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Returns a list of messages for a given thread. */
+	pub async fn list_messages(&self, thread_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, run_id: Option<&str>, ) -> ConversaResult<crate::types::ListMessagesResponse> {
+		self.list_messages_with_meta(thread_id, limit, order, after, before, run_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_messages`] but also returns the metadata of the response. */
+	pub async fn list_messages_with_meta(&self, thread_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, run_id: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ListMessagesResponse>> {
+		let address = format!("{}/threads/{thread_id}/messages", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
+		if let Some(q) = limit {
+			request = request.query(&[("limit", q)])?;
+		}
+		if let Some(q) = order {
+			request = request.query(&[("order", q)])?;
+		}
+		if let Some(q) = after {
+			request = request.query(&[("after", q)])?;
+		}
+		if let Some(q) = before {
+			request = request.query(&[("before", q)])?;
+		}
+		if let Some(q) = run_id {
+			request = request.query(&[("run_id", q)])?;
+		}
+		let result = self.send(request, Operation { id: "listMessages", name: "list_messages", path: "/threads/{thread_id}/messages" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Create a message. */
+	pub async fn create_message(&self, thread_id: &str, request_body: crate::types::CreateMessageRequest, ) -> ConversaResult<crate::types::MessageObject> {
+		self.create_message_with_meta(thread_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_message`] but also returns the metadata of the response. */
+	pub async fn create_message_with_meta(&self, thread_id: &str, request_body: crate::types::CreateMessageRequest, ) -> ConversaResult<WithMeta<crate::types::MessageObject>> {
+		let address = format!("{}/threads/{thread_id}/messages", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createMessage", name: "create_message", path: "/threads/{thread_id}/messages" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Retrieve a message. */
+	pub async fn get_message(&self, thread_id: &str, message_id: &str, ) -> ConversaResult<crate::types::MessageObject> {
+		self.get_message_with_meta(thread_id, message_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_message`] but also returns the metadata of the response. */
+	pub async fn get_message_with_meta(&self, thread_id: &str, message_id: &str, ) -> ConversaResult<WithMeta<crate::types::MessageObject>> {
+		let address = format!("{}/threads/{thread_id}/messages/{message_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
+		let result = self.send(request, Operation { id: "getMessage", name: "get_message", path: "/threads/{thread_id}/messages/{message_id}" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		let result = request.send().await?;
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Modifies a message. */
+	pub async fn modify_message(&self, thread_id: &str, message_id: &str, request_body: crate::types::ModifyMessageRequest, ) -> ConversaResult<crate::types::MessageObject> {
+		self.modify_message_with_meta(thread_id, message_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::modify_message`] but also returns the metadata of the response. */
+	pub async fn modify_message_with_meta(&self, thread_id: &str, message_id: &str, request_body: crate::types::ModifyMessageRequest, ) -> ConversaResult<WithMeta<crate::types::MessageObject>> {
+		let address = format!("{}/threads/{thread_id}/messages/{message_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "modifyMessage", name: "modify_message", path: "/threads/{thread_id}/messages/{message_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Deletes a message. */
+	pub async fn delete_message(&self, thread_id: &str, message_id: &str, ) -> ConversaResult<crate::types::DeleteMessageResponse> {
+		self.delete_message_with_meta(thread_id, message_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_message`] but also returns the metadata of the response. */
+	pub async fn delete_message_with_meta(&self, thread_id: &str, message_id: &str, ) -> ConversaResult<WithMeta<crate::types::DeleteMessageResponse>> {
+		let address = format!("{}/threads/{thread_id}/messages/{message_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::DELETE, address);
+		request = self.with_openai_beta(request);
+		let result = self.send(request, Operation { id: "deleteMessage", name: "delete_message", path: "/threads/{thread_id}/messages/{message_id}" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Returns a list of runs belonging to a thread. */
+	pub async fn list_runs(&self, thread_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, ) -> ConversaResult<crate::types::ListRunsResponse> {
+		self.list_runs_with_meta(thread_id, limit, order, after, before, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_runs`] but also returns the metadata of the response. */
+	pub async fn list_runs_with_meta(&self, thread_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ListRunsResponse>> {
+		let address = format!("{}/threads/{thread_id}/runs", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
+		if let Some(q) = limit {
+			request = request.query(&[("limit", q)])?;
+		}
+		if let Some(q) = order {
+			request = request.query(&[("order", q)])?;
+		}
+		if let Some(q) = after {
+			request = request.query(&[("after", q)])?;
+		}
+		if let Some(q) = before {
+			request = request.query(&[("before", q)])?;
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let result = self.send(request, Operation { id: "listRuns", name: "list_runs", path: "/threads/{thread_id}/runs" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Create a run. */
+	pub async fn create_run(&self, thread_id: &str, include: Option<&[String]>, request_body: crate::types::CreateRunRequest, ) -> ConversaResult<crate::types::RunObject> {
+		self.create_run_with_meta(thread_id, include, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_run`] but also returns the metadata of the response. */
+	pub async fn create_run_with_meta(&self, thread_id: &str, include: Option<&[String]>, request_body: crate::types::CreateRunRequest, ) -> ConversaResult<WithMeta<crate::types::RunObject>> {
+		let address = format!("{}/threads/{thread_id}/runs", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		if let Some(q) = include {
+			request = request.query(&[("include", q)])?;
+		}
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createRun", name: "create_run", path: "/threads/{thread_id}/runs" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Retrieves a run. */
+	pub async fn get_run(&self, thread_id: &str, run_id: &str, ) -> ConversaResult<crate::types::RunObject> {
+		self.get_run_with_meta(thread_id, run_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_run`] but also returns the metadata of the response. */
+	pub async fn get_run_with_meta(&self, thread_id: &str, run_id: &str, ) -> ConversaResult<WithMeta<crate::types::RunObject>> {
+		let address = format!("{}/threads/{thread_id}/runs/{run_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
+		let result = self.send(request, Operation { id: "getRun", name: "get_run", path: "/threads/{thread_id}/runs/{run_id}" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Modifies a run. */
+	pub async fn modify_run(&self, thread_id: &str, run_id: &str, request_body: crate::types::ModifyRunRequest, ) -> ConversaResult<crate::types::RunObject> {
+		self.modify_run_with_meta(thread_id, run_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::modify_run`] but also returns the metadata of the response. */
+	pub async fn modify_run_with_meta(&self, thread_id: &str, run_id: &str, request_body: crate::types::ModifyRunRequest, ) -> ConversaResult<WithMeta<crate::types::RunObject>> {
+		let address = format!("{}/threads/{thread_id}/runs/{run_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "modifyRun", name: "modify_run", path: "/threads/{thread_id}/runs/{run_id}" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Cancels a run that is `in_progress`. */
+	pub async fn cancel_run(&self, thread_id: &str, run_id: &str, ) -> ConversaResult<crate::types::RunObject> {
+		self.cancel_run_with_meta(thread_id, run_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::cancel_run`] but also returns the metadata of the response. */
+	pub async fn cancel_run_with_meta(&self, thread_id: &str, run_id: &str, ) -> ConversaResult<WithMeta<crate::types::RunObject>> {
+		let address = format!("{}/threads/{thread_id}/runs/{run_id}/cancel", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		let result = self.send(request, Operation { id: "cancelRun", name: "cancel_run", path: "/threads/{thread_id}/runs/{run_id}/cancel" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Returns a list of run steps belonging to a run. */
+	pub async fn list_run_steps(&self, thread_id: &str, run_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, include: Option<&[String]>, ) -> ConversaResult<crate::types::ListRunStepsResponse> {
+		self.list_run_steps_with_meta(thread_id, run_id, limit, order, after, before, include, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_run_steps`] but also returns the metadata of the response. */
+	pub async fn list_run_steps_with_meta(&self, thread_id: &str, run_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, include: Option<&[String]>, ) -> ConversaResult<WithMeta<crate::types::ListRunStepsResponse>> {
+		let address = format!("{}/threads/{thread_id}/runs/{run_id}/steps", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
+		if let Some(q) = limit {
+			request = request.query(&[("limit", q)])?;
+		}
+		if let Some(q) = order {
+			request = request.query(&[("order", q)])?;
+		}
+		if let Some(q) = after {
+			request = request.query(&[("after", q)])?;
+		}
+		if let Some(q) = before {
+			request = request.query(&[("before", q)])?;
+		}
+		if let Some(q) = include {
+			request = request.query(&[("include", q)])?;
+		}
+		let result = self.send(request, Operation { id: "listRunSteps", name: "list_run_steps", path: "/threads/{thread_id}/runs/{run_id}/steps" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Retrieves a run step. */
+	pub async fn get_run_step(&self, thread_id: &str, run_id: &str, step_id: &str, include: Option<&[String]>, ) -> ConversaResult<crate::types::RunStepObject> {
+		self.get_run_step_with_meta(thread_id, run_id, step_id, include, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_run_step`] but also returns the metadata of the response. */
+	pub async fn get_run_step_with_meta(&self, thread_id: &str, run_id: &str, step_id: &str, include: Option<&[String]>, ) -> ConversaResult<WithMeta<crate::types::RunStepObject>> {
+		let address = format!("{}/threads/{thread_id}/runs/{run_id}/steps/{step_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
+		if let Some(q) = include {
+			request = request.query(&[("include", q)])?;
+		}
+		let result = self.send(request, Operation { id: "getRunStep", name: "get_run_step", path: "/threads/{thread_id}/runs/{run_id}/steps/{step_id}" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** When a run has the `status: "requires_action"` and `required_action.type` is `submit_tool_outputs`, this endpoint can be used to submit the outputs from the tool calls once they're all completed. All outputs must be submitted in a single request. */
+	pub async fn submit_tool_ouputs_to_run(&self, thread_id: &str, run_id: &str, request_body: crate::types::SubmitToolOutputsRunRequest, ) -> ConversaResult<crate::types::RunObject> {
+		self.submit_tool_ouputs_to_run_with_meta(thread_id, run_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::submit_tool_ouputs_to_run`] but also returns the metadata of the response. */
+	pub async fn submit_tool_ouputs_to_run_with_meta(&self, thread_id: &str, run_id: &str, request_body: crate::types::SubmitToolOutputsRunRequest, ) -> ConversaResult<WithMeta<crate::types::RunObject>> {
+		let address = format!("{}/threads/{thread_id}/runs/{run_id}/submit_tool_outputs", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "submitToolOuputsToRun", name: "submit_tool_ouputs_to_run", path: "/threads/{thread_id}/runs/{run_id}/submit_tool_outputs" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+
+}
+}
+#[cfg(feature = "assistants")]
+#[allow(unused_imports)]
+pub use assistants::*;
+
+#[cfg(feature = "audio")]
+mod audio {
+use super::*;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub enum CreateSpeechResponse {
+	ApplicationOctetStream(Vec<u8>),
+	TextEventStream(crate::types::CreateSpeechResponseStreamEvent),
+}
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub enum CreateTranscriptionResponse {
+	ApplicationJson(crate::types::CreateTranscriptionResponseJson),
+	ApplicationJsonVerbose(crate::types::CreateTranscriptionResponseVerboseJson),
+	TextEventStream(crate::types::CreateTranscriptionResponseStreamEvent),
+}
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CreateTranslationResponse {
+	CreateTranslationResponseJson(CreateTranslationResponseJson),
+	CreateTranslationResponseVerboseJson(CreateTranslationResponseVerboseJson),
+}
+
+impl OpenAIClient {
+	/** Generates audio from the input text. */
+	pub async fn create_speech(&self, request_body: crate::types::CreateSpeechRequest, ) -> ConversaResult<CreateSpeechResponse> {
+		self.create_speech_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_speech`] but also returns the metadata of the response. */
+	pub async fn create_speech_with_meta(&self, request_body: crate::types::CreateSpeechRequest, ) -> ConversaResult<WithMeta<CreateSpeechResponse>> {
+		let address = format!("{}/audio/speech", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createSpeech", name: "create_speech", path: "/audio/speech" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = match meta.content_type.as_deref() {
+			Some("text/event-stream") => CreateSpeechResponse::TextEventStream(serde_json::from_slice(&response_bytes)?),
+			_ => CreateSpeechResponse::ApplicationOctetStream(response_bytes.to_vec()),
+		};
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Transcribes audio into the input language. */
+	pub async fn create_transcription(&self, request_body: crate::types::CreateTranscriptionRequest, ) -> ConversaResult<CreateTranscriptionResponse> {
+		self.create_transcription_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_transcription`] but also returns the metadata of the response. */
+	pub async fn create_transcription_with_meta(&self, request_body: crate::types::CreateTranscriptionRequest, ) -> ConversaResult<WithMeta<CreateTranscriptionResponse>> {
+		let address = format!("{}/audio/transcriptions", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.multipart(request_body.into_multipart_form()?);
+		let result = self.send(request, Operation { id: "createTranscription", name: "create_transcription", path: "/audio/transcriptions" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = match meta.content_type.as_deref() {
+			Some("text/event-stream") => CreateTranscriptionResponse::TextEventStream(serde_json::from_slice(&response_bytes)?),
+			_ => CreateTranscriptionResponse::ApplicationJson(serde_json::from_slice(&response_bytes)?),
+		};
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Translates audio into English. */
+	pub async fn create_translation(&self, request_body: crate::types::CreateTranslationRequest, ) -> ConversaResult<CreateTranslationResponse> {
+		self.create_translation_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_translation`] but also returns the metadata of the response. */
+	pub async fn create_translation_with_meta(&self, request_body: crate::types::CreateTranslationRequest, ) -> ConversaResult<WithMeta<CreateTranslationResponse>> {
+		let address = format!("{}/audio/translations", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.multipart(request_body.into_multipart_form()?);
+		let result = self.send(request, Operation { id: "createTranslation", name: "create_translation", path: "/audio/translations" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+
+}
+}
+#[cfg(feature = "audio")]
+#[allow(unused_imports)]
+pub use audio::*;
+
+#[cfg(feature = "evals")]
+mod evals {
+use super::*;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateEvalRequestBody {
+	/** Rename the evaluation. */
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<Metadata>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeleteEvalResponse {
+	pub object: String,
+	pub deleted: bool,
+	pub eval_id: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeleteEvalRunResponse {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub object: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub deleted: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub run_id: Option<String>,
+}
+
+impl OpenAIClient {
+	/** List evaluations for a project. */
+	pub async fn list_evals(&self, after: Option<&str>, limit: Option<u64>, order: Option<&str>, order_by: Option<&str>, ) -> ConversaResult<crate::types::EvalList> {
+		self.list_evals_with_meta(after, limit, order, order_by, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_evals`] but also returns the metadata of the response. */
+	pub async fn list_evals_with_meta(&self, after: Option<&str>, limit: Option<u64>, order: Option<&str>, order_by: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::EvalList>> {
+		let address = format!("{}/evals", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		if let Some(q) = after {
+			request = request.query(&[("after", q)])?;
+		}
+		if let Some(q) = limit {
+			request = request.query(&[("limit", q)])?;
+		}
+		if let Some(q) = order {
+			request = request.query(&[("order", q)])?;
+		}
+		if let Some(q) = order_by {
+			request = request.query(&[("order_by", q)])?;
+		}
+		let result = self.send(request, Operation { id: "listEvals", name: "list_evals", path: "/evals" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
+		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Create the structure of an evaluation that can be used to test a model's performance.
+An evaluation is a set of testing criteria and the config for a data source, which dictates the schema of the data used in the evaluation. After creating an evaluation, you can run it on different models and model parameters. We support several types of graders and datasources.
+For more information, see the [Evals guide](/docs/guides/evals). */
+	pub async fn create_eval(&self, request_body: crate::types::CreateEvalRequest, ) -> ConversaResult<crate::types::Eval> {
+		self.create_eval_with_meta(request_body, ).await.map(|r| r.data)
 	}
 
-	/** Retrieves a user by their identifier. */
-	pub async fn retrieve_user(&self, user_id: &str, ) -> ConversaResult<crate::types::User> {
-		let address = format!("{}/organization/users/{user_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Same as [`Self::create_eval`] but also returns the metadata of the response. */
+	pub async fn create_eval_with_meta(&self, request_body: crate::types::CreateEvalRequest, ) -> ConversaResult<WithMeta<crate::types::Eval>> {
+		let address = format!("{}/evals", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createEval", name: "create_eval", path: "/evals" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 201) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Modifies a user's role in the organization. */
-	pub async fn modify_user(&self, user_id: &str, request_body: crate::types::UserRoleUpdateRequest, ) -> ConversaResult<crate::types::User> {
-		let address = format!("{}/organization/users/{user_id}", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** Get an evaluation by ID. */
+	pub async fn get_eval(&self, eval_id: &str, ) -> ConversaResult<crate::types::Eval> {
+		self.get_eval_with_meta(eval_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_eval`] but also returns the metadata of the response. */
+	pub async fn get_eval_with_meta(&self, eval_id: &str, ) -> ConversaResult<WithMeta<crate::types::Eval>> {
+		let address = format!("{}/evals/{eval_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "getEval", name: "get_eval", path: "/evals/{eval_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Deletes a user from the organization. */
-	pub async fn delete_user(&self, user_id: &str, ) -> ConversaResult<crate::types::UserDeleteResponse> {
-		let address = format!("{}/organization/users/{user_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Update certain properties of an evaluation. */
+	pub async fn update_eval(&self, eval_id: &str, request_body: UpdateEvalRequestBody, ) -> ConversaResult<crate::types::Eval> {
+		self.update_eval_with_meta(eval_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::update_eval`] but also returns the metadata of the response. */
+	pub async fn update_eval_with_meta(&self, eval_id: &str, request_body: UpdateEvalRequestBody, ) -> ConversaResult<WithMeta<crate::types::Eval>> {
+		let address = format!("{}/evals/{eval_id}", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "updateEval", name: "update_eval", path: "/evals/{eval_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Create an ephemeral API token for use in client-side applications with the
-Realtime API. Can be configured with the same session parameters as the
-`session.update` client event.
+	/** Delete an evaluation. */
+	pub async fn delete_eval(&self, eval_id: &str, ) -> ConversaResult<DeleteEvalResponse> {
+		self.delete_eval_with_meta(eval_id, ).await.map(|r| r.data)
+	}
 
-It responds with a session object, plus a `client_secret` key which contains
-a usable ephemeral API token that can be used to authenticate browser clients
-for the Realtime API. */
-	pub async fn create_realtime_session(&self, request_body: crate::types::RealtimeSessionCreateRequest, ) -> ConversaResult<crate::types::RealtimeSessionCreateResponse> {
-		let address = format!("{}/realtime/sessions", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** Same as [`Self::delete_eval`] but also returns the metadata of the response. */
+	pub async fn delete_eval_with_meta(&self, eval_id: &str, ) -> ConversaResult<WithMeta<DeleteEvalResponse>> {
+		let address = format!("{}/evals/{eval_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "deleteEval", name: "delete_eval", path: "/evals/{eval_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			let mut error = ApiError::from_response(meta, &response_bytes);
+			if status_code == 404 {
+				error.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::Error);
+			}
+			return Err(error.into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Create an ephemeral API token for use in client-side applications with the
-Realtime API specifically for realtime transcriptions. 
-Can be configured with the same session parameters as the `transcription_session.update` client event.
+	/** Get a list of runs for an evaluation. */
+	pub async fn get_eval_runs(&self, eval_id: &str, after: Option<&str>, limit: Option<u64>, order: Option<&str>, status: Option<&str>, ) -> ConversaResult<crate::types::EvalRunList> {
+		self.get_eval_runs_with_meta(eval_id, after, limit, order, status, ).await.map(|r| r.data)
+	}
 
-It responds with a session object, plus a `client_secret` key which contains
-a usable ephemeral API token that can be used to authenticate browser clients
-for the Realtime API. */
-	pub async fn create_realtime_transcription_session(&self, request_body: crate::types::RealtimeTranscriptionSessionCreateRequest, ) -> ConversaResult<crate::types::RealtimeTranscriptionSessionCreateResponse> {
-		let address = format!("{}/realtime/transcription_sessions", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+	/** Same as [`Self::get_eval_runs`] but also returns the metadata of the response. */
+	pub async fn get_eval_runs_with_meta(&self, eval_id: &str, after: Option<&str>, limit: Option<u64>, order: Option<&str>, status: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::EvalRunList>> {
+		let address = format!("{}/evals/{eval_id}/runs", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		if let Some(q) = after {
+			request = request.query(&[("after", q)])?;
+		}
+		if let Some(q) = limit {
+			request = request.query(&[("limit", q)])?;
+		}
+		if let Some(q) = order {
+			request = request.query(&[("order", q)])?;
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if let Some(q) = status {
+			request = request.query(&[("status", q)])?;
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		let result = self.send(request, Operation { id: "getEvalRuns", name: "get_eval_runs", path: "/evals/{eval_id}/runs" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Creates a model response. Provide [text](/docs/guides/text) or
-[image](/docs/guides/images) inputs to generate [text](/docs/guides/text)
-or [JSON](/docs/guides/structured-outputs) outputs. Have the model call
-your own [custom code](/docs/guides/function-calling) or use built-in
-[tools](/docs/guides/tools) like [web search](/docs/guides/tools-web-search)
-or [file search](/docs/guides/tools-file-search) to use your own data
-as input for the model's response. */
-	pub async fn create_response(&self, request_body: crate::types::CreateResponse, ) -> ConversaResult<CreateResponseResponse> {
-		let address = format!("{}/responses", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** Kicks off a new run for a given evaluation, specifying the data source, and what model configuration to use to test. The datasource will be validated against the schema specified in the config of the evaluation. */
+	pub async fn create_eval_run(&self, eval_id: &str, request_body: crate::types::CreateEvalRunRequest, ) -> ConversaResult<crate::types::EvalRun> {
+		self.create_eval_run_with_meta(eval_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_eval_run`] but also returns the metadata of the response. */
+	pub async fn create_eval_run_with_meta(&self, eval_id: &str, request_body: crate::types::CreateEvalRunRequest, ) -> ConversaResult<WithMeta<crate::types::EvalRun>> {
+		let address = format!("{}/evals/{eval_id}/runs", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createEvalRun", name: "create_eval_run", path: "/evals/{eval_id}/runs" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		match _content_type.as_str() {
-			"application/json" => Ok(CreateResponseResponse::ApplicationJson(serde_json::from_slice(&response_bytes)?)),
-			"text/event-stream" => Ok(CreateResponseResponse::TextEventStream(serde_json::from_slice(&response_bytes)?)),
-			_ => Err(ConversaError::UnexpectedContentType(_content_type)),
+		if !matches!(status_code, 201) {
+			let mut error = ApiError::from_response(meta, &response_bytes);
+			if status_code == 400 {
+				error.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::Error);
+			}
+			return Err(error.into());
 		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Retrieves a model response with the given ID. */
-	pub async fn get_response(&self, response_id: &str, include: Option<&[crate::types::Includable]>, stream: Option<bool>, starting_after: Option<u64>, ) -> ConversaResult<crate::types::Response> {
-		let address = format!("{}/responses/{response_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = include {
-			request = request.query(&[("include", q)]);
-		}
-		if let Some(q) = stream {
-			request = request.query(&[("stream", q)]);
-		}
-		if let Some(q) = starting_after {
-			request = request.query(&[("starting_after", q)]);
-		}
-		let result = request.send().await?;
+	/** Get an evaluation run by ID. */
+	pub async fn get_eval_run(&self, eval_id: &str, run_id: &str, ) -> ConversaResult<crate::types::EvalRun> {
+		self.get_eval_run_with_meta(eval_id, run_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_eval_run`] but also returns the metadata of the response. */
+	pub async fn get_eval_run_with_meta(&self, eval_id: &str, run_id: &str, ) -> ConversaResult<WithMeta<crate::types::EvalRun>> {
+		let address = format!("{}/evals/{eval_id}/runs/{run_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "getEvalRun", name: "get_eval_run", path: "/evals/{eval_id}/runs/{run_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Deletes a model response with the given ID. */
-	pub async fn delete_response(&self, response_id: &str, ) -> ConversaResult<()> {
-		let address = format!("{}/responses/{response_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Cancel an ongoing evaluation run. */
+	pub async fn cancel_eval_run(&self, eval_id: &str, run_id: &str, ) -> ConversaResult<crate::types::EvalRun> {
+		self.cancel_eval_run_with_meta(eval_id, run_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::cancel_eval_run`] but also returns the metadata of the response. */
+	pub async fn cancel_eval_run_with_meta(&self, eval_id: &str, run_id: &str, ) -> ConversaResult<WithMeta<crate::types::EvalRun>> {
+		let address = format!("{}/evals/{eval_id}/runs/{run_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::POST, address);
+		let result = self.send(request, Operation { id: "cancelEvalRun", name: "cancel_eval_run", path: "/evals/{eval_id}/runs/{run_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(())
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Cancels a model response with the given ID. Only responses created with
-the `background` parameter set to `true` can be cancelled. 
-[Learn more](/docs/guides/background). */
-	pub async fn cancel_response(&self, response_id: &str, ) -> ConversaResult<crate::types::Response> {
-		let address = format!("{}/responses/{response_id}/cancel", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Delete an eval run. */
+	pub async fn delete_eval_run(&self, eval_id: &str, run_id: &str, ) -> ConversaResult<DeleteEvalRunResponse> {
+		self.delete_eval_run_with_meta(eval_id, run_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_eval_run`] but also returns the metadata of the response. */
+	pub async fn delete_eval_run_with_meta(&self, eval_id: &str, run_id: &str, ) -> ConversaResult<WithMeta<DeleteEvalRunResponse>> {
+		let address = format!("{}/evals/{eval_id}/runs/{run_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "deleteEvalRun", name: "delete_eval_run", path: "/evals/{eval_id}/runs/{run_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			let mut error = ApiError::from_response(meta, &response_bytes);
+			if status_code == 404 {
+				error.details = serde_json::from_slice(&response_bytes).ok().map(ApiErrorBody::Error);
+			}
+			return Err(error.into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Returns a list of input items for a given response. */
-	pub async fn list_input_items(&self, response_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, include: Option<&[crate::types::Includable]>, ) -> ConversaResult<crate::types::ResponseItemList> {
-		let address = format!("{}/responses/{response_id}/input_items", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
-		}
-		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
-		}
+	/** Get a list of output items for an evaluation run. */
+	pub async fn get_eval_run_output_items(&self, eval_id: &str, run_id: &str, after: Option<&str>, limit: Option<u64>, status: Option<&str>, order: Option<&str>, ) -> ConversaResult<crate::types::EvalRunOutputItemList> {
+		self.get_eval_run_output_items_with_meta(eval_id, run_id, after, limit, status, order, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_eval_run_output_items`] but also returns the metadata of the response. */
+	pub async fn get_eval_run_output_items_with_meta(&self, eval_id: &str, run_id: &str, after: Option<&str>, limit: Option<u64>, status: Option<&str>, order: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::EvalRunOutputItemList>> {
+		let address = format!("{}/evals/{eval_id}/runs/{run_id}/output_items", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
-		if let Some(q) = before {
-			request = request.query(&[("before", q)]);
+		if let Some(q) = limit {
+			request = request.query(&[("limit", q)])?;
 		}
-		if let Some(q) = include {
-			request = request.query(&[("include", q)]);
+		if let Some(q) = status {
+			request = request.query(&[("status", q)])?;
+		}
+		if let Some(q) = order {
+			request = request.query(&[("order", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "getEvalRunOutputItems", name: "get_eval_run_output_items", path: "/evals/{eval_id}/runs/{run_id}/output_items" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Create a thread. */
-	pub async fn create_thread(&self, request_body: Option<crate::types::CreateThreadRequest>, ) -> ConversaResult<crate::types::ThreadObject> {
-		let address = format!("{}/threads", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(b) = request_body {
-			request = request.body(serde_json::to_string(&b)?);
-		}
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+	/** Get an evaluation run output item by ID. */
+	pub async fn get_eval_run_output_item(&self, eval_id: &str, run_id: &str, output_item_id: &str, ) -> ConversaResult<crate::types::EvalRunOutputItem> {
+		self.get_eval_run_output_item_with_meta(eval_id, run_id, output_item_id, ).await.map(|r| r.data)
 	}
 
-	/** Create a thread and run it in one request. */
-	pub async fn create_thread_and_run(&self, request_body: crate::types::CreateThreadAndRunRequest, ) -> ConversaResult<crate::types::RunObject> {
-		let address = format!("{}/threads/runs", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** Same as [`Self::get_eval_run_output_item`] but also returns the metadata of the response. */
+	pub async fn get_eval_run_output_item_with_meta(&self, eval_id: &str, run_id: &str, output_item_id: &str, ) -> ConversaResult<WithMeta<crate::types::EvalRunOutputItem>> {
+		let address = format!("{}/evals/{eval_id}/runs/{run_id}/output_items/{output_item_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "getEvalRunOutputItem", name: "get_eval_run_output_item", path: "/evals/{eval_id}/runs/{run_id}/output_items/{output_item_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Retrieves a thread. */
-	pub async fn get_thread(&self, thread_id: &str, ) -> ConversaResult<crate::types::ThreadObject> {
-		let address = format!("{}/threads/{thread_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+
+}
+}
+#[cfg(feature = "evals")]
+#[allow(unused_imports)]
+pub use evals::*;
+
+#[cfg(feature = "fine-tuning")]
+mod fine_tuning {
+use super::*;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListPaginatedFineTuningJobsQuery(pub String);
+
+impl OpenAIClient {
+	/** Run a grader. */
+	pub async fn run_grader(&self, request_body: crate::types::RunGraderRequest, ) -> ConversaResult<crate::types::RunGraderResponse> {
+		self.run_grader_with_meta(request_body, ).await.map(|r| r.data)
 	}
 
-	/** Modifies a thread. */
-	pub async fn modify_thread(&self, thread_id: &str, request_body: crate::types::ModifyThreadRequest, ) -> ConversaResult<crate::types::ThreadObject> {
-		let address = format!("{}/threads/{thread_id}", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** Same as [`Self::run_grader`] but also returns the metadata of the response. */
+	pub async fn run_grader_with_meta(&self, request_body: crate::types::RunGraderRequest, ) -> ConversaResult<WithMeta<crate::types::RunGraderResponse>> {
+		let address = format!("{}/fine_tuning/alpha/graders/run", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "runGrader", name: "run_grader", path: "/fine_tuning/alpha/graders/run" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Delete a thread. */
-	pub async fn delete_thread(&self, thread_id: &str, ) -> ConversaResult<crate::types::DeleteThreadResponse> {
-		let address = format!("{}/threads/{thread_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Validate a grader. */
+	pub async fn validate_grader(&self, request_body: crate::types::ValidateGraderRequest, ) -> ConversaResult<crate::types::ValidateGraderResponse> {
+		self.validate_grader_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::validate_grader`] but also returns the metadata of the response. */
+	pub async fn validate_grader_with_meta(&self, request_body: crate::types::ValidateGraderRequest, ) -> ConversaResult<WithMeta<crate::types::ValidateGraderResponse>> {
+		let address = format!("{}/fine_tuning/alpha/graders/validate", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "validateGrader", name: "validate_grader", path: "/fine_tuning/alpha/graders/validate" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Returns a list of messages for a given thread. */
-	pub async fn list_messages(&self, thread_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, run_id: Option<&str>, ) -> ConversaResult<crate::types::ListMessagesResponse> {
-		let address = format!("{}/threads/{thread_id}/messages", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
-		}
-		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+	/** **NOTE:** This endpoint requires an [admin API key](../admin-api-keys).
+
+Organization owners can use this endpoint to view all permissions for a fine-tuned model checkpoint. */
+	pub async fn list_fine_tuning_checkpoint_permissions(&self, fine_tuned_model_checkpoint: &str, project_id: Option<&str>, after: Option<&str>, limit: Option<u64>, order: Option<&str>, ) -> ConversaResult<crate::types::ListFineTuningCheckpointPermissionResponse> {
+		self.list_fine_tuning_checkpoint_permissions_with_meta(fine_tuned_model_checkpoint, project_id, after, limit, order, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_fine_tuning_checkpoint_permissions`] but also returns the metadata of the response. */
+	pub async fn list_fine_tuning_checkpoint_permissions_with_meta(&self, fine_tuned_model_checkpoint: &str, project_id: Option<&str>, after: Option<&str>, limit: Option<u64>, order: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ListFineTuningCheckpointPermissionResponse>> {
+		let address = format!("{}/fine_tuning/checkpoints/{fine_tuned_model_checkpoint}/permissions", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		if let Some(q) = project_id {
+			request = request.query(&[("project_id", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
-		if let Some(q) = before {
-			request = request.query(&[("before", q)]);
+		if let Some(q) = limit {
+			request = request.query(&[("limit", q)])?;
 		}
-		if let Some(q) = run_id {
-			request = request.query(&[("run_id", q)]);
+		if let Some(q) = order {
+			request = request.query(&[("order", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "listFineTuningCheckpointPermissions", name: "list_fine_tuning_checkpoint_permissions", path: "/fine_tuning/checkpoints/{fine_tuned_model_checkpoint}/permissions" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Create a message. */
-	pub async fn create_message(&self, thread_id: &str, request_body: crate::types::CreateMessageRequest, ) -> ConversaResult<crate::types::MessageObject> {
-		let address = format!("{}/threads/{thread_id}/messages", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+	/** **NOTE:** Calling this endpoint requires an [admin API key](../admin-api-keys).
+
+This enables organization owners to share fine-tuned models with other projects in their organization. */
+	pub async fn create_fine_tuning_checkpoint_permission(&self, fine_tuned_model_checkpoint: &str, request_body: crate::types::CreateFineTuningCheckpointPermissionRequest, ) -> ConversaResult<crate::types::ListFineTuningCheckpointPermissionResponse> {
+		self.create_fine_tuning_checkpoint_permission_with_meta(fine_tuned_model_checkpoint, request_body, ).await.map(|r| r.data)
 	}
 
-	/** Retrieve a message. */
-	pub async fn get_message(&self, thread_id: &str, message_id: &str, ) -> ConversaResult<crate::types::MessageObject> {
-		let address = format!("{}/threads/{thread_id}/messages/{message_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Same as [`Self::create_fine_tuning_checkpoint_permission`] but also returns the metadata of the response. */
+	pub async fn create_fine_tuning_checkpoint_permission_with_meta(&self, fine_tuned_model_checkpoint: &str, request_body: crate::types::CreateFineTuningCheckpointPermissionRequest, ) -> ConversaResult<WithMeta<crate::types::ListFineTuningCheckpointPermissionResponse>> {
+		let address = format!("{}/fine_tuning/checkpoints/{fine_tuned_model_checkpoint}/permissions", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createFineTuningCheckpointPermission", name: "create_fine_tuning_checkpoint_permission", path: "/fine_tuning/checkpoints/{fine_tuned_model_checkpoint}/permissions" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Modifies a message. */
-	pub async fn modify_message(&self, thread_id: &str, message_id: &str, request_body: crate::types::ModifyMessageRequest, ) -> ConversaResult<crate::types::MessageObject> {
-		let address = format!("{}/threads/{thread_id}/messages/{message_id}", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** **NOTE:** This endpoint requires an [admin API key](../admin-api-keys).
+
+Organization owners can use this endpoint to delete a permission for a fine-tuned model checkpoint. */
+	pub async fn delete_fine_tuning_checkpoint_permission(&self, fine_tuned_model_checkpoint: &str, permission_id: &str, ) -> ConversaResult<crate::types::DeleteFineTuningCheckpointPermissionResponse> {
+		self.delete_fine_tuning_checkpoint_permission_with_meta(fine_tuned_model_checkpoint, permission_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_fine_tuning_checkpoint_permission`] but also returns the metadata of the response. */
+	pub async fn delete_fine_tuning_checkpoint_permission_with_meta(&self, fine_tuned_model_checkpoint: &str, permission_id: &str, ) -> ConversaResult<WithMeta<crate::types::DeleteFineTuningCheckpointPermissionResponse>> {
+		let address = format!("{}/fine_tuning/checkpoints/{fine_tuned_model_checkpoint}/permissions/{permission_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::DELETE, address);
+		let result = self.send(request, Operation { id: "deleteFineTuningCheckpointPermission", name: "delete_fine_tuning_checkpoint_permission", path: "/fine_tuning/checkpoints/{fine_tuned_model_checkpoint}/permissions/{permission_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Deletes a message. */
-	pub async fn delete_message(&self, thread_id: &str, message_id: &str, ) -> ConversaResult<crate::types::DeleteMessageResponse> {
-		let address = format!("{}/threads/{thread_id}/messages/{message_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Creates a fine-tuning job which begins the process of creating a new model from a given dataset.
+
+Response includes details of the enqueued job including job status and the name of the fine-tuned models once complete.
+
+[Learn more about fine-tuning](/docs/guides/model-optimization) */
+	pub async fn create_fine_tuning_job(&self, request_body: crate::types::CreateFineTuningJobRequest, ) -> ConversaResult<crate::types::FineTuningJob> {
+		self.create_fine_tuning_job_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_fine_tuning_job`] but also returns the metadata of the response. */
+	pub async fn create_fine_tuning_job_with_meta(&self, request_body: crate::types::CreateFineTuningJobRequest, ) -> ConversaResult<WithMeta<crate::types::FineTuningJob>> {
+		let address = format!("{}/fine_tuning/jobs", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createFineTuningJob", name: "create_fine_tuning_job", path: "/fine_tuning/jobs" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Returns a list of runs belonging to a thread. */
-	pub async fn list_runs(&self, thread_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, ) -> ConversaResult<crate::types::ListRunsResponse> {
-		let address = format!("{}/threads/{thread_id}/runs", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
-		}
-		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
-		}
+	/** List your organization's fine-tuning jobs */
+	pub async fn list_paginated_fine_tuning_jobs(&self, after: Option<&str>, limit: Option<u64>, metadata: Option<ListPaginatedFineTuningJobsQuery>, ) -> ConversaResult<crate::types::ListPaginatedFineTuningJobsResponse> {
+		self.list_paginated_fine_tuning_jobs_with_meta(after, limit, metadata, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_paginated_fine_tuning_jobs`] but also returns the metadata of the response. */
+	pub async fn list_paginated_fine_tuning_jobs_with_meta(&self, after: Option<&str>, limit: Option<u64>, metadata: Option<ListPaginatedFineTuningJobsQuery>, ) -> ConversaResult<WithMeta<crate::types::ListPaginatedFineTuningJobsResponse>> {
+		let address = format!("{}/fine_tuning/jobs", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
-		if let Some(q) = before {
-			request = request.query(&[("before", q)]);
+		if let Some(q) = limit {
+			request = request.query(&[("limit", q)])?;
+		}
+		if let Some(q) = metadata {
+			request = request.query(&[("metadata", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "listPaginatedFineTuningJobs", name: "list_paginated_fine_tuning_jobs", path: "/fine_tuning/jobs" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Create a run. */
-	pub async fn create_run(&self, thread_id: &str, include: Option<&[String]>, request_body: crate::types::CreateRunRequest, ) -> ConversaResult<crate::types::RunObject> {
-		let address = format!("{}/threads/{thread_id}/runs", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = include {
-			request = request.query(&[("include", q)]);
-		}
-		request = request.json(&request_body);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+	/** Get info about a fine-tuning job.
+
+[Learn more about fine-tuning](/docs/guides/model-optimization) */
+	pub async fn retrieve_fine_tuning_job(&self, fine_tuning_job_id: &str, ) -> ConversaResult<crate::types::FineTuningJob> {
+		self.retrieve_fine_tuning_job_with_meta(fine_tuning_job_id, ).await.map(|r| r.data)
 	}
 
-	/** Retrieves a run. */
-	pub async fn get_run(&self, thread_id: &str, run_id: &str, ) -> ConversaResult<crate::types::RunObject> {
-		let address = format!("{}/threads/{thread_id}/runs/{run_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Same as [`Self::retrieve_fine_tuning_job`] but also returns the metadata of the response. */
+	pub async fn retrieve_fine_tuning_job_with_meta(&self, fine_tuning_job_id: &str, ) -> ConversaResult<WithMeta<crate::types::FineTuningJob>> {
+		let address = format!("{}/fine_tuning/jobs/{fine_tuning_job_id}", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "retrieveFineTuningJob", name: "retrieve_fine_tuning_job", path: "/fine_tuning/jobs/{fine_tuning_job_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Modifies a run. */
-	pub async fn modify_run(&self, thread_id: &str, run_id: &str, request_body: crate::types::ModifyRunRequest, ) -> ConversaResult<crate::types::RunObject> {
-		let address = format!("{}/threads/{thread_id}/runs/{run_id}", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
-		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
-		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+	/** Immediately cancel a fine-tune job. */
+	pub async fn cancel_fine_tuning_job(&self, fine_tuning_job_id: &str, ) -> ConversaResult<crate::types::FineTuningJob> {
+		self.cancel_fine_tuning_job_with_meta(fine_tuning_job_id, ).await.map(|r| r.data)
 	}
 
-	/** Cancels a run that is `in_progress`. */
-	pub async fn cancel_run(&self, thread_id: &str, run_id: &str, ) -> ConversaResult<crate::types::RunObject> {
-		let address = format!("{}/threads/{thread_id}/runs/{run_id}/cancel", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Same as [`Self::cancel_fine_tuning_job`] but also returns the metadata of the response. */
+	pub async fn cancel_fine_tuning_job_with_meta(&self, fine_tuning_job_id: &str, ) -> ConversaResult<WithMeta<crate::types::FineTuningJob>> {
+		let address = format!("{}/fine_tuning/jobs/{fine_tuning_job_id}/cancel", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::POST, address);
+		let result = self.send(request, Operation { id: "cancelFineTuningJob", name: "cancel_fine_tuning_job", path: "/fine_tuning/jobs/{fine_tuning_job_id}/cancel" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Returns a list of run steps belonging to a run. */
-	pub async fn list_run_steps(&self, thread_id: &str, run_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, include: Option<&[String]>, ) -> ConversaResult<crate::types::ListRunStepsResponse> {
-		let address = format!("{}/threads/{thread_id}/runs/{run_id}/steps", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+	/** List checkpoints for a fine-tuning job. */
+	pub async fn list_fine_tuning_job_checkpoints(&self, fine_tuning_job_id: &str, after: Option<&str>, limit: Option<u64>, ) -> ConversaResult<crate::types::ListFineTuningJobCheckpointsResponse> {
+		self.list_fine_tuning_job_checkpoints_with_meta(fine_tuning_job_id, after, limit, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_fine_tuning_job_checkpoints`] but also returns the metadata of the response. */
+	pub async fn list_fine_tuning_job_checkpoints_with_meta(&self, fine_tuning_job_id: &str, after: Option<&str>, limit: Option<u64>, ) -> ConversaResult<WithMeta<crate::types::ListFineTuningJobCheckpointsResponse>> {
+		let address = format!("{}/fine_tuning/jobs/{fine_tuning_job_id}/checkpoints", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		if let Some(q) = after {
+			request = request.query(&[("after", q)])?;
+		}
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
-		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+		let result = self.send(request, Operation { id: "listFineTuningJobCheckpoints", name: "list_fine_tuning_job_checkpoints", path: "/fine_tuning/jobs/{fine_tuning_job_id}/checkpoints" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Get status updates for a fine-tuning job. */
+	pub async fn list_fine_tuning_events(&self, fine_tuning_job_id: &str, after: Option<&str>, limit: Option<u64>, ) -> ConversaResult<crate::types::ListFineTuningJobEventsResponse> {
+		self.list_fine_tuning_events_with_meta(fine_tuning_job_id, after, limit, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_fine_tuning_events`] but also returns the metadata of the response. */
+	pub async fn list_fine_tuning_events_with_meta(&self, fine_tuning_job_id: &str, after: Option<&str>, limit: Option<u64>, ) -> ConversaResult<WithMeta<crate::types::ListFineTuningJobEventsResponse>> {
+		let address = format!("{}/fine_tuning/jobs/{fine_tuning_job_id}/events", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
-		}
-		if let Some(q) = before {
-			request = request.query(&[("before", q)]);
+			request = request.query(&[("after", q)])?;
 		}
-		if let Some(q) = include {
-			request = request.query(&[("include", q)]);
+		if let Some(q) = limit {
+			request = request.query(&[("limit", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "listFineTuningEvents", name: "list_fine_tuning_events", path: "/fine_tuning/jobs/{fine_tuning_job_id}/events" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Retrieves a run step. */
-	pub async fn get_run_step(&self, thread_id: &str, run_id: &str, step_id: &str, include: Option<&[String]>, ) -> ConversaResult<crate::types::RunStepObject> {
-		let address = format!("{}/threads/{thread_id}/runs/{run_id}/steps/{step_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		if let Some(q) = include {
-			request = request.query(&[("include", q)]);
-		}
-		let result = request.send().await?;
+	/** Pause a fine-tune job. */
+	pub async fn pause_fine_tuning_job(&self, fine_tuning_job_id: &str, ) -> ConversaResult<crate::types::FineTuningJob> {
+		self.pause_fine_tuning_job_with_meta(fine_tuning_job_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::pause_fine_tuning_job`] but also returns the metadata of the response. */
+	pub async fn pause_fine_tuning_job_with_meta(&self, fine_tuning_job_id: &str, ) -> ConversaResult<WithMeta<crate::types::FineTuningJob>> {
+		let address = format!("{}/fine_tuning/jobs/{fine_tuning_job_id}/pause", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::POST, address);
+		let result = self.send(request, Operation { id: "pauseFineTuningJob", name: "pause_fine_tuning_job", path: "/fine_tuning/jobs/{fine_tuning_job_id}/pause" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** When a run has the `status: "requires_action"` and `required_action.type` is `submit_tool_outputs`, this endpoint can be used to submit the outputs from the tool calls once they're all completed. All outputs must be submitted in a single request. */
-	pub async fn submit_tool_ouputs_to_run(&self, thread_id: &str, run_id: &str, request_body: crate::types::SubmitToolOutputsRunRequest, ) -> ConversaResult<crate::types::RunObject> {
-		let address = format!("{}/threads/{thread_id}/runs/{run_id}/submit_tool_outputs", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+	/** Resume a fine-tune job. */
+	pub async fn resume_fine_tuning_job(&self, fine_tuning_job_id: &str, ) -> ConversaResult<crate::types::FineTuningJob> {
+		self.resume_fine_tuning_job_with_meta(fine_tuning_job_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::resume_fine_tuning_job`] but also returns the metadata of the response. */
+	pub async fn resume_fine_tuning_job_with_meta(&self, fine_tuning_job_id: &str, ) -> ConversaResult<WithMeta<crate::types::FineTuningJob>> {
+		let address = format!("{}/fine_tuning/jobs/{fine_tuning_job_id}/resume", self.base_address);
+		let request = HttpRequest::new(reqwest::Method::POST, address);
+		let result = self.send(request, Operation { id: "resumeFineTuningJob", name: "resume_fine_tuning_job", path: "/fine_tuning/jobs/{fine_tuning_job_id}/resume" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Creates an intermediate [Upload](/docs/api-reference/uploads/object) object
-that you can add [Parts](/docs/api-reference/uploads/part-object) to.
-Currently, an Upload can accept at most 8 GB in total and expires after an
-hour after you create it.
 
-Once you complete the Upload, we will create a
-[File](/docs/api-reference/files/object) object that contains all the parts
-you uploaded. This File is usable in the rest of our platform as a regular
-File object.
+}
+}
+#[cfg(feature = "fine-tuning")]
+#[allow(unused_imports)]
+pub use fine_tuning::*;
 
-For certain `purpose` values, the correct `mime_type` must be specified. 
-Please refer to documentation for the 
-[supported MIME types for your use case](/docs/assistants/tools/file-search#supported-files).
+#[cfg(feature = "images")]
+mod images {
+use super::*;
 
-For guidance on the proper filename extensions for each purpose, please
-follow the documentation on [creating a
-File](/docs/api-reference/files/create). */
-	pub async fn create_upload(&self, request_body: crate::types::CreateUploadRequest, ) -> ConversaResult<crate::types::Upload> {
-		let address = format!("{}/uploads", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+impl OpenAIClient {
+	/** Creates an edited or extended image given one or more source images and a prompt. This endpoint only supports `gpt-image-1` and `dall-e-2`. */
+	pub async fn create_image_edit(&self, request_body: crate::types::CreateImageEditRequest, ) -> ConversaResult<crate::types::ImagesResponse> {
+		self.create_image_edit_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_image_edit`] but also returns the metadata of the response. */
+	pub async fn create_image_edit_with_meta(&self, request_body: crate::types::CreateImageEditRequest, ) -> ConversaResult<WithMeta<crate::types::ImagesResponse>> {
+		let address = format!("{}/images/edits", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.multipart(request_body.into_multipart_form()?);
+		let result = self.send(request, Operation { id: "createImageEdit", name: "create_image_edit", path: "/images/edits" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Cancels the Upload. No Parts may be added after an Upload is cancelled. */
-	pub async fn cancel_upload(&self, upload_id: &str, ) -> ConversaResult<crate::types::Upload> {
-		let address = format!("{}/uploads/{upload_id}/cancel", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+	/** Creates an image given a prompt. [Learn more](/docs/guides/images). */
+	pub async fn create_image(&self, request_body: crate::types::CreateImageRequest, ) -> ConversaResult<crate::types::ImagesResponse> {
+		self.create_image_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_image`] but also returns the metadata of the response. */
+	pub async fn create_image_with_meta(&self, request_body: crate::types::CreateImageRequest, ) -> ConversaResult<WithMeta<crate::types::ImagesResponse>> {
+		let address = format!("{}/images/generations", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createImage", name: "create_image", path: "/images/generations" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
+	}
+
+	/** Creates a variation of a given image. This endpoint only supports `dall-e-2`. */
+	pub async fn create_image_variation(&self, request_body: crate::types::CreateImageVariationRequest, ) -> ConversaResult<crate::types::ImagesResponse> {
+		self.create_image_variation_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_image_variation`] but also returns the metadata of the response. */
+	pub async fn create_image_variation_with_meta(&self, request_body: crate::types::CreateImageVariationRequest, ) -> ConversaResult<WithMeta<crate::types::ImagesResponse>> {
+		let address = format!("{}/images/variations", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.multipart(request_body.into_multipart_form()?);
+		let result = self.send(request, Operation { id: "createImageVariation", name: "create_image_variation", path: "/images/variations" }).await?;
+		let status_code = result.status().as_u16();
+		let meta = ResponseMeta::from_response(&result);
+		let response_bytes = result.bytes().await?;
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Completes the [Upload](/docs/api-reference/uploads/object). 
 
-Within the returned Upload object, there is a nested [File](/docs/api-reference/files/object) object that is ready to use in the rest of the platform.
+}
+}
+#[cfg(feature = "images")]
+#[allow(unused_imports)]
+pub use images::*;
 
-You can specify the order of the Parts by passing in an ordered list of the Part IDs.
+#[cfg(feature = "realtime")]
+mod realtime {
+use super::*;
 
-The number of bytes uploaded upon completion must match the number of bytes initially specified when creating the Upload object. No Parts may be added after an Upload is completed. */
-	pub async fn complete_upload(&self, upload_id: &str, request_body: crate::types::CompleteUploadRequest, ) -> ConversaResult<crate::types::Upload> {
-		let address = format!("{}/uploads/{upload_id}/complete", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+impl OpenAIClient {
+	/** Create an ephemeral API token for use in client-side applications with the
+Realtime API. Can be configured with the same session parameters as the
+`session.update` client event.
+
+It responds with a session object, plus a `client_secret` key which contains
+a usable ephemeral API token that can be used to authenticate browser clients
+for the Realtime API. */
+	pub async fn create_realtime_session(&self, request_body: crate::types::RealtimeSessionCreateRequest, ) -> ConversaResult<crate::types::RealtimeSessionCreateResponse> {
+		self.create_realtime_session_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_realtime_session`] but also returns the metadata of the response. */
+	pub async fn create_realtime_session_with_meta(&self, request_body: crate::types::RealtimeSessionCreateRequest, ) -> ConversaResult<WithMeta<crate::types::RealtimeSessionCreateResponse>> {
+		let address = format!("{}/realtime/sessions", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "create-realtime-session", name: "create_realtime_session", path: "/realtime/sessions" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
-	/** Adds a [Part](/docs/api-reference/uploads/part-object) to an [Upload](/docs/api-reference/uploads/object) object. A Part represents a chunk of bytes from the file you are trying to upload. 
+	/** Create an ephemeral API token for use in client-side applications with the
+Realtime API specifically for realtime transcriptions. 
+Can be configured with the same session parameters as the `transcription_session.update` client event.
 
-Each Part can be at most 64 MB, and you can add Parts until you hit the Upload maximum of 8 GB.
+It responds with a session object, plus a `client_secret` key which contains
+a usable ephemeral API token that can be used to authenticate browser clients
+for the Realtime API. */
+	pub async fn create_realtime_transcription_session(&self, request_body: crate::types::RealtimeTranscriptionSessionCreateRequest, ) -> ConversaResult<crate::types::RealtimeTranscriptionSessionCreateResponse> {
+		self.create_realtime_transcription_session_with_meta(request_body, ).await.map(|r| r.data)
+	}
 
-It is possible to add multiple Parts in parallel. You can decide the intended order of the Parts when you [complete the Upload](/docs/api-reference/uploads/complete). */
-	pub async fn add_upload_part(&self, upload_id: &str, request_body: crate::types::AddUploadPartRequest, ) -> ConversaResult<crate::types::UploadPart> {
-		let address = format!("{}/uploads/{upload_id}/parts", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.multipart(request_body.into_multipart_form());
-		let result = request.send().await?;
+	/** Same as [`Self::create_realtime_transcription_session`] but also returns the metadata of the response. */
+	pub async fn create_realtime_transcription_session_with_meta(&self, request_body: crate::types::RealtimeTranscriptionSessionCreateRequest, ) -> ConversaResult<WithMeta<crate::types::RealtimeTranscriptionSessionCreateResponse>> {
+		let address = format!("{}/realtime/transcription_sessions", self.base_address);
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "create-realtime-transcription-session", name: "create_realtime_transcription_session", path: "/realtime/transcription_sessions" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
+
+}
+}
+#[cfg(feature = "realtime")]
+#[allow(unused_imports)]
+pub use realtime::*;
+
+#[cfg(feature = "vector-stores")]
+mod vector_stores {
+use super::*;
+
+impl OpenAIClient {
 	/** Returns a list of vector stores. */
 	pub async fn list_vector_stores(&self, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, ) -> ConversaResult<crate::types::ListVectorStoresResponse> {
+		self.list_vector_stores_with_meta(limit, order, after, before, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_vector_stores`] but also returns the metadata of the response. */
+	pub async fn list_vector_stores_with_meta(&self, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ListVectorStoresResponse>> {
 		let address = format!("{}/vector_stores", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+			request = request.query(&[("order", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
 		if let Some(q) = before {
-			request = request.query(&[("before", q)]);
+			request = request.query(&[("before", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "listVectorStores", name: "list_vector_stores", path: "/vector_stores" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Create a vector store. */
 	pub async fn create_vector_store(&self, request_body: crate::types::CreateVectorStoreRequest, ) -> ConversaResult<crate::types::VectorStoreObject> {
+		self.create_vector_store_with_meta(request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_vector_store`] but also returns the metadata of the response. */
+	pub async fn create_vector_store_with_meta(&self, request_body: crate::types::CreateVectorStoreRequest, ) -> ConversaResult<WithMeta<crate::types::VectorStoreObject>> {
 		let address = format!("{}/vector_stores", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createVectorStore", name: "create_vector_store", path: "/vector_stores" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Retrieves a vector store. */
 	pub async fn get_vector_store(&self, vector_store_id: &str, ) -> ConversaResult<crate::types::VectorStoreObject> {
+		self.get_vector_store_with_meta(vector_store_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_vector_store`] but also returns the metadata of the response. */
+	pub async fn get_vector_store_with_meta(&self, vector_store_id: &str, ) -> ConversaResult<WithMeta<crate::types::VectorStoreObject>> {
 		let address = format!("{}/vector_stores/{vector_store_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
+		let result = self.send(request, Operation { id: "getVectorStore", name: "get_vector_store", path: "/vector_stores/{vector_store_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Modifies a vector store. */
 	pub async fn modify_vector_store(&self, vector_store_id: &str, request_body: crate::types::UpdateVectorStoreRequest, ) -> ConversaResult<crate::types::VectorStoreObject> {
+		self.modify_vector_store_with_meta(vector_store_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::modify_vector_store`] but also returns the metadata of the response. */
+	pub async fn modify_vector_store_with_meta(&self, vector_store_id: &str, request_body: crate::types::UpdateVectorStoreRequest, ) -> ConversaResult<WithMeta<crate::types::VectorStoreObject>> {
 		let address = format!("{}/vector_stores/{vector_store_id}", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "modifyVectorStore", name: "modify_vector_store", path: "/vector_stores/{vector_store_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Delete a vector store. */
 	pub async fn delete_vector_store(&self, vector_store_id: &str, ) -> ConversaResult<crate::types::DeleteVectorStoreResponse> {
+		self.delete_vector_store_with_meta(vector_store_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_vector_store`] but also returns the metadata of the response. */
+	pub async fn delete_vector_store_with_meta(&self, vector_store_id: &str, ) -> ConversaResult<WithMeta<crate::types::DeleteVectorStoreResponse>> {
 		let address = format!("{}/vector_stores/{vector_store_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::DELETE, address);
+		request = self.with_openai_beta(request);
+		let result = self.send(request, Operation { id: "deleteVectorStore", name: "delete_vector_store", path: "/vector_stores/{vector_store_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Create a vector store file batch. */
 	pub async fn create_vector_store_file_batch(&self, vector_store_id: &str, request_body: crate::types::CreateVectorStoreFileBatchRequest, ) -> ConversaResult<crate::types::VectorStoreFileBatchObject> {
+		self.create_vector_store_file_batch_with_meta(vector_store_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_vector_store_file_batch`] but also returns the metadata of the response. */
+	pub async fn create_vector_store_file_batch_with_meta(&self, vector_store_id: &str, request_body: crate::types::CreateVectorStoreFileBatchRequest, ) -> ConversaResult<WithMeta<crate::types::VectorStoreFileBatchObject>> {
 		let address = format!("{}/vector_stores/{vector_store_id}/file_batches", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createVectorStoreFileBatch", name: "create_vector_store_file_batch", path: "/vector_stores/{vector_store_id}/file_batches" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Retrieves a vector store file batch. */
 	pub async fn get_vector_store_file_batch(&self, vector_store_id: &str, batch_id: &str, ) -> ConversaResult<crate::types::VectorStoreFileBatchObject> {
+		self.get_vector_store_file_batch_with_meta(vector_store_id, batch_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_vector_store_file_batch`] but also returns the metadata of the response. */
+	pub async fn get_vector_store_file_batch_with_meta(&self, vector_store_id: &str, batch_id: &str, ) -> ConversaResult<WithMeta<crate::types::VectorStoreFileBatchObject>> {
 		let address = format!("{}/vector_stores/{vector_store_id}/file_batches/{batch_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
+		let result = self.send(request, Operation { id: "getVectorStoreFileBatch", name: "get_vector_store_file_batch", path: "/vector_stores/{vector_store_id}/file_batches/{batch_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Cancel a vector store file batch. This attempts to cancel the processing of files in this batch as soon as possible. */
 	pub async fn cancel_vector_store_file_batch(&self, vector_store_id: &str, batch_id: &str, ) -> ConversaResult<crate::types::VectorStoreFileBatchObject> {
+		self.cancel_vector_store_file_batch_with_meta(vector_store_id, batch_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::cancel_vector_store_file_batch`] but also returns the metadata of the response. */
+	pub async fn cancel_vector_store_file_batch_with_meta(&self, vector_store_id: &str, batch_id: &str, ) -> ConversaResult<WithMeta<crate::types::VectorStoreFileBatchObject>> {
 		let address = format!("{}/vector_stores/{vector_store_id}/file_batches/{batch_id}/cancel", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		let result = self.send(request, Operation { id: "cancelVectorStoreFileBatch", name: "cancel_vector_store_file_batch", path: "/vector_stores/{vector_store_id}/file_batches/{batch_id}/cancel" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Returns a list of vector store files in a batch. */
 	pub async fn list_files_in_vector_store_batch(&self, vector_store_id: &str, batch_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, filter: Option<&str>, ) -> ConversaResult<crate::types::ListVectorStoreFilesResponse> {
+		self.list_files_in_vector_store_batch_with_meta(vector_store_id, batch_id, limit, order, after, before, filter, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_files_in_vector_store_batch`] but also returns the metadata of the response. */
+	pub async fn list_files_in_vector_store_batch_with_meta(&self, vector_store_id: &str, batch_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, filter: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ListVectorStoreFilesResponse>> {
 		let address = format!("{}/vector_stores/{vector_store_id}/file_batches/{batch_id}/files", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+			request = request.query(&[("order", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
 		if let Some(q) = before {
-			request = request.query(&[("before", q)]);
+			request = request.query(&[("before", q)])?;
 		}
 		if let Some(q) = filter {
-			request = request.query(&[("filter", q)]);
+			request = request.query(&[("filter", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "listFilesInVectorStoreBatch", name: "list_files_in_vector_store_batch", path: "/vector_stores/{vector_store_id}/file_batches/{batch_id}/files" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Returns a list of vector store files. */
 	pub async fn list_vector_store_files(&self, vector_store_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, filter: Option<&str>, ) -> ConversaResult<crate::types::ListVectorStoreFilesResponse> {
+		self.list_vector_store_files_with_meta(vector_store_id, limit, order, after, before, filter, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::list_vector_store_files`] but also returns the metadata of the response. */
+	pub async fn list_vector_store_files_with_meta(&self, vector_store_id: &str, limit: Option<u64>, order: Option<&str>, after: Option<&str>, before: Option<&str>, filter: Option<&str>, ) -> ConversaResult<WithMeta<crate::types::ListVectorStoreFilesResponse>> {
 		let address = format!("{}/vector_stores/{vector_store_id}/files", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
 		if let Some(q) = limit {
-			request = request.query(&[("limit", q)]);
+			request = request.query(&[("limit", q)])?;
 		}
 		if let Some(q) = order {
-			request = request.query(&[("order", q)]);
+			request = request.query(&[("order", q)])?;
 		}
 		if let Some(q) = after {
-			request = request.query(&[("after", q)]);
+			request = request.query(&[("after", q)])?;
 		}
 		if let Some(q) = before {
-			request = request.query(&[("before", q)]);
+			request = request.query(&[("before", q)])?;
 		}
 		if let Some(q) = filter {
-			request = request.query(&[("filter", q)]);
+			request = request.query(&[("filter", q)])?;
 		}
-		let result = request.send().await?;
+		let result = self.send(request, Operation { id: "listVectorStoreFiles", name: "list_vector_store_files", path: "/vector_stores/{vector_store_id}/files" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Create a vector store file by attaching a [File](/docs/api-reference/files) to a [vector store](/docs/api-reference/vector-stores/object). */
 	pub async fn create_vector_store_file(&self, vector_store_id: &str, request_body: crate::types::CreateVectorStoreFileRequest, ) -> ConversaResult<crate::types::VectorStoreFileObject> {
+		self.create_vector_store_file_with_meta(vector_store_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::create_vector_store_file`] but also returns the metadata of the response. */
+	pub async fn create_vector_store_file_with_meta(&self, vector_store_id: &str, request_body: crate::types::CreateVectorStoreFileRequest, ) -> ConversaResult<WithMeta<crate::types::VectorStoreFileObject>> {
 		let address = format!("{}/vector_stores/{vector_store_id}/files", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = self.with_openai_beta(request);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "createVectorStoreFile", name: "create_vector_store_file", path: "/vector_stores/{vector_store_id}/files" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Retrieves a vector store file. */
 	pub async fn get_vector_store_file(&self, vector_store_id: &str, file_id: &str, ) -> ConversaResult<crate::types::VectorStoreFileObject> {
+		self.get_vector_store_file_with_meta(vector_store_id, file_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::get_vector_store_file`] but also returns the metadata of the response. */
+	pub async fn get_vector_store_file_with_meta(&self, vector_store_id: &str, file_id: &str, ) -> ConversaResult<WithMeta<crate::types::VectorStoreFileObject>> {
 		let address = format!("{}/vector_stores/{vector_store_id}/files/{file_id}", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::GET, address);
+		request = self.with_openai_beta(request);
+		let result = self.send(request, Operation { id: "getVectorStoreFile", name: "get_vector_store_file", path: "/vector_stores/{vector_store_id}/files/{file_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Delete a vector store file. This will remove the file from the vector store but the file itself will not be deleted. To delete the file, use the [delete file](/docs/api-reference/files/delete) endpoint. */
 	pub async fn delete_vector_store_file(&self, vector_store_id: &str, file_id: &str, ) -> ConversaResult<crate::types::DeleteVectorStoreFileResponse> {
+		self.delete_vector_store_file_with_meta(vector_store_id, file_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::delete_vector_store_file`] but also returns the metadata of the response. */
+	pub async fn delete_vector_store_file_with_meta(&self, vector_store_id: &str, file_id: &str, ) -> ConversaResult<WithMeta<crate::types::DeleteVectorStoreFileResponse>> {
 		let address = format!("{}/vector_stores/{vector_store_id}/files/{file_id}", self.base_address);
-		let mut request = self.client.delete(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::DELETE, address);
+		request = self.with_openai_beta(request);
+		let result = self.send(request, Operation { id: "deleteVectorStoreFile", name: "delete_vector_store_file", path: "/vector_stores/{vector_store_id}/files/{file_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Update attributes on a vector store file. */
 	pub async fn update_vector_store_file_attributes(&self, vector_store_id: &str, file_id: &str, request_body: crate::types::UpdateVectorStoreFileAttributesRequest, ) -> ConversaResult<crate::types::VectorStoreFileObject> {
+		self.update_vector_store_file_attributes_with_meta(vector_store_id, file_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::update_vector_store_file_attributes`] but also returns the metadata of the response. */
+	pub async fn update_vector_store_file_attributes_with_meta(&self, vector_store_id: &str, file_id: &str, request_body: crate::types::UpdateVectorStoreFileAttributesRequest, ) -> ConversaResult<WithMeta<crate::types::VectorStoreFileObject>> {
 		let address = format!("{}/vector_stores/{vector_store_id}/files/{file_id}", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "updateVectorStoreFileAttributes", name: "update_vector_store_file_attributes", path: "/vector_stores/{vector_store_id}/files/{file_id}" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Retrieve the parsed contents of a vector store file. */
 	pub async fn retrieve_vector_store_file_content(&self, vector_store_id: &str, file_id: &str, ) -> ConversaResult<crate::types::VectorStoreFileContentResponse> {
+		self.retrieve_vector_store_file_content_with_meta(vector_store_id, file_id, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::retrieve_vector_store_file_content`] but also returns the metadata of the response. */
+	pub async fn retrieve_vector_store_file_content_with_meta(&self, vector_store_id: &str, file_id: &str, ) -> ConversaResult<WithMeta<crate::types::VectorStoreFileContentResponse>> {
 		let address = format!("{}/vector_stores/{vector_store_id}/files/{file_id}/content", self.base_address);
-		let mut request = self.client.get(&address);
-		request = request.bearer_auth(&self.api_key);
-		let result = request.send().await?;
+		let request = HttpRequest::new(reqwest::Method::GET, address);
+		let result = self.send(request, Operation { id: "retrieveVectorStoreFileContent", name: "retrieve_vector_store_file_content", path: "/vector_stores/{vector_store_id}/files/{file_id}/content" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 	/** Search a vector store for relevant chunks based on a query and file attributes filter. */
 	pub async fn search_vector_store(&self, vector_store_id: &str, request_body: crate::types::VectorStoreSearchRequest, ) -> ConversaResult<crate::types::VectorStoreSearchResultsPage> {
+		self.search_vector_store_with_meta(vector_store_id, request_body, ).await.map(|r| r.data)
+	}
+
+	/** Same as [`Self::search_vector_store`] but also returns the metadata of the response. */
+	pub async fn search_vector_store_with_meta(&self, vector_store_id: &str, request_body: crate::types::VectorStoreSearchRequest, ) -> ConversaResult<WithMeta<crate::types::VectorStoreSearchResultsPage>> {
 		let address = format!("{}/vector_stores/{vector_store_id}/search", self.base_address);
-		let mut request = self.client.post(&address);
-		request = request.bearer_auth(&self.api_key);
-		request = request.json(&request_body);
-		let result = request.send().await?;
+		let mut request = HttpRequest::new(reqwest::Method::POST, address);
+		request = request.json(&request_body)?;
+		let result = self.send(request, Operation { id: "searchVectorStore", name: "search_vector_store", path: "/vector_stores/{vector_store_id}/search" }).await?;
 		let status_code = result.status().as_u16();
-		let _content_type = result.headers()[reqwest::header::CONTENT_TYPE].to_str()?.to_string();
+		let meta = ResponseMeta::from_response(&result);
 		let response_bytes = result.bytes().await?;
-		if status_code == 400 {
-			return Err(ConversaError::ErrorResponse(serde_json::from_slice(&response_bytes)?))
+		if !matches!(status_code, 200) {
+			return Err(ApiError::from_response(meta, &response_bytes).into());
 		}
-		if status_code == 404 {
-			return Err(ConversaError::Error(serde_json::from_slice(&response_bytes)?))
-		}
-		if status_code != 200 {
-			return Err(ConversaError::UnexpectedStatusCode{code: status_code, response: String::from_utf8(response_bytes.to_vec())?})
-		}
-		Ok(serde_json::from_slice(&response_bytes)?)
+		let data = serde_json::from_slice(&response_bytes)?;
+		Ok(WithMeta { data, meta })
 	}
 
 
 }
+}
+#[cfg(feature = "vector-stores")]
+#[allow(unused_imports)]
+pub use vector_stores::*;
+
@@ -0,0 +1,215 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures::{Stream, ready};
+use reqwest::header::CONTENT_LENGTH;
+use serde_json::{Map, Value, json};
+
+use crate::{
+    ConversaResult,
+    meta::media_type,
+    transport::{BodyStream, HttpResponse},
+};
+
+// Servers implementing the OpenAI API for local models, such as Ollama, vLLM and llama.cpp,
+// leave out fields the OpenAI API always sends. The responses of the operations they support are
+// completed here with neutral values before they are deserialized, the generated types stay as
+// strict as the specification.
+
+/// The servers the client talks to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CompatibilityProfile {
+    /// The OpenAI API.
+    #[default]
+    OpenAI,
+    /// A server implementing the OpenAI API for local models, such as Ollama, vLLM or
+    /// llama.cpp. The API key is optional and the `OpenAI-Beta` header is not sent. The
+    /// responses of chat completions, embeddings and the list of models may lack fields, which
+    /// are filled in with empty values.
+    LocalServer,
+}
+
+impl CompatibilityProfile {
+    /// Completes the response of `operation_id` with the fields the server left out.
+    pub(crate) fn complete_response(&self, operation_id: &str, response: &mut HttpResponse) {
+        let Some(complete) = completion_for(operation_id).filter(|_| *self == Self::LocalServer)
+        else {
+            return;
+        };
+        let is_json = response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok())
+            .and_then(media_type)
+            .is_some_and(|m| m == "application/json");
+        if !response.status().is_success() || !is_json {
+            return;
+        }
+        response.headers.remove(CONTENT_LENGTH);
+        let body = std::mem::replace(&mut response.body, Box::pin(futures::stream::empty()));
+        response.body = Box::pin(CompletedBody {
+            body,
+            received: Vec::new(),
+            complete: Some(complete),
+        });
+    }
+}
+
+type Completion = fn(&mut Map<String, Value>);
+
+fn completion_for(operation_id: &str) -> Option<Completion> {
+    match operation_id {
+        "createChatCompletion" => Some(complete_chat_completion),
+        "createEmbedding" => Some(complete_embeddings),
+        "listModels" => Some(complete_model_list),
+        _ => None,
+    }
+}
+
+/// Sets `key` to `value` unless it already has a value.
+fn fill(object: &mut Map<String, Value>, key: &str, value: Value) {
+    match object.get(key) {
+        None | Some(Value::Null) => {
+            object.insert(key.to_string(), value);
+        }
+        Some(_) => {}
+    }
+}
+
+/// The objects in the array `key`, which is created if it is missing.
+fn objects<'a>(
+    object: &'a mut Map<String, Value>,
+    key: &str,
+) -> impl Iterator<Item = (usize, &'a mut Map<String, Value>)> {
+    fill(object, key, json!([]));
+    object
+        .get_mut(key)
+        .and_then(Value::as_array_mut)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_object_mut)
+        .enumerate()
+}
+
+fn complete_chat_completion(completion: &mut Map<String, Value>) {
+    fill(completion, "id", json!(""));
+    fill(completion, "object", json!("chat.completion"));
+    fill(completion, "created", json!(0));
+    fill(completion, "model", json!(""));
+    for (index, choice) in objects(completion, "choices") {
+        fill(choice, "index", json!(index));
+        // Some servers report reasons of their own, such as `eos` or `abort`, which are reported
+        // as a normal stop like a missing reason
+        let known_reason = matches!(
+            choice.get("finish_reason").and_then(Value::as_str),
+            Some("stop" | "length" | "tool_calls" | "content_filter" | "function_call")
+        );
+        if !known_reason {
+            choice.insert("finish_reason".to_string(), json!("stop"));
+        }
+        fill(choice, "message", json!({}));
+        if let Some(message) = choice.get_mut("message").and_then(Value::as_object_mut) {
+            fill(message, "role", json!("assistant"));
+            if message.contains_key("tool_calls") {
+                for (index, tool_call) in objects(message, "tool_calls") {
+                    fill(tool_call, "id", json!(format!("call_{index}")));
+                    fill(tool_call, "type", json!("function"));
+                    complete_function_call(tool_call);
+                }
+            }
+        }
+    }
+    if let Some(usage) = completion.get_mut("usage").and_then(Value::as_object_mut) {
+        complete_usage(usage);
+    }
+}
+
+/// Ollama sends the arguments of a function call as an object instead of a JSON string.
+fn complete_function_call(tool_call: &mut Map<String, Value>) {
+    let Some(function) = tool_call.get_mut("function").and_then(Value::as_object_mut) else {
+        return;
+    };
+    fill(function, "arguments", json!("{}"));
+    if let Some(arguments) = function.get_mut("arguments")
+        && !arguments.is_string()
+    {
+        *arguments = Value::String(arguments.to_string());
+    }
+}
+
+fn complete_usage(usage: &mut Map<String, Value>) {
+    fill(usage, "prompt_tokens", json!(0));
+    fill(usage, "completion_tokens", json!(0));
+    let total_tokens = ["prompt_tokens", "completion_tokens"]
+        .iter()
+        .filter_map(|key| usage.get(*key).and_then(Value::as_u64))
+        .sum::<u64>();
+    fill(usage, "total_tokens", json!(total_tokens));
+}
+
+fn complete_embeddings(embeddings: &mut Map<String, Value>) {
+    fill(embeddings, "object", json!("list"));
+    fill(embeddings, "model", json!(""));
+    for (index, embedding) in objects(embeddings, "data") {
+        fill(embedding, "index", json!(index));
+        fill(embedding, "object", json!("embedding"));
+    }
+    fill(embeddings, "usage", json!({}));
+    if let Some(usage) = embeddings.get_mut("usage").and_then(Value::as_object_mut) {
+        fill(usage, "prompt_tokens", json!(0));
+        let prompt_tokens = usage.get("prompt_tokens").cloned().unwrap_or(json!(0));
+        fill(usage, "total_tokens", prompt_tokens);
+    }
+}
+
+fn complete_model_list(models: &mut Map<String, Value>) {
+    fill(models, "object", json!("list"));
+    for (_, model) in objects(models, "data") {
+        fill(model, "object", json!("model"));
+        fill(model, "created", json!(0));
+        fill(model, "owned_by", json!(""));
+    }
+}
+
+/// The body of a response, which is passed on once it is completely received and completed.
+struct CompletedBody {
+    body: BodyStream,
+    received: Vec<u8>,
+    complete: Option<Completion>,
+}
+
+impl Stream for CompletedBody {
+    type Item = ConversaResult<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            let Some(complete) = this.complete else {
+                return Poll::Ready(None);
+            };
+            match ready!(this.body.as_mut().poll_next(cx)) {
+                Some(Ok(bytes)) => this.received.extend_from_slice(&bytes),
+                Some(Err(e)) => {
+                    this.complete = None;
+                    return Poll::Ready(Some(Err(e)));
+                }
+                None => {
+                    this.complete = None;
+                    let received = std::mem::take(&mut this.received);
+                    // A body which is not a JSON object is passed on for the usual error
+                    let body = match serde_json::from_slice::<Value>(&received) {
+                        Ok(Value::Object(mut object)) => {
+                            complete(&mut object);
+                            serde_json::to_vec(&object).unwrap_or(received)
+                        }
+                        _ => received,
+                    };
+                    return Poll::Ready(Some(Ok(body.into())));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use std::{
+    path::PathBuf,
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime},
+};
+
+use futures::future::BoxFuture;
+use reqwest::StatusCode;
+
+use crate::{ConversaError, ConversaResult};
+
+// The client asks its credential provider for a token before every attempt of a request, so
+// that keys can be rotated without building a new client. The providers below cover keys which
+// are fixed, read from the environment or a file, or spread over a pool.
+
+/// Supplies the API key or bearer token sent with the requests.
+pub trait CredentialProvider: Send + Sync {
+    /// Returns the token for the next request. It is called for every attempt of a request, so
+    /// providers fetching their tokens from elsewhere should cache them, for example with
+    /// [`Cached`].
+    fn token(&self) -> BoxFuture<'_, ConversaResult<String>>;
+
+    /// Receives the status code of the response to a request sent with `token`.
+    fn on_response(&self, _token: &str, _status: StatusCode) {}
+}
+
+/// A key which never changes.
+#[derive(Debug, Clone)]
+pub struct StaticKey(pub String);
+
+impl CredentialProvider for StaticKey {
+    fn token(&self) -> BoxFuture<'_, ConversaResult<String>> {
+        Box::pin(async { Ok(self.0.clone()) })
+    }
+}
+
+/// A key read from an environment variable for every request.
+#[derive(Debug, Clone)]
+pub struct EnvKey {
+    variable: String,
+}
+
+impl EnvKey {
+    pub fn new(variable: impl Into<String>) -> Self {
+        Self {
+            variable: variable.into(),
+        }
+    }
+}
+
+impl CredentialProvider for EnvKey {
+    fn token(&self) -> BoxFuture<'_, ConversaResult<String>> {
+        Box::pin(async {
+            std::env::var(&self.variable).map_err(|e| {
+                ConversaError::ClientError(format!("Invalid API key in {}: {e}", self.variable))
+            })
+        })
+    }
+}
+
+/// A key read from a file, which is read again whenever it is modified. Whitespace around the
+/// key is ignored.
+#[derive(Debug)]
+pub struct FileKey {
+    path: PathBuf,
+    cached: Mutex<Option<(SystemTime, String)>>,
+}
+
+impl FileKey {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            cached: Mutex::new(None),
+        }
+    }
+}
+
+impl CredentialProvider for FileKey {
+    fn token(&self) -> BoxFuture<'_, ConversaResult<String>> {
+        Box::pin(async {
+            let modified = tokio::fs::metadata(&self.path).await?.modified()?;
+            if let Some((cached_modified, key)) = &*lock(&self.cached)
+                && *cached_modified == modified
+            {
+                return Ok(key.clone());
+            }
+            let key = tokio::fs::read_to_string(&self.path)
+                .await?
+                .trim()
+                .to_string();
+            *lock(&self.cached) = Some((modified, key.clone()));
+            Ok(key)
+        })
+    }
+}
+
+/// Takes turns with several keys. A key which receives a 429 response is skipped for a cool-down
+/// period, unless every key is cooling down, in which case the key available first is used.
+#[derive(Debug)]
+pub struct KeyPool {
+    keys: Vec<String>,
+    cool_down: Duration,
+    state: Mutex<PoolState>,
+}
+
+#[derive(Debug)]
+struct PoolState {
+    next: usize,
+    cooling_until: Vec<Option<Instant>>,
+}
+
+impl KeyPool {
+    /// Creates a pool whose keys cool down for a minute.
+    pub fn new(keys: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let keys: Vec<String> = keys.into_iter().map(Into::into).collect();
+        Self {
+            cool_down: Duration::from_secs(60),
+            state: Mutex::new(PoolState {
+                next: 0,
+                cooling_until: vec![None; keys.len()],
+            }),
+            keys,
+        }
+    }
+
+    pub fn with_cool_down(mut self, cool_down: Duration) -> Self {
+        self.cool_down = cool_down;
+        self
+    }
+
+    fn next_key(&self) -> ConversaResult<String> {
+        let mut state = lock(&self.state);
+        let now = Instant::now();
+        let count = self.keys.len();
+        let index = (0..count)
+            .map(|offset| (state.next + offset) % count)
+            .find(|index| state.cooling_until[*index].is_none_or(|until| until <= now))
+            .or_else(|| (0..count).min_by_key(|index| state.cooling_until[*index].unwrap_or(now)))
+            .ok_or_else(|| ConversaError::ClientError("The key pool is empty".to_string()))?;
+        state.next = (index + 1) % count;
+        Ok(self.keys[index].clone())
+    }
+}
+
+impl CredentialProvider for KeyPool {
+    fn token(&self) -> BoxFuture<'_, ConversaResult<String>> {
+        Box::pin(async { self.next_key() })
+    }
+
+    fn on_response(&self, token: &str, status: StatusCode) {
+        if status != StatusCode::TOO_MANY_REQUESTS {
+            return;
+        }
+        if let Some(index) = self.keys.iter().position(|k| k == token) {
+            lock(&self.state).cooling_until[index] = Some(Instant::now() + self.cool_down);
+        }
+    }
+}
+
+/// Keeps the token of another provider for a while. The token is fetched again once it
+/// expires or after a 401 response, which hints that it was rotated. Requests needing a new
+/// token at the same time wait for a single fetch.
+#[derive(Debug)]
+pub struct Cached<P> {
+    provider: P,
+    time_to_live: Duration,
+    cached: Mutex<Option<(Instant, String)>>,
+    refreshing: tokio::sync::Mutex<()>,
+}
+
+impl<P: CredentialProvider> Cached<P> {
+    pub fn new(provider: P, time_to_live: Duration) -> Self {
+        Self {
+            provider,
+            time_to_live,
+            cached: Mutex::new(None),
+            refreshing: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    fn cached_token(&self) -> Option<String> {
+        match &*lock(&self.cached) {
+            Some((expires, token)) if *expires > Instant::now() => Some(token.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl<P: CredentialProvider> CredentialProvider for Cached<P> {
+    fn token(&self) -> BoxFuture<'_, ConversaResult<String>> {
+        Box::pin(async {
+            if let Some(token) = self.cached_token() {
+                return Ok(token);
+            }
+            let _refreshing = self.refreshing.lock().await;
+            // The token may have been fetched while waiting
+            if let Some(token) = self.cached_token() {
+                return Ok(token);
+            }
+            let token = self.provider.token().await?;
+            *lock(&self.cached) = Some((Instant::now() + self.time_to_live, token.clone()));
+            Ok(token)
+        })
+    }
+
+    fn on_response(&self, token: &str, status: StatusCode) {
+        if status == StatusCode::UNAUTHORIZED {
+            let mut cached = lock(&self.cached);
+            if cached.as_ref().is_some_and(|(_, t)| t == token) {
+                *cached = None;
+            }
+        }
+        self.provider.on_response(token, status);
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
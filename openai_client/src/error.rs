@@ -0,0 +1,268 @@
+use std::string::FromUtf8Error;
+
+use reqwest::header::ToStrError;
+
+use crate::{client::ApiErrorBody, meta::ResponseMeta, transport::TransportError};
+
+#[derive(Debug)]
+pub enum ConversaError {
+    /// The client was configured or called with invalid values.
+    ClientError(String),
+    /// The request could not be sent or the response could not be received.
+    Http(reqwest::Error),
+    /// A custom transport failed to send the request or to receive the response.
+    Transport(TransportError),
+    /// The server answered with an error, either with an error status code or with an error
+    /// event on a stream.
+    Api(Box<ApiError>),
+    /// A body could not be encoded or decoded.
+    Json(serde_json::Error),
+    /// The data received does not match what the API should send.
+    InvalidData(String),
+    IoError(std::io::Error),
+    UnexpectedContentType(String),
+}
+
+/// An error reported by the API.
+#[derive(Debug, PartialEq)]
+pub struct ApiError {
+    pub message: String,
+    /// Machine readable code of the error, e.g. `rate_limit_exceeded` or
+    /// `context_length_exceeded`.
+    pub code: Option<String>,
+    /// Category of the error, e.g. `invalid_request_error` or `server_error`.
+    pub r#type: Option<String>,
+    /// The request parameter which caused the error.
+    pub param: Option<String>,
+    /// The body as it was received, which is useful when it is not a JSON error object.
+    pub body: String,
+    /// The status code and headers of the response.
+    pub meta: ResponseMeta,
+    /// The body decoded with the schema the operation declares for the status code, if it
+    /// declares one.
+    pub details: Option<ApiErrorBody>,
+}
+
+impl ApiError {
+    /// Builds the error from the body of a response with an error status code. The body is
+    /// usually wrapped in an `error` object, but a bare error object or plain text are accepted
+    /// too.
+    pub fn from_response(meta: ResponseMeta, body: &[u8]) -> Self {
+        let body = String::from_utf8_lossy(body).into_owned();
+        let error = serde_json::from_str::<crate::types::ErrorResponse>(&body)
+            .map(|e| e.error)
+            .or_else(|_| serde_json::from_str::<crate::types::Error>(&body));
+        match error {
+            Ok(error) => Self {
+                body,
+                meta,
+                ..Self::from(error)
+            },
+            Err(_) => Self {
+                message: body.clone(),
+                code: None,
+                r#type: None,
+                param: None,
+                body,
+                meta,
+                details: None,
+            },
+        }
+    }
+
+    pub fn status_code(&self) -> u16 {
+        self.meta.status_code
+    }
+
+    /// The `x-request-id` header to quote when reporting the issue.
+    pub fn request_id(&self) -> Option<&str> {
+        self.meta.request_id.as_deref()
+    }
+}
+
+impl From<crate::types::Error> for ApiError {
+    fn from(value: crate::types::Error) -> Self {
+        Self {
+            body: serde_json::to_string(&value).unwrap_or_default(),
+            message: value.message,
+            code: value.code,
+            r#type: Some(value.r#type),
+            param: value.param,
+            meta: ResponseMeta::default(),
+            details: None,
+        }
+    }
+}
+
+impl ConversaError {
+    /// The HTTP status code of the response which caused the error, if there was one.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            ConversaError::Http(e) => e.status().map(|s| s.as_u16()),
+            ConversaError::Api(e) => Some(e.status_code()),
+            _ => None,
+        }
+    }
+
+    /// The id of the request as assigned by the server, if the error came from a response.
+    pub fn request_id(&self) -> Option<&str> {
+        self.api_error().and_then(|e| e.request_id())
+    }
+
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            ConversaError::Api(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    fn api_code(&self) -> Option<&str> {
+        self.api_error().and_then(|e| e.code.as_deref())
+    }
+
+    fn api_type(&self) -> Option<&str> {
+        self.api_error().and_then(|e| e.r#type.as_deref())
+    }
+
+    /// The request was rejected because a rate limit was reached. Running out of quota is not
+    /// considered a rate limit since waiting does not help.
+    pub fn is_rate_limited(&self) -> bool {
+        if self.api_code() == Some("insufficient_quota") {
+            return false;
+        }
+        self.status_code() == Some(429) || self.api_code() == Some("rate_limit_exceeded")
+    }
+
+    /// The request failed for a transient reason and can be sent again.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ConversaError::Http(e) => e.is_connect() || e.is_timeout(),
+            ConversaError::Transport(e) => e.is_connect() || e.is_timeout(),
+            ConversaError::Api(e) => {
+                let status_code = e.status_code();
+                self.is_rate_limited()
+                    || matches!(status_code, 408 | 409)
+                    || status_code >= 500
+                    || e.r#type.as_deref() == Some("server_error")
+            }
+            _ => false,
+        }
+    }
+
+    /// The input is longer than the context window of the model.
+    pub fn is_context_length_exceeded(&self) -> bool {
+        self.api_code() == Some("context_length_exceeded")
+    }
+
+    /// The API key is missing, invalid or not allowed to perform the request.
+    pub fn is_auth_error(&self) -> bool {
+        matches!(self.status_code(), Some(401 | 403))
+            || matches!(
+                self.api_type(),
+                Some("authentication_error" | "permission_error")
+            )
+            || self.api_code() == Some("invalid_api_key")
+    }
+
+    /// Adds the metadata of a stream to the errors sent as events, which do not have a response
+    /// of their own.
+    pub(crate) fn with_meta(self, meta: &ResponseMeta) -> Self {
+        match self {
+            ConversaError::Api(mut e) => {
+                e.meta = meta.clone();
+                ConversaError::Api(e)
+            }
+            e => e,
+        }
+    }
+}
+
+impl From<ApiError> for ConversaError {
+    fn from(value: ApiError) -> Self {
+        ConversaError::Api(Box::new(value))
+    }
+}
+
+impl From<reqwest::Error> for ConversaError {
+    fn from(value: reqwest::Error) -> Self {
+        ConversaError::Http(value)
+    }
+}
+
+impl From<TransportError> for ConversaError {
+    fn from(value: TransportError) -> Self {
+        ConversaError::Transport(value)
+    }
+}
+
+impl From<serde_json::Error> for ConversaError {
+    fn from(value: serde_json::Error) -> Self {
+        ConversaError::Json(value)
+    }
+}
+
+impl From<ToStrError> for ConversaError {
+    fn from(value: ToStrError) -> Self {
+        ConversaError::InvalidData(value.to_string())
+    }
+}
+
+impl From<FromUtf8Error> for ConversaError {
+    fn from(value: FromUtf8Error) -> Self {
+        ConversaError::InvalidData(value.to_string())
+    }
+}
+
+impl From<std::io::Error> for ConversaError {
+    fn from(value: std::io::Error) -> Self {
+        ConversaError::IoError(value)
+    }
+}
+
+impl std::fmt::Display for ConversaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversaError::ClientError(msg) => write!(f, "Client error: {msg}",),
+            ConversaError::Http(e) => write!(f, "HTTP error: {e}",),
+            ConversaError::Transport(e) => write!(f, "HTTP error: {e}",),
+            ConversaError::Api(e) => write!(f, "{e}"),
+            ConversaError::Json(e) => write!(f, "JSON error: {e}",),
+            ConversaError::InvalidData(msg) => write!(f, "Invalid data: {msg}",),
+            ConversaError::IoError(e) => write!(f, "std::io error: {e}",),
+            ConversaError::UnexpectedContentType(content_type) => {
+                write!(f, "Unexpected content type: {content_type}",)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "API error {}", self.status_code())?;
+        if let Some(code) = self.code.as_ref().or(self.r#type.as_ref()) {
+            write!(f, " ({code})")?;
+        }
+        write!(f, ": {}", self.message)?;
+        if let Some(request_id) = self.request_id() {
+            write!(f, " [request id {request_id}]")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConversaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConversaError::Http(e) => Some(e),
+            ConversaError::Transport(e) => Some(e),
+            ConversaError::Json(e) => Some(e),
+            ConversaError::IoError(e) => Some(e),
+            ConversaError::Api(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+pub type ConversaResult<T> = Result<T, ConversaError>;
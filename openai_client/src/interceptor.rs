@@ -0,0 +1,35 @@
+use crate::{
+    ConversaResult,
+    transport::{HttpRequest, HttpResponse},
+};
+
+// Cross-cutting concerns such as audit logging, scrubbing or signing requests hook into the
+// client here instead of into the generated operations. Every operation calls the interceptors
+// configured on the builder, in the order they were added, around each attempt of the request.
+
+/// Hooks which run around every request sent by the client.
+///
+/// [`before_send`](Self::before_send) runs in the order the interceptors were added and
+/// [`after_receive`](Self::after_receive) in the reverse order, so the first interceptor sees
+/// the request first and the response last. Retried requests go through the interceptors again.
+pub trait Interceptor: Send + Sync {
+    /// Inspects or modifies the request before it is sent. Returning a response skips sending
+    /// the request and the following interceptors, the response only goes through the
+    /// `after_receive` hooks of the interceptors before this one. Returning an error fails the
+    /// operation with it.
+    fn before_send(&self, request: &mut HttpRequest) -> ConversaResult<Option<HttpResponse>> {
+        let _ = request;
+        Ok(None)
+    }
+
+    /// Inspects or modifies the response, including error responses, before the operation
+    /// decodes it. Returning an error fails the operation with it.
+    fn after_receive(
+        &self,
+        request: &HttpRequest,
+        response: &mut HttpResponse,
+    ) -> ConversaResult<()> {
+        let _ = (request, response);
+        Ok(())
+    }
+}
@@ -1,98 +1,500 @@
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::large_enum_variant)]
 
+pub mod accumulator;
+pub mod azure;
 pub mod client;
+pub mod compat;
+pub mod credentials;
+mod error;
+pub mod interceptor;
+pub mod meta;
 pub mod multipart;
+pub mod rate_limiter;
+pub mod retry;
+pub mod stream;
+#[cfg(feature = "tracing")]
+mod telemetry;
+pub mod transport;
 pub mod types;
+pub mod upload;
 
-use std::string::FromUtf8Error;
+use std::{sync::Arc, time::Duration};
 
-use reqwest::{Client, header::ToStrError};
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+use reqwest::{Certificate, Identity};
+use reqwest::{
+    Client, Proxy,
+    header::{HeaderMap, HeaderName, HeaderValue},
+};
 
-#[derive(Debug)]
-pub enum ConversaError {
-    ClientError(String),
-    InvalidData(String),
-    IoError(String),
-    UnexpectedStatusCode { code: u16, response: String },
-    UnexpectedContentType(String),
-    ErrorResponse(crate::types::ErrorResponse),
-    Error(crate::types::Error),
+use crate::{
+    azure::AzureConfig,
+    compat::CompatibilityProfile,
+    credentials::{CredentialProvider, StaticKey},
+    interceptor::Interceptor,
+    rate_limiter::RateLimiter,
+    retry::RetryPolicy,
+    transport::{HttpRequest, HttpResponse, Operation, ReqwestTransport, Transport},
+};
+
+pub use error::{ApiError, ConversaError, ConversaResult};
+
+const DEFAULT_OPENAI_BETA: &str = "assistants=v2";
+
+pub struct OpenAIClientBuilder {
+    credentials: Arc<dyn CredentialProvider>,
+    address: String,
+    retry_policy: RetryPolicy,
+    default_headers: Vec<(String, String)>,
+    openai_beta: Option<String>,
+    request_timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    proxies: Vec<Proxy>,
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    root_certificates: Vec<Certificate>,
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    identity: Option<Identity>,
+    http_client: Option<Client>,
+    transport: Option<Arc<dyn Transport>>,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    rate_limiter: Option<RateLimiter>,
+    azure: Option<AzureConfig>,
+    compatibility_profile: CompatibilityProfile,
 }
 
-impl From<reqwest::Error> for ConversaError {
-    fn from(value: reqwest::Error) -> Self {
-        ConversaError::ClientError(value.to_string())
+impl OpenAIClientBuilder {
+    pub fn new(address: String, api_key: String) -> Self {
+        Self {
+            credentials: Arc::new(StaticKey(api_key)),
+            address,
+            retry_policy: RetryPolicy::disabled(),
+            default_headers: Vec::new(),
+            openai_beta: Some(DEFAULT_OPENAI_BETA.to_string()),
+            request_timeout: None,
+            connect_timeout: None,
+            read_timeout: None,
+            proxies: Vec::new(),
+            #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+            root_certificates: Vec::new(),
+            #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+            identity: None,
+            http_client: None,
+            transport: None,
+            interceptors: Vec::new(),
+            rate_limiter: None,
+            azure: None,
+            compatibility_profile: CompatibilityProfile::default(),
+        }
     }
-}
 
-impl From<serde_json::Error> for ConversaError {
-    fn from(value: serde_json::Error) -> Self {
-        ConversaError::InvalidData(value.to_string())
+    /// Builds a client for an OpenAI-compatible server running local models, which needs no
+    /// API key. See [`CompatibilityProfile::LocalServer`].
+    pub fn local_server(address: String) -> Self {
+        Self::new(address, String::new()).compatibility_profile(CompatibilityProfile::LocalServer)
     }
-}
 
-impl From<ToStrError> for ConversaError {
-    fn from(value: ToStrError) -> Self {
-        ConversaError::InvalidData(value.to_string())
+    /// Sends the `OpenAI-Organization` header, which selects the organization used and billed
+    /// for the requests.
+    pub fn organization(self, organization_id: impl Into<String>) -> Self {
+        self.default_header("OpenAI-Organization", organization_id)
     }
-}
 
-impl From<FromUtf8Error> for ConversaError {
-    fn from(value: FromUtf8Error) -> Self {
-        ConversaError::InvalidData(value.to_string())
+    /// Sends the `OpenAI-Project` header, which selects the project used and billed for the
+    /// requests.
+    pub fn project(self, project_id: impl Into<String>) -> Self {
+        self.default_header("OpenAI-Project", project_id)
     }
-}
 
-impl From<std::io::Error> for ConversaError {
-    fn from(value: std::io::Error) -> Self {
-        ConversaError::IoError(value.to_string())
+    /// Sets the value of the `OpenAI-Beta` header sent with the beta operations of the
+    /// Assistants API. It defaults to `assistants=v2`.
+    pub fn openai_beta(mut self, value: impl Into<String>) -> Self {
+        self.openai_beta = Some(value.into());
+        self
     }
-}
 
-impl std::fmt::Display for ConversaError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ConversaError::ClientError(msg) => write!(f, "Client error: {msg}",),
-            ConversaError::InvalidData(msg) => write!(f, "Invalid data: {msg}",),
-            ConversaError::UnexpectedStatusCode { code, response } => {
-                write!(f, "Unexpected status code {code}: {response}",)
-            }
-            ConversaError::IoError(msg) => write!(f, "std::io error: {msg}",),
-            ConversaError::UnexpectedContentType(content_type) => {
-                write!(f, "Unexpected content type: {content_type}",)
-            }
-            ConversaError::ErrorResponse(err) => write!(f, "Error response: {err:?}",),
-            ConversaError::Error(err) => write!(f, "Error: {err:?}",),
-        }
+    /// Sends the beta operations without the `OpenAI-Beta` header.
+    pub fn without_openai_beta(mut self) -> Self {
+        self.openai_beta = None;
+        self
     }
-}
-impl std::error::Error for ConversaError {}
 
-pub type ConversaResult<T> = Result<T, ConversaError>;
+    /// Adds a header which is sent with every request, unless the operation sets the header
+    /// itself. Setting the same header twice keeps the last value.
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let name = name.into();
+        self.default_headers
+            .retain(|(n, _)| !n.eq_ignore_ascii_case(&name));
+        self.default_headers.push((name, value.into()));
+        self
+    }
 
-pub struct OpenAIClientBuilder {
-    api_key: String,
-    address: String,
-}
+    /// Retries failed requests according to `retry_policy`. By default requests are sent once.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
 
-impl OpenAIClientBuilder {
-    pub fn new(address: String, api_key: String) -> Self {
-        Self { api_key, address }
+    /// Limits the time from sending a request until its response body is completely received.
+    /// Streaming operations are not limited since they can take as long as the model needs, use
+    /// [`read_timeout`](Self::read_timeout) for them instead.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Limits the time spent establishing a connection to the server.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Limits the time waiting for the next piece of a response. It applies to every request of
+    /// the client, streams included: a stream whose events pause for longer, for example while
+    /// a reasoning model thinks, ends with a timeout error. Choose a value above the longest
+    /// pause expected between two events.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Sends the requests through a proxy. Without proxies the system proxy configured in the
+    /// environment variables is used.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Trusts an additional certificate authority, for example a private CA of a gateway.
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Authenticates the client with a certificate (mTLS).
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub fn identity(mut self, identity: Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Sends the requests with an already configured HTTP client. The connection options of
+    /// this builder can not be combined with it, only the request timeout is still applied.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Sends the requests through a custom transport instead of reqwest, for example an
+    /// in-memory fake in tests. Neither an HTTP client nor connection options can be combined
+    /// with it, only the request timeout is still passed on with every request.
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Holds back requests which would exceed the budgets of `rate_limiter`. Every attempt of a
+    /// retried request counts against the budget.
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Sends the requests to Azure OpenAI, at the endpoint of the resource given as address.
+    pub fn azure(mut self, azure: AzureConfig) -> Self {
+        self.azure = Some(azure);
+        self
+    }
+
+    /// Asks `provider` for the token of every request instead of using the API key given to
+    /// [`new`](Self::new), so that the key can be rotated while the client is running.
+    pub fn credential_provider(mut self, provider: impl CredentialProvider + 'static) -> Self {
+        self.credentials = Arc::new(provider);
+        self
+    }
+
+    /// Adapts the requests and responses to the server, by default the OpenAI API.
+    pub fn compatibility_profile(mut self, profile: CompatibilityProfile) -> Self {
+        self.compatibility_profile = profile;
+        self
+    }
+
+    /// Runs `interceptor` around every request, after the interceptors added before it. See
+    /// [`Interceptor`] for the order of the hooks.
+    pub fn interceptor(mut self, interceptor: impl Interceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    fn build_transport(&mut self) -> ConversaResult<Arc<dyn Transport>> {
+        let mut builder = reqwest::ClientBuilder::new();
+        let mut has_connection_options = false;
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+            has_connection_options = true;
+        }
+        if let Some(timeout) = self.read_timeout {
+            builder = builder.read_timeout(timeout);
+            has_connection_options = true;
+        }
+        for proxy in self.proxies.drain(..) {
+            builder = builder.proxy(proxy);
+            has_connection_options = true;
+        }
+        #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+        {
+            for certificate in self.root_certificates.drain(..) {
+                builder = builder.add_root_certificate(certificate);
+                has_connection_options = true;
+            }
+            if let Some(identity) = self.identity.take() {
+                builder = builder.identity(identity);
+                has_connection_options = true;
+            }
+        }
+
+        match (self.transport.take(), self.http_client.take()) {
+            (Some(_), Some(_)) => Err(ConversaError::ClientError(
+                "A transport can not be set together with an HTTP client".to_string(),
+            )),
+            (Some(_), None) | (None, Some(_)) if has_connection_options => {
+                Err(ConversaError::ClientError(
+                    "The connection options can not be set together with an HTTP client or a transport"
+                        .to_string(),
+                ))
+            }
+            (Some(transport), None) => Ok(transport),
+            (None, Some(client)) => Ok(Arc::new(ReqwestTransport::new(client))),
+            (None, None) => Ok(Arc::new(ReqwestTransport::new(builder.build()?))),
+        }
     }
 
-    pub fn build(self) -> ConversaResult<OpenAIClient> {
-        let client = reqwest::ClientBuilder::new().build()?;
+    pub fn build(mut self) -> ConversaResult<OpenAIClient> {
+        let transport = self.build_transport()?;
+        let mut default_headers = HeaderMap::new();
+        for (name, value) in self.default_headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| ConversaError::ClientError(format!("Invalid header {name}: {e}")))?;
+            let header_value = HeaderValue::from_str(&value)
+                .map_err(|e| ConversaError::ClientError(format!("Invalid header {name}: {e}")))?;
+            default_headers.insert(header_name, header_value);
+        }
+        let openai_beta = self
+            .openai_beta
+            .filter(|_| self.compatibility_profile != CompatibilityProfile::LocalServer)
+            .map(|b| HeaderValue::from_str(&b))
+            .transpose()
+            .map_err(|e| ConversaError::ClientError(format!("Invalid OpenAI-Beta header: {e}")))?;
         Ok(OpenAIClient {
-            client,
-            api_key: self.api_key,
+            transport,
+            credentials: self.credentials,
             base_address: self.address,
+            retry_policy: self.retry_policy,
+            default_headers,
+            openai_beta,
+            request_timeout: self.request_timeout,
+            interceptors: self.interceptors,
+            rate_limiter: self.rate_limiter,
+            azure: self.azure,
+            compatibility_profile: self.compatibility_profile,
         })
     }
 }
 
 pub struct OpenAIClient {
-    pub(crate) client: Client,
-    pub(crate) api_key: String,
+    pub(crate) transport: Arc<dyn Transport>,
+    pub(crate) credentials: Arc<dyn CredentialProvider>,
     pub(crate) base_address: String,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) default_headers: HeaderMap,
+    // The beta operations all belong to gated API groups
+    #[cfg_attr(
+        not(any(
+            feature = "assistants",
+            feature = "fine-tuning",
+            feature = "vector-stores"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) openai_beta: Option<HeaderValue>,
+    pub(crate) request_timeout: Option<Duration>,
+    pub(crate) interceptors: Vec<Arc<dyn Interceptor>>,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    pub(crate) azure: Option<AzureConfig>,
+    pub(crate) compatibility_profile: CompatibilityProfile,
+}
+
+impl OpenAIClient {
+    /// Adds the `OpenAI-Beta` header required by the beta operations.
+    #[cfg_attr(
+        not(any(
+            feature = "assistants",
+            feature = "fine-tuning",
+            feature = "vector-stores"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn with_openai_beta(&self, request: HttpRequest) -> HttpRequest {
+        match &self.openai_beta {
+            Some(openai_beta) => {
+                request.header(HeaderName::from_static("openai-beta"), openai_beta.clone())
+            }
+            None => request,
+        }
+    }
+
+    /// Sends the request of `operation`, retrying it as allowed by the retry policy. The
+    /// response of the last attempt is returned whatever its status code.
+    pub(crate) async fn send(
+        &self,
+        request: HttpRequest,
+        operation: Operation,
+    ) -> ConversaResult<HttpResponse> {
+        let request = match self.request_timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        };
+        self.send_operation(request, operation, false).await
+    }
+
+    /// Sends the request like [`send`](Self::send) but without the request timeout, since the
+    /// response is streamed for as long as the model generates it.
+    pub(crate) async fn send_stream(
+        &self,
+        request: HttpRequest,
+        operation: Operation,
+    ) -> ConversaResult<HttpResponse> {
+        self.send_operation(request, operation, true).await
+    }
+
+    /// Sends the request of `operation`, recorded in a span with the `tracing` feature. The
+    /// usage reported in the body is only recorded when the response is not `streamed`.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    async fn send_operation(
+        &self,
+        mut request: HttpRequest,
+        operation: Operation,
+        streamed: bool,
+    ) -> ConversaResult<HttpResponse> {
+        request.operation = operation;
+        for (name, value) in &self.default_headers {
+            request.headers.entry(name).or_insert_with(|| value.clone());
+        }
+        if let Some(azure) = &self.azure {
+            azure.prepare(&mut request, &self.base_address)?;
+        }
+
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+
+            let trace = telemetry::OperationTrace::start(&request);
+            let result = self
+                .send_with_retries(request)
+                .instrument(trace.span.clone())
+                .await;
+            trace.record_response(result, streamed)
+        }
+        #[cfg(not(feature = "tracing"))]
+        self.send_with_retries(request).await
+    }
+
+    /// Sends the request, retrying it as allowed by the retry policy.
+    async fn send_with_retries(&self, request: HttpRequest) -> ConversaResult<HttpResponse> {
+        let retryable_operation = self
+            .retry_policy
+            .is_retryable_operation(&request.method, request.operation.id);
+        let mut attempt = 1;
+        loop {
+            if attempt >= self.retry_policy.max_attempts {
+                return self.send_attempt(request).await;
+            }
+
+            let delay = match self.send_attempt(request.clone()).await {
+                Ok(response) if retryable_operation && !response.status().is_success() => {
+                    let (response, error) = retry::error_of_response(response).await?;
+                    if !error.is_retryable() {
+                        return Ok(response);
+                    }
+                    self.retry_policy.delay(attempt, Some(response.headers()))
+                }
+                Ok(response) => return Ok(response),
+                Err(e)
+                    if self
+                        .retry_policy
+                        .is_retryable_error(&e, retryable_operation) =>
+                {
+                    self.retry_policy.delay(attempt, None)
+                }
+                Err(e) => return Err(e),
+            };
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Authenticates the request with `token` as the server expects it.
+    fn authorize(&self, request: HttpRequest, token: &str) -> ConversaResult<HttpRequest> {
+        match &self.azure {
+            Some(azure) => azure.authorize(request, token),
+            None if token.is_empty()
+                && self.compatibility_profile == CompatibilityProfile::LocalServer =>
+            {
+                Ok(request)
+            }
+            None => request.bearer_auth(token),
+        }
+    }
+
+    /// Sends one attempt of the request through the interceptors and the transport, with a
+    /// token from the credential provider.
+    async fn send_attempt(&self, request: HttpRequest) -> ConversaResult<HttpResponse> {
+        let token = self.credentials.token().await?;
+        let mut request = self.authorize(request, &token)?;
+        if self.interceptors.is_empty() {
+            return self.send_limited(request, &token).await;
+        }
+        let mut answered = None;
+        for (index, interceptor) in self.interceptors.iter().enumerate() {
+            if let Some(response) = interceptor.before_send(&mut request)? {
+                answered = Some((index, response));
+                break;
+            }
+        }
+        // A response given by an interceptor only goes back through the ones before it
+        let (answered_by, mut response) = match answered {
+            Some(answered) => answered,
+            None => (
+                self.interceptors.len(),
+                self.send_limited(request.clone(), &token).await?,
+            ),
+        };
+        for interceptor in self.interceptors[..answered_by].iter().rev() {
+            interceptor.after_receive(&request, &mut response)?;
+        }
+        Ok(response)
+    }
+
+    /// Sends the request through the transport once the rate limiter allows it.
+    async fn send_limited(
+        &self,
+        request: HttpRequest,
+        token: &str,
+    ) -> ConversaResult<HttpResponse> {
+        let operation_id = request.operation.id;
+        let reservation = match &self.rate_limiter {
+            Some(rate_limiter) => rate_limiter.acquire(&request).await,
+            None => None,
+        };
+        let mut response = self.transport.send(request).await?;
+        self.credentials.on_response(token, response.status());
+        if let Some(reservation) = reservation {
+            reservation.settle(&mut response);
+        }
+        self.compatibility_profile
+            .complete_response(operation_id, &mut response);
+        Ok(response)
+    }
 }
@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+use serde::Deserialize;
+
+use crate::transport::HttpResponse;
+
+/// A response body together with the metadata sent in the response headers.
+#[derive(Debug, PartialEq)]
+pub struct WithMeta<T> {
+    pub data: T,
+    pub meta: ResponseMeta,
+}
+
+/// Information about a request sent by the server in the response headers.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ResponseMeta {
+    pub status_code: u16,
+    /// The media type of the `content-type` header in lowercase and without parameters like
+    /// `charset`, e.g. `application/json`.
+    pub content_type: Option<String>,
+    /// The `x-request-id` header, which identifies the request when reporting issues.
+    pub request_id: Option<String>,
+    /// The `openai-processing-ms` header, the time the server spent on the request.
+    pub processing_time: Option<Duration>,
+    /// The `openai-version` header with the version of the API which handled the request.
+    pub openai_version: Option<String>,
+    /// The `openai-organization` header with the organization the request was billed to.
+    pub organization: Option<String>,
+    pub rate_limits: RateLimits,
+    /// All the response headers, including the ones above.
+    pub headers: HeaderMap,
+}
+
+/// The `x-ratelimit-*` headers, describing the limits of the model which handled the request.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RateLimits {
+    pub limit_requests: Option<u64>,
+    pub limit_tokens: Option<u64>,
+    pub remaining_requests: Option<u64>,
+    pub remaining_tokens: Option<u64>,
+    /// Time until the request limit is fully restored.
+    pub reset_requests: Option<Duration>,
+    /// Time until the token limit is fully restored.
+    pub reset_tokens: Option<Duration>,
+}
+
+/// The token counts in the `usage` of a response body, which are called `prompt_tokens` and
+/// `completion_tokens` in `CompletionUsage` and `input_tokens` and `output_tokens` in the usage
+/// of the Responses API.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+pub(crate) struct TokenUsage {
+    #[serde(alias = "prompt_tokens")]
+    pub(crate) input_tokens: Option<u64>,
+    #[serde(alias = "completion_tokens")]
+    pub(crate) output_tokens: Option<u64>,
+}
+
+impl ResponseMeta {
+    pub fn from_response(response: &HttpResponse) -> Self {
+        Self::from_headers(response.status().as_u16(), response.headers())
+    }
+
+    pub fn from_headers(status_code: u16, headers: &HeaderMap) -> Self {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.trim().to_string())
+        };
+        let number = |name: &str| header(name).and_then(|v| v.parse().ok());
+        let duration = |name: &str| header(name).and_then(|v| parse_duration(&v));
+
+        Self {
+            status_code,
+            content_type: header("content-type").and_then(|v| media_type(&v)),
+            request_id: header("x-request-id"),
+            processing_time: number("openai-processing-ms").map(Duration::from_millis),
+            openai_version: header("openai-version"),
+            organization: header("openai-organization"),
+            rate_limits: RateLimits {
+                limit_requests: number("x-ratelimit-limit-requests"),
+                limit_tokens: number("x-ratelimit-limit-tokens"),
+                remaining_requests: number("x-ratelimit-remaining-requests"),
+                remaining_tokens: number("x-ratelimit-remaining-tokens"),
+                reset_requests: duration("x-ratelimit-reset-requests"),
+                reset_tokens: duration("x-ratelimit-reset-tokens"),
+            },
+            headers: headers.clone(),
+        }
+    }
+}
+
+/// The media type of a `content-type` value, `application/json; charset=utf-8` becomes
+/// `application/json`.
+pub(crate) fn media_type(value: &str) -> Option<String> {
+    let media_type = value.split(';').next().unwrap_or_default().trim();
+    (!media_type.is_empty()).then(|| media_type.to_ascii_lowercase())
+}
+
+/// Parses the durations of the reset headers, which look like `1s`, `6m0s`, `1h2m3.5s` or
+/// `20ms`.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let mut total = 0.0;
+    let mut rest = value;
+    while !rest.is_empty() {
+        let number_end = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let number: f64 = rest[..number_end].parse().ok()?;
+        rest = &rest[number_end..];
+        let unit_end = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(rest.len());
+        let seconds = match &rest[..unit_end] {
+            "h" => 3600.0,
+            "m" => 60.0,
+            "s" => 1.0,
+            "ms" => 0.001,
+            _ => return None,
+        };
+        total += number * seconds;
+        rest = &rest[unit_end..];
+    }
+    Duration::try_from_secs_f64(total).ok()
+}
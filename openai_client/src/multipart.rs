@@ -1,6 +1,8 @@
+use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 
-use crate::ConversaError;
+use crate::transport::text_value;
+use crate::{ConversaError, ConversaResult};
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct File {
@@ -15,13 +17,15 @@ impl TryFrom<&std::path::Path> for File {
     fn try_from(value: &std::path::Path) -> Result<Self, Self::Error> {
         let file_name = value
             .file_name()
-            .ok_or(ConversaError::IoError(
-                "Path does not have file name".to_string(),
-            ))?
+            .ok_or(ConversaError::IoError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Path does not have file name",
+            )))?
             .to_str()
-            .ok_or(ConversaError::IoError(
-                "Failed to convert OsStr to str".to_string(),
-            ))?
+            .ok_or(ConversaError::IoError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Failed to convert OsStr to str",
+            )))?
             .to_string();
 
         let file_data = std::fs::read(value)?;
@@ -32,89 +36,234 @@ impl TryFrom<&std::path::Path> for File {
     }
 }
 
+impl File {
+    /// Guesses the MIME type from the extension of the file name, since the API uses it to
+    /// validate the format of images.
+    pub(crate) fn mime_type(&self) -> &'static str {
+        let extension = std::path::Path::new(&self.file_name)
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("png") => "image/png",
+            Some("jpg" | "jpeg") => "image/jpeg",
+            Some("webp") => "image/webp",
+            Some("gif") => "image/gif",
+            Some("mp3" | "mpga" | "mpeg") => "audio/mpeg",
+            Some("mp4" | "m4a") => "audio/mp4",
+            Some("wav") => "audio/wav",
+            Some("ogg") => "audio/ogg",
+            Some("flac") => "audio/flac",
+            Some("webm") => "audio/webm",
+            Some("json") => "application/json",
+            Some("jsonl") => "text/jsonl",
+            Some("pdf") => "application/pdf",
+            Some("txt") => "text/plain",
+            _ => "application/octet-stream",
+        }
+    }
+
+    fn into_part(self) -> Part {
+        Part {
+            content_type: Some(self.mime_type().to_string()),
+            file_name: Some(self.file_name),
+            data: self.file_data.into(),
+        }
+    }
+}
+
+/// A `multipart/form-data` form, which is encoded by the transport sending it.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Form {
+    parts: Vec<(String, Part)>,
+}
+
+/// A field of a [`Form`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Part {
+    pub file_name: Option<String>,
+    pub content_type: Option<String>,
+    pub data: Bytes,
+}
+
+impl Form {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn text(self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let part = Part {
+            file_name: None,
+            content_type: None,
+            data: value.into().into(),
+        };
+        self.part(name, part)
+    }
+
+    pub fn part(mut self, name: impl Into<String>, part: Part) -> Self {
+        self.parts.push((name.into(), part));
+        self
+    }
+
+    /// The fields in the order they are sent.
+    pub fn parts(&self) -> &[(String, Part)] {
+        &self.parts
+    }
+
+    pub fn into_parts(self) -> Vec<(String, Part)> {
+        self.parts
+    }
+}
+
 // Some types need to be converted into multipart form. This is not easy to automate,
 // so the methods to do this are manually defined here
 
+#[cfg(feature = "audio")]
 impl crate::types::CreateTranscriptionRequest {
-    pub(crate) fn into_multipart_form(self) -> reqwest::multipart::Form {
-        let file_part = reqwest::multipart::Part::bytes(self.file.file_data.clone())
-            .mime_str("application/octet-stream")
-            .unwrap()
-            .file_name(self.file.file_name.clone());
-        reqwest::multipart::Form::new()
-            .text(
-                "model",
-                serde_json::to_string(&self.model)
-                    .unwrap()
-                    .replace(r#"""#, ""),
-            )
-            .part("file", file_part)
+    pub(crate) fn into_multipart_form(self) -> ConversaResult<Form> {
+        let mut form = Form::new()
+            .text("model", text_value(&self.model)?)
+            .part("file", self.file.into_part());
+        if let Some(language) = self.language {
+            form = form.text("language", language);
+        }
+        if let Some(prompt) = self.prompt {
+            form = form.text("prompt", prompt);
+        }
+        if let Some(response_format) = &self.response_format {
+            form = form.text("response_format", text_value(response_format)?);
+        }
+        if let Some(temperature) = self.temperature {
+            form = form.text("temperature", temperature.to_string());
+        }
+        // Arrays are sent as one field per item with the brackets in the name
+        for include in self.include.iter().flatten() {
+            form = form.text("include[]", text_value(include)?);
+        }
+        for granularity in self.timestamp_granularities.into_iter().flatten() {
+            form = form.text("timestamp_granularities[]", granularity);
+        }
+        if let Some(stream) = self.stream {
+            form = form.text("stream", stream.to_string());
+        }
+        if let Some(chunking_strategy) = &self.chunking_strategy {
+            form = form.text("chunking_strategy", text_value(chunking_strategy)?);
+        }
+        Ok(form)
     }
 }
 
+#[cfg(feature = "audio")]
 impl crate::types::CreateTranslationRequest {
-    pub(crate) fn into_multipart_form(self) -> reqwest::multipart::Form {
-        let file_part = reqwest::multipart::Part::bytes(self.file.file_data.clone())
-            .mime_str("application/octet-stream")
-            .unwrap()
-            .file_name(self.file.file_name.clone());
-        reqwest::multipart::Form::new()
-            .text(
-                "model",
-                serde_json::to_string(&self.model)
-                    .unwrap()
-                    .replace(r#"""#, ""),
-            )
-            .part("file", file_part)
+    pub(crate) fn into_multipart_form(self) -> ConversaResult<Form> {
+        let mut form = Form::new()
+            .text("model", text_value(&self.model)?)
+            .part("file", self.file.into_part());
+        if let Some(prompt) = self.prompt {
+            form = form.text("prompt", prompt);
+        }
+        if let Some(response_format) = &self.response_format {
+            form = form.text("response_format", text_value(response_format)?);
+        }
+        if let Some(temperature) = self.temperature {
+            form = form.text("temperature", temperature.to_string());
+        }
+        Ok(form)
     }
 }
 
 impl crate::types::CreateContainerFileBody {
-    pub(crate) fn into_multipart_form(self) -> reqwest::multipart::Form {
-        let form = reqwest::multipart::Form::new();
-        if let Some(file) = &self.file {
-            let file_part = reqwest::multipart::Part::bytes(file.file_data.clone())
-                .mime_str("application/octet-stream")
-                .unwrap()
-                .file_name(file.file_name.clone());
-            form.part("file", file_part)
-        } else {
-            form
-        }
+    pub(crate) fn into_multipart_form(self) -> ConversaResult<Form> {
+        let form = Form::new();
+        Ok(match self.file {
+            Some(file) => form.part("file", file.into_part()),
+            None => form,
+        })
     }
 }
 
 impl crate::types::CreateFileRequest {
-    pub(crate) fn into_multipart_form(self) -> reqwest::multipart::Form {
-        let file_part = reqwest::multipart::Part::bytes(self.file.file_data.clone())
-            .mime_str("application/octet-stream")
-            .unwrap()
-            .file_name(self.file.file_name.clone());
-        reqwest::multipart::Form::new()
-            .text(
-                "purpose",
-                serde_json::to_string(&self.purpose)
-                    .unwrap()
-                    .replace(r#"""#, ""),
-            )
-            .part("file", file_part)
+    pub(crate) fn into_multipart_form(self) -> ConversaResult<Form> {
+        Ok(Form::new()
+            .text("purpose", text_value(&self.purpose)?)
+            .part("file", self.file.into_part()))
     }
 }
 
+#[cfg(feature = "images")]
 impl crate::types::CreateImageEditRequest {
-    pub(crate) fn into_multipart_form(self) -> reqwest::multipart::Form {
-        todo!()
+    pub(crate) fn into_multipart_form(self) -> ConversaResult<Form> {
+        let mut form = Form::new();
+        match self.image {
+            crate::types::CreateImageEditRequestImage::File(image) => {
+                form = form.part("image", image.into_part());
+            }
+            crate::types::CreateImageEditRequestImage::ArrayFile(images) => {
+                for image in images {
+                    form = form.part("image[]", image.into_part());
+                }
+            }
+        }
+        form = form.text("prompt", self.prompt);
+        if let Some(mask) = self.mask {
+            form = form.part("mask", mask.into_part());
+        }
+        if let Some(background) = &self.background {
+            form = form.text("background", text_value(background)?);
+        }
+        if let Some(model) = &self.model {
+            form = form.text("model", text_value(model)?);
+        }
+        if let Some(n) = self.n {
+            form = form.text("n", n.to_string());
+        }
+        if let Some(size) = &self.size {
+            form = form.text("size", text_value(size)?);
+        }
+        if let Some(response_format) = &self.response_format {
+            form = form.text("response_format", text_value(response_format)?);
+        }
+        if let Some(output_format) = &self.output_format {
+            form = form.text("output_format", text_value(output_format)?);
+        }
+        if let Some(output_compression) = self.output_compression {
+            form = form.text("output_compression", output_compression.to_string());
+        }
+        if let Some(user) = self.user {
+            form = form.text("user", user);
+        }
+        if let Some(quality) = &self.quality {
+            form = form.text("quality", text_value(quality)?);
+        }
+        Ok(form)
     }
 }
 
+#[cfg(feature = "images")]
 impl crate::types::CreateImageVariationRequest {
-    pub(crate) fn into_multipart_form(self) -> reqwest::multipart::Form {
-        todo!()
+    pub(crate) fn into_multipart_form(self) -> ConversaResult<Form> {
+        let mut form = Form::new().part("image", self.image.into_part());
+        if let Some(model) = &self.model {
+            form = form.text("model", text_value(model)?);
+        }
+        if let Some(n) = self.n {
+            form = form.text("n", n.to_string());
+        }
+        if let Some(response_format) = &self.response_format {
+            form = form.text("response_format", text_value(response_format)?);
+        }
+        if let Some(size) = &self.size {
+            form = form.text("size", text_value(size)?);
+        }
+        if let Some(user) = self.user {
+            form = form.text("user", user);
+        }
+        Ok(form)
     }
 }
 
 impl crate::types::AddUploadPartRequest {
-    pub(crate) fn into_multipart_form(self) -> reqwest::multipart::Form {
-        todo!()
+    pub(crate) fn into_multipart_form(self) -> ConversaResult<Form> {
+        Ok(Form::new().part("data", self.data.into_part()))
     }
 }
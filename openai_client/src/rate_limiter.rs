@@ -0,0 +1,339 @@
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use bytes::Bytes;
+use futures::{Stream, ready};
+use serde::Deserialize;
+
+use crate::{
+    ConversaResult,
+    meta::{ResponseMeta, TokenUsage},
+    transport::{BodyStream, HttpRequest, HttpResponse, RequestBody},
+};
+
+// The API limits the requests and tokens per minute of every model. Clients sharing a key can
+// exceed those limits together, so the requests are held back here until the budget allows
+// them instead of being answered with 429 by the server.
+
+/// The requests and tokens per minute a model may use.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    pub requests_per_minute: Option<u32>,
+    pub tokens_per_minute: Option<u32>,
+}
+
+impl Budget {
+    pub fn with_requests_per_minute(mut self, requests_per_minute: u32) -> Self {
+        self.requests_per_minute = Some(requests_per_minute);
+        self
+    }
+
+    pub fn with_tokens_per_minute(mut self, tokens_per_minute: u32) -> Self {
+        self.tokens_per_minute = Some(tokens_per_minute);
+        self
+    }
+}
+
+/// Delays requests which would exceed the [`Budget`] of their model.
+///
+/// The budget is replenished continuously, so a model allowed 60 requests per minute can send
+/// one request per second once its budget is used up. Waiting requests are sent in the order
+/// they arrived. The tokens of a request are estimated from its body as a quarter of its length
+/// plus the maximum number of output tokens it asks for. The `x-ratelimit-remaining-*` headers
+/// of the response lower the budget when the server counted more, for example because other
+/// clients use the same key. Without those headers the estimate is corrected with the `usage`
+/// reported in the response.
+///
+/// Clones share their budgets, so one limiter can be given to several clients. Requests without
+/// a `model` are not limited.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    state: Arc<Mutex<LimiterState>>,
+}
+
+#[derive(Debug, Default)]
+struct LimiterState {
+    model_budgets: HashMap<String, Budget>,
+    default_budget: Option<Budget>,
+    models: HashMap<String, ModelState>,
+}
+
+#[derive(Debug)]
+struct ModelState {
+    requests: Option<Bucket>,
+    tokens: Option<Bucket>,
+}
+
+/// A token bucket which may go negative, in which case the requests which took from it wait
+/// until it is refilled.
+#[derive(Debug)]
+struct Bucket {
+    per_minute: f64,
+    available: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(per_minute: u32) -> Self {
+        Self {
+            per_minute: per_minute.max(1) as f64,
+            available: per_minute as f64,
+            updated: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.updated).as_secs_f64();
+        self.available = (self.available + self.per_minute * elapsed / 60.0).min(self.per_minute);
+        self.updated = now;
+    }
+
+    /// Takes `amount` and returns how long the request has to wait for it.
+    fn take(&mut self, amount: f64) -> Duration {
+        self.refill();
+        // A request larger than the budget would never fit, it waits for a full budget instead
+        self.available -= amount.min(self.per_minute);
+        if self.available >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.available * 60.0 / self.per_minute)
+        }
+    }
+
+    fn give_back(&mut self, amount: f64) {
+        self.refill();
+        self.available = (self.available + amount).min(self.per_minute);
+    }
+
+    fn limit_to(&mut self, remaining: u64) {
+        self.refill();
+        self.available = self.available.min(remaining as f64);
+    }
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the requests using `model`.
+    pub fn with_model_budget(self, model: impl Into<String>, budget: Budget) -> Self {
+        self.lock().model_budgets.insert(model.into(), budget);
+        self
+    }
+
+    /// Limits the requests using a model without a budget of its own.
+    pub fn with_default_budget(self, budget: Budget) -> Self {
+        self.lock().default_budget = Some(budget);
+        self
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LimiterState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Waits until the budget of the model of `request` allows sending it. The budget is given
+    /// back if the caller stops waiting.
+    pub(crate) async fn acquire(&self, request: &HttpRequest) -> Option<Reservation> {
+        let estimate = RequestEstimate::from_request(request)?;
+        let wait = {
+            let mut state = self.lock();
+            let budget = state
+                .model_budgets
+                .get(&estimate.model)
+                .or(state.default_budget.as_ref())
+                .copied()?;
+            let model = state
+                .models
+                .entry(estimate.model.clone())
+                .or_insert_with(|| ModelState {
+                    requests: budget.requests_per_minute.map(Bucket::new),
+                    tokens: budget.tokens_per_minute.map(Bucket::new),
+                });
+            let request_wait = model
+                .requests
+                .as_mut()
+                .map(|b| b.take(1.0))
+                .unwrap_or_default();
+            let token_wait = model
+                .tokens
+                .as_mut()
+                .map(|b| b.take(estimate.tokens as f64))
+                .unwrap_or_default();
+            request_wait.max(token_wait)
+        };
+        let mut reservation = Reservation {
+            limiter: self.clone(),
+            model: estimate.model,
+            estimated_tokens: estimate.tokens,
+            waiting: true,
+        };
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+        reservation.waiting = false;
+        Some(reservation)
+    }
+
+    fn update_model(&self, model: &str, update: impl FnOnce(&mut ModelState)) {
+        if let Some(model) = self.lock().models.get_mut(model) {
+            update(model);
+        }
+    }
+}
+
+/// The budget taken by a request.
+pub(crate) struct Reservation {
+    limiter: RateLimiter,
+    model: String,
+    estimated_tokens: u64,
+    /// Whether the request is still waiting for the budget, so that it was not sent.
+    waiting: bool,
+}
+
+impl Reservation {
+    /// Corrects the budget with the rate limit headers of `response` or, once its body is
+    /// received, with the usage reported in it.
+    pub(crate) fn settle(self, response: &mut HttpResponse) {
+        let meta = ResponseMeta::from_headers(response.status().as_u16(), response.headers());
+        let rate_limits = &meta.rate_limits;
+        self.limiter.update_model(&self.model, |model| {
+            if let (Some(bucket), Some(remaining)) =
+                (&mut model.requests, rate_limits.remaining_requests)
+            {
+                bucket.limit_to(remaining);
+            }
+            if let (Some(bucket), Some(remaining)) =
+                (&mut model.tokens, rate_limits.remaining_tokens)
+            {
+                bucket.limit_to(remaining);
+            }
+        });
+
+        // The remaining tokens reported by the server already account for the usage
+        let reports_usage = rate_limits.remaining_tokens.is_none()
+            && response.status().is_success()
+            && meta.content_type.as_deref() == Some("application/json");
+        if reports_usage {
+            let body = std::mem::replace(&mut response.body, Box::pin(futures::stream::empty()));
+            response.body = Box::pin(MeteredBody {
+                body,
+                received: Vec::new(),
+                reservation: Some(self),
+            });
+        }
+    }
+
+    fn correct_tokens(&self, usage: TokenUsage) {
+        let Some(used_tokens) = usage
+            .input_tokens
+            .map(|i| i + usage.output_tokens.unwrap_or_default())
+        else {
+            return;
+        };
+        let difference = self.estimated_tokens as f64 - used_tokens as f64;
+        self.limiter.update_model(&self.model, |model| {
+            if let Some(bucket) = &mut model.tokens {
+                bucket.give_back(difference);
+            }
+        });
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        if !self.waiting {
+            return;
+        }
+        let estimated_tokens = self.estimated_tokens as f64;
+        self.limiter.update_model(&self.model, |model| {
+            if let Some(bucket) = &mut model.requests {
+                bucket.give_back(1.0);
+            }
+            if let Some(bucket) = &mut model.tokens {
+                bucket.give_back(estimated_tokens.min(bucket.per_minute));
+            }
+        });
+    }
+}
+
+/// The fields of a request body which determine the model and the tokens it uses.
+#[derive(Deserialize)]
+struct RequestFields {
+    model: Option<String>,
+    max_tokens: Option<u64>,
+    max_completion_tokens: Option<u64>,
+    max_output_tokens: Option<u64>,
+    n: Option<u64>,
+}
+
+struct RequestEstimate {
+    model: String,
+    tokens: u64,
+}
+
+impl RequestEstimate {
+    fn from_request(request: &HttpRequest) -> Option<Self> {
+        match &request.body {
+            RequestBody::Empty => None,
+            RequestBody::Json(body) => {
+                let fields = serde_json::from_slice::<RequestFields>(body).ok()?;
+                let max_output_tokens = fields
+                    .max_completion_tokens
+                    .or(fields.max_tokens)
+                    .or(fields.max_output_tokens)
+                    .unwrap_or_default();
+                Some(Self {
+                    model: fields.model?,
+                    tokens: body.len() as u64 / 4
+                        + max_output_tokens * fields.n.unwrap_or(1).max(1),
+                })
+            }
+            RequestBody::Multipart(_) => Some(Self {
+                model: request.model()?,
+                tokens: 0,
+            }),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct UsageField {
+    usage: Option<TokenUsage>,
+}
+
+/// The body of a response, which corrects the budget with the usage reported in it once it is
+/// completely received.
+struct MeteredBody {
+    body: BodyStream,
+    received: Vec<u8>,
+    reservation: Option<Reservation>,
+}
+
+impl Stream for MeteredBody {
+    type Item = ConversaResult<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let item = ready!(this.body.as_mut().poll_next(cx));
+        match &item {
+            Some(Ok(bytes)) => this.received.extend_from_slice(bytes),
+            Some(Err(_)) => this.reservation = None,
+            None => {
+                if let (Some(reservation), Ok(UsageField { usage: Some(usage) })) = (
+                    this.reservation.take(),
+                    serde_json::from_slice::<UsageField>(&this.received),
+                ) {
+                    reservation.correct_tokens(usage);
+                }
+            }
+        }
+        Poll::Ready(item)
+    }
+}
@@ -0,0 +1,211 @@
+use std::{
+    hash::{BuildHasher, Hasher},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::{Method, header::HeaderMap};
+
+use crate::{ApiError, ConversaError, ConversaResult, meta::ResponseMeta, transport::HttpResponse};
+
+// Transient failures (rate limits, overloaded servers, dropped connections) are retried in a
+// single place which every operation goes through, so the generated methods don't need to know
+// about it.
+
+/// Decides which failed requests are sent again and how long to wait in between.
+///
+/// Requests are retried after a connection error, since they never reached the server. The
+/// other failures [`ConversaError::is_retryable`] considers transient, such as timeouts, 408,
+/// 409, 429 or 5xx responses, are only retried for idempotent requests (`GET`, `PUT`, `DELETE`,
+/// ...) and the operations listed in `safe_operations`. Running out of quota is not retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total number of attempts including the first one. A value of 1 disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry. It doubles with every further attempt.
+    pub base_delay: Duration,
+    /// Upper bound of the exponential delay.
+    pub max_delay: Duration,
+    /// Upper bound of a delay requested by the server with the `retry-after` headers.
+    pub max_retry_after: Duration,
+    /// Fraction between 0 and 1 of the delay which is randomized to spread out clients that
+    /// failed at the same time.
+    pub jitter: f64,
+    /// Ids of non-idempotent operations which are safe to repeat, e.g. `createEmbedding`.
+    pub safe_operations: Vec<String>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+            max_retry_after: Duration::from_secs(60),
+            jitter: 0.25,
+            safe_operations: Vec::new(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy which sends every request exactly once.
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    pub fn with_delays(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn with_max_retry_after(mut self, max_retry_after: Duration) -> Self {
+        self.max_retry_after = max_retry_after;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Allows retrying the operation with the given id although it is not idempotent.
+    pub fn with_safe_operation(mut self, operation_id: impl Into<String>) -> Self {
+        self.safe_operations.push(operation_id.into());
+        self
+    }
+
+    pub(crate) fn is_retryable_operation(&self, method: &Method, operation_id: &str) -> bool {
+        method.is_idempotent() || self.safe_operations.iter().any(|o| o == operation_id)
+    }
+
+    /// Whether the request failing with `error` is sent again. Requests which failed to connect
+    /// never reached the server, so they are retried whatever the operation.
+    pub(crate) fn is_retryable_error(
+        &self,
+        error: &ConversaError,
+        retryable_operation: bool,
+    ) -> bool {
+        let not_sent = match error {
+            ConversaError::Http(e) => e.is_connect(),
+            ConversaError::Transport(e) => e.is_connect(),
+            _ => false,
+        };
+        error.is_retryable() && (retryable_operation || not_sent)
+    }
+
+    /// The delay before the attempt following `attempt` (starting at 1). A delay requested by
+    /// the server takes precedence over the exponential backoff, up to `max_retry_after`.
+    pub(crate) fn delay(&self, attempt: u32, headers: Option<&HeaderMap>) -> Duration {
+        if let Some(delay) = headers.and_then(retry_after) {
+            return delay.min(self.max_retry_after);
+        }
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+        exponential.mul_f64(1.0 - self.jitter.clamp(0.0, 1.0) * random_fraction())
+    }
+}
+
+/// Reads an error response, so that its error can be inspected, and returns it with the body
+/// still available to the caller.
+pub(crate) async fn error_of_response(
+    response: HttpResponse,
+) -> ConversaResult<(HttpResponse, ConversaError)> {
+    let meta = ResponseMeta::from_response(&response);
+    let HttpResponse {
+        status, headers, ..
+    } = &response;
+    let (status, headers) = (*status, headers.clone());
+    let body = response.bytes().await?;
+    let error = ApiError::from_response(meta, &body).into();
+    let response = HttpResponse {
+        status,
+        headers,
+        body: Box::pin(futures::stream::once(async { Ok(body) })),
+    };
+    Ok((response, error))
+}
+
+/// Reads the delay from the `retry-after-ms` header or from the `retry-after` header, in seconds
+/// or as an HTTP date. A value which can not be parsed leaves the delay to the backoff, a value
+/// too large for a `Duration` is the longest delay.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let header_value = |name: &str| Some(headers.get(name)?.to_str().ok()?.trim());
+    let delay = |value: &str, unit: f64| {
+        let value = value.parse::<f64>().ok()?;
+        (value >= 0.0).then(|| Duration::try_from_secs_f64(value * unit).unwrap_or(Duration::MAX))
+    };
+    if let Some(delay) = header_value("retry-after-ms").and_then(|v| delay(v, 0.001)) {
+        return Some(delay);
+    }
+    let retry_after = header_value("retry-after")?;
+    if let Some(delay) = delay(retry_after, 1.0) {
+        return Some(delay);
+    }
+    let date = parse_http_date(retry_after)?;
+    // A date in the past allows retrying right away
+    Some(date.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+/// Parses an HTTP date in the IMF-fixdate format, e.g. `Sun, 06 Nov 1994 08:49:37 GMT`. The
+/// obsolete formats servers must no longer send and dates beyond the range of `SystemTime` are
+/// not supported.
+fn parse_http_date(value: &str) -> Option<SystemTime> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let (_, date) = value.split_once(", ")?;
+    let [day, month, year, time, "GMT"] = date.split(' ').collect::<Vec<_>>()[..] else {
+        return None;
+    };
+    let day = day.parse::<i64>().ok().filter(|d| (1..=31).contains(d))?;
+    let month = MONTHS.iter().position(|m| *m == month)? as i64 + 1;
+    let year = year.parse::<i64>().ok()?;
+    let [hours, minutes, seconds] = time
+        .split(':')
+        .map(|t| t.parse::<u64>().ok())
+        .collect::<Option<Vec<_>>>()?[..]
+    else {
+        return None;
+    };
+    if hours > 23 || minutes > 59 || seconds > 60 {
+        return None;
+    }
+
+    // Days since 1970-01-01 of the proleptic Gregorian calendar, with years starting in March
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era
+        .checked_mul(146_097)?
+        .checked_add(day_of_era - 719_468)
+        .and_then(|days| u64::try_from(days).ok())?;
+    let seconds = days
+        .checked_mul(86_400)?
+        .checked_add(hours * 3_600 + minutes * 60 + seconds)?;
+    UNIX_EPOCH.checked_add(Duration::from_secs(seconds))
+}
+
+/// A number in `[0, 1)` which is good enough for jitter without pulling in a random generator.
+fn random_fraction() -> f64 {
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
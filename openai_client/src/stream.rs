@@ -0,0 +1,531 @@
+use std::{
+    borrow::Cow,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures::Stream;
+use serde::Deserialize;
+
+use crate::{
+    ApiError, ConversaError, ConversaResult, OpenAIClient,
+    meta::ResponseMeta,
+    transport::{BodyStream, HttpRequest, HttpResponse, Operation},
+};
+
+// The streaming endpoints answer with a `text/event-stream` body which can not be decoded
+// in one go like the other responses. The methods to consume them are manually defined here
+// and decode the server-sent events incrementally as the bytes arrive.
+
+/// A single frame received on a server-sent events stream.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ServerSentEvent {
+    pub event: Option<String>,
+    pub data: String,
+    pub id: Option<String>,
+}
+
+/// Incremental parser for the `text/event-stream` format.
+#[derive(Default)]
+struct EventParser {
+    buffer: Vec<u8>,
+    event: Option<String>,
+    data: Option<String>,
+    id: Option<String>,
+}
+
+impl EventParser {
+    fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    fn next_line(&mut self) -> Option<Vec<u8>> {
+        let end = self.buffer.iter().position(|&b| b == b'\n' || b == b'\r')?;
+        let separator_length = if self.buffer[end] == b'\r' {
+            match self.buffer.get(end + 1) {
+                Some(b'\n') => 2,
+                Some(_) => 1,
+                // A trailing `\r` might be followed by a `\n` in the next chunk
+                None => return None,
+            }
+        } else {
+            1
+        };
+        let line = self.buffer[..end].to_vec();
+        self.buffer.drain(..end + separator_length);
+        Some(line)
+    }
+
+    fn next_event(&mut self) -> ConversaResult<Option<ServerSentEvent>> {
+        while let Some(line) = self.next_line() {
+            let line = String::from_utf8(line)?;
+            if line.is_empty() {
+                if let Some(data) = self.data.take() {
+                    return Ok(Some(ServerSentEvent {
+                        event: self.event.take(),
+                        data,
+                        id: self.id.clone(),
+                    }));
+                }
+                self.event = None;
+                continue;
+            }
+
+            let (field, value) = match line.split_once(':') {
+                Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+                None => (line.as_str(), ""),
+            };
+            match field {
+                "event" => self.event = Some(value.to_string()),
+                "data" => match &mut self.data {
+                    Some(data) => {
+                        data.push('\n');
+                        data.push_str(value);
+                    }
+                    None => self.data = Some(value.to_string()),
+                },
+                "id" => self.id = Some(value.to_string()),
+                // Comments (empty field name), `retry` and unknown fields are ignored
+                _ => (),
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Items which can be decoded from the frames of a server-sent events stream.
+pub trait StreamEvent: Sized {
+    fn from_event(event: ServerSentEvent) -> ConversaResult<Self>;
+}
+
+/// Returns the name of the event, falling back to the `type` field of the data for servers
+/// which only send `data` lines.
+fn event_type(event: &ServerSentEvent) -> ConversaResult<Cow<'_, str>> {
+    #[derive(Deserialize)]
+    struct TypedEvent<'a> {
+        #[serde(rename = "type", borrow)]
+        r#type: Cow<'a, str>,
+    }
+
+    match &event.event {
+        Some(event_type) => Ok(Cow::Borrowed(event_type)),
+        None => Ok(serde_json::from_str::<TypedEvent>(&event.data)?.r#type),
+    }
+}
+
+impl StreamEvent for crate::types::CreateChatCompletionStreamResponse {
+    fn from_event(event: ServerSentEvent) -> ConversaResult<Self> {
+        serde_json::from_str(&event.data).map_err(|e| {
+            // Errors that happen after the stream started are sent as a regular event
+            match serde_json::from_str::<crate::types::ErrorResponse>(&event.data) {
+                Ok(error_response) => ApiError::from(error_response.error).into(),
+                Err(_) => ConversaError::from(e),
+            }
+        })
+    }
+}
+
+impl StreamEvent for crate::types::ResponseStreamEvent {
+    fn from_event(event: ServerSentEvent) -> ConversaResult<Self> {
+        let event_type = event_type(&event)?;
+        if event_type == "error" {
+            let error_event: crate::types::ResponseErrorEvent = serde_json::from_str(&event.data)?;
+            return Err(ApiError::from(crate::types::Error {
+                code: error_event.code,
+                message: error_event.message,
+                param: error_event.param,
+                r#type: event_type.into_owned(),
+            })
+            .into());
+        }
+        Ok(Self::from_event_type(&event_type, &event.data)?)
+    }
+}
+
+#[cfg(feature = "audio")]
+impl StreamEvent for crate::types::CreateSpeechResponseStreamEvent {
+    fn from_event(event: ServerSentEvent) -> ConversaResult<Self> {
+        Ok(Self::from_event_type(&event_type(&event)?, &event.data)?)
+    }
+}
+
+#[cfg(feature = "audio")]
+impl StreamEvent for crate::types::CreateTranscriptionResponseStreamEvent {
+    fn from_event(event: ServerSentEvent) -> ConversaResult<Self> {
+        Ok(Self::from_event_type(&event_type(&event)?, &event.data)?)
+    }
+}
+
+#[cfg(feature = "assistants")]
+impl StreamEvent for crate::types::AssistantStreamEvent {
+    fn from_event(event: ServerSentEvent) -> ConversaResult<Self> {
+        use crate::types::AssistantStreamEvent;
+
+        let event_type = event.event.as_deref().unwrap_or_default();
+        if event_type == "error" {
+            let error: crate::types::Error = serde_json::from_str(&event.data)?;
+            return Err(ApiError::from(error).into());
+        }
+
+        // The event objects wrap the data together with the event name
+        let event_json = format!(
+            r#"{{"event":{},"data":{}}}"#,
+            serde_json::to_string(event_type)?,
+            event.data
+        );
+        Ok(if event_type == "thread.created" {
+            AssistantStreamEvent::ThreadStreamEvent(serde_json::from_str(&event_json)?)
+        } else if event_type.starts_with("thread.run.step.") {
+            AssistantStreamEvent::RunStepStreamEvent(serde_json::from_str(&event_json)?)
+        } else if event_type.starts_with("thread.run.") {
+            AssistantStreamEvent::RunStreamEvent(serde_json::from_str(&event_json)?)
+        } else if event_type.starts_with("thread.message.") {
+            AssistantStreamEvent::MessageStreamEvent(serde_json::from_str(&event_json)?)
+        } else {
+            serde_json::from_str(&event_json)?
+        })
+    }
+}
+
+/// Stream of typed items decoded from the server-sent events of a streaming response.
+///
+/// The stream ends when the server closes the connection or sends the `[DONE]` marker.
+pub struct EventStream<T> {
+    body: BodyStream,
+    parser: EventParser,
+    finished: bool,
+    meta: ResponseMeta,
+    item: PhantomData<fn() -> T>,
+}
+
+impl<T> EventStream<T> {
+    pub(crate) async fn from_response(response: HttpResponse) -> ConversaResult<Self> {
+        let response = check_status(response).await?;
+        let meta = ResponseMeta::from_response(&response);
+        // Proxies sometimes drop the header, in which case the body is assumed to be the
+        // stream which was requested.
+        if let Some(content_type) = &meta.content_type
+            && content_type != "text/event-stream"
+        {
+            return Err(ConversaError::UnexpectedContentType(content_type.clone()));
+        }
+
+        Ok(Self {
+            meta,
+            body: response.bytes_stream(),
+            parser: EventParser::default(),
+            finished: false,
+            item: PhantomData,
+        })
+    }
+
+    /// The metadata sent in the headers of the response.
+    pub fn meta(&self) -> &ResponseMeta {
+        &self.meta
+    }
+}
+
+impl<T: StreamEvent> Stream for EventStream<T> {
+    type Item = ConversaResult<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if this.finished {
+                return Poll::Ready(None);
+            }
+
+            match this.parser.next_event() {
+                Ok(Some(event)) if event.data == "[DONE]" => this.finished = true,
+                Ok(Some(event)) => {
+                    return Poll::Ready(Some(
+                        T::from_event(event).map_err(|e| e.with_meta(&this.meta)),
+                    ));
+                }
+                Ok(None) => match this.body.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(Ok(bytes))) => this.parser.push(&bytes),
+                    Poll::Ready(Some(Err(e))) => {
+                        this.finished = true;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                    // Data of an event which was not terminated by an empty line is discarded
+                    Poll::Ready(None) => this.finished = true,
+                    Poll::Pending => return Poll::Pending,
+                },
+                Err(e) => {
+                    this.finished = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+    }
+}
+
+/// Stream of the raw chunks of a binary response body, as they are received.
+pub struct ByteStream {
+    body: BodyStream,
+    meta: ResponseMeta,
+}
+
+impl ByteStream {
+    #[cfg(feature = "audio")]
+    pub(crate) async fn from_response(response: HttpResponse) -> ConversaResult<Self> {
+        let response = check_status(response).await?;
+        Ok(Self {
+            meta: ResponseMeta::from_response(&response),
+            body: response.bytes_stream(),
+        })
+    }
+
+    /// The metadata sent in the headers of the response.
+    pub fn meta(&self) -> &ResponseMeta {
+        &self.meta
+    }
+
+    /// Writes the whole body to `writer` and returns the number of bytes written.
+    pub async fn write_to<W>(mut self, writer: &mut W) -> ConversaResult<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        use futures::StreamExt;
+        use tokio::io::AsyncWriteExt;
+
+        let mut length = 0;
+        while let Some(bytes) = self.next().await {
+            let bytes = bytes?;
+            writer.write_all(&bytes).await?;
+            length += bytes.len() as u64;
+        }
+        writer.flush().await?;
+        Ok(length)
+    }
+}
+
+impl Stream for ByteStream {
+    type Item = ConversaResult<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().body.as_mut().poll_next(cx)
+    }
+}
+
+async fn check_status(response: HttpResponse) -> ConversaResult<HttpResponse> {
+    if response.status().as_u16() != 200 {
+        let meta = ResponseMeta::from_response(&response);
+        let response_bytes = response.bytes().await?;
+        return Err(ApiError::from_response(meta, &response_bytes).into());
+    }
+
+    Ok(response)
+}
+
+impl OpenAIClient {
+    /// Streaming variant of [`OpenAIClient::create_chat_completion`]. The `stream` field of the
+    /// request is always enabled and the chunks are returned as they are generated.
+    pub async fn create_chat_completion_stream(
+        &self,
+        mut request_body: crate::types::CreateChatCompletionRequest,
+    ) -> ConversaResult<EventStream<crate::types::CreateChatCompletionStreamResponse>> {
+        request_body.object.stream = Some(true);
+        let address = format!("{}/chat/completions", self.base_address);
+        let mut request = HttpRequest::new(reqwest::Method::POST, address);
+        request = request.json(&request_body)?;
+        let result = self
+            .send_stream(
+                request,
+                Operation {
+                    id: "createChatCompletion",
+                    name: "create_chat_completion",
+                    path: "/chat/completions",
+                },
+            )
+            .await?;
+        EventStream::from_response(result).await
+    }
+
+    /// Streaming variant of [`OpenAIClient::create_response`]. The `stream` field of the request
+    /// is always enabled and every server-sent event is returned as a [`ResponseStreamEvent`].
+    /// Error events are returned as [`ConversaError::Api`].
+    ///
+    /// [`ResponseStreamEvent`]: crate::types::ResponseStreamEvent
+    pub async fn create_response_stream(
+        &self,
+        mut request_body: crate::types::CreateResponse,
+    ) -> ConversaResult<EventStream<crate::types::ResponseStreamEvent>> {
+        request_body.object.stream = Some(true);
+        let address = format!("{}/responses", self.base_address);
+        let mut request = HttpRequest::new(reqwest::Method::POST, address);
+        request = request.json(&request_body)?;
+        let result = self
+            .send_stream(
+                request,
+                Operation {
+                    id: "createResponse",
+                    name: "create_response",
+                    path: "/responses",
+                },
+            )
+            .await?;
+        EventStream::from_response(result).await
+    }
+
+    /// Streaming variant of [`OpenAIClient::create_run`]. The `stream` field of the request is
+    /// always enabled and the events of the run are returned as they happen.
+    #[cfg(feature = "assistants")]
+    pub async fn create_run_stream(
+        &self,
+        thread_id: &str,
+        include: Option<&[String]>,
+        mut request_body: crate::types::CreateRunRequest,
+    ) -> ConversaResult<EventStream<crate::types::AssistantStreamEvent>> {
+        request_body.stream = Some(true);
+        let address = format!("{}/threads/{thread_id}/runs", self.base_address);
+        let mut request = HttpRequest::new(reqwest::Method::POST, address);
+        request = self.with_openai_beta(request);
+        for q in include.unwrap_or_default() {
+            request = request.query(&[("include[]", q)])?;
+        }
+        request = request.json(&request_body)?;
+        let result = self
+            .send_stream(
+                request,
+                Operation {
+                    id: "createRun",
+                    name: "create_run",
+                    path: "/threads/{thread_id}/runs",
+                },
+            )
+            .await?;
+        EventStream::from_response(result).await
+    }
+
+    /// Streaming variant of [`OpenAIClient::create_thread_and_run`]. The `stream` field of the
+    /// request is always enabled and the events of the run are returned as they happen.
+    #[cfg(feature = "assistants")]
+    pub async fn create_thread_and_run_stream(
+        &self,
+        mut request_body: crate::types::CreateThreadAndRunRequest,
+    ) -> ConversaResult<EventStream<crate::types::AssistantStreamEvent>> {
+        request_body.stream = Some(true);
+        let address = format!("{}/threads/runs", self.base_address);
+        let mut request = HttpRequest::new(reqwest::Method::POST, address);
+        request = self.with_openai_beta(request);
+        request = request.json(&request_body)?;
+        let result = self
+            .send_stream(
+                request,
+                Operation {
+                    id: "createThreadAndRun",
+                    name: "create_thread_and_run",
+                    path: "/threads/runs",
+                },
+            )
+            .await?;
+        EventStream::from_response(result).await
+    }
+
+    /// Streaming variant of [`OpenAIClient::submit_tool_ouputs_to_run`]. The `stream` field of
+    /// the request is always enabled and the events of the resumed run are returned as they
+    /// happen.
+    #[cfg(feature = "assistants")]
+    pub async fn submit_tool_ouputs_to_run_stream(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+        mut request_body: crate::types::SubmitToolOutputsRunRequest,
+    ) -> ConversaResult<EventStream<crate::types::AssistantStreamEvent>> {
+        request_body.stream = Some(true);
+        let address = format!(
+            "{}/threads/{thread_id}/runs/{run_id}/submit_tool_outputs",
+            self.base_address
+        );
+        let mut request = HttpRequest::new(reqwest::Method::POST, address);
+        request = self.with_openai_beta(request);
+        request = request.json(&request_body)?;
+        let result = self
+            .send_stream(
+                request,
+                Operation {
+                    id: "submitToolOuputsToRun",
+                    name: "submit_tool_ouputs_to_run",
+                    path: "/threads/{thread_id}/runs/{run_id}/submit_tool_outputs",
+                },
+            )
+            .await?;
+        EventStream::from_response(result).await
+    }
+
+    /// Variant of [`OpenAIClient::create_speech`] which returns the audio as it is being
+    /// generated instead of waiting for the complete file.
+    #[cfg(feature = "audio")]
+    pub async fn create_speech_audio_stream(
+        &self,
+        mut request_body: crate::types::CreateSpeechRequest,
+    ) -> ConversaResult<ByteStream> {
+        request_body.stream_format = Some(crate::types::CreateSpeechRequestStreamFormat::Audio);
+        let address = format!("{}/audio/speech", self.base_address);
+        let mut request = HttpRequest::new(reqwest::Method::POST, address);
+        request = request.json(&request_body)?;
+        let result = self
+            .send_stream(
+                request,
+                Operation {
+                    id: "createSpeech",
+                    name: "create_speech",
+                    path: "/audio/speech",
+                },
+            )
+            .await?;
+        ByteStream::from_response(result).await
+    }
+
+    /// Streaming variant of [`OpenAIClient::create_speech`] which sets the `stream_format` of the
+    /// request to `sse` and returns the Base64 encoded audio chunks as server-sent events.
+    #[cfg(feature = "audio")]
+    pub async fn create_speech_stream(
+        &self,
+        mut request_body: crate::types::CreateSpeechRequest,
+    ) -> ConversaResult<EventStream<crate::types::CreateSpeechResponseStreamEvent>> {
+        request_body.stream_format = Some(crate::types::CreateSpeechRequestStreamFormat::Sse);
+        let address = format!("{}/audio/speech", self.base_address);
+        let mut request = HttpRequest::new(reqwest::Method::POST, address);
+        request = request.json(&request_body)?;
+        let result = self
+            .send_stream(
+                request,
+                Operation {
+                    id: "createSpeech",
+                    name: "create_speech",
+                    path: "/audio/speech",
+                },
+            )
+            .await?;
+        EventStream::from_response(result).await
+    }
+
+    /// Streaming variant of [`OpenAIClient::create_transcription`] which returns the transcribed
+    /// text as it is generated. Streaming is not supported by the `whisper-1` model.
+    #[cfg(feature = "audio")]
+    pub async fn create_transcription_stream(
+        &self,
+        mut request_body: crate::types::CreateTranscriptionRequest,
+    ) -> ConversaResult<EventStream<crate::types::CreateTranscriptionResponseStreamEvent>> {
+        request_body.stream = Some(true);
+        let address = format!("{}/audio/transcriptions", self.base_address);
+        let mut request = HttpRequest::new(reqwest::Method::POST, address);
+        request = request.multipart(request_body.into_multipart_form()?);
+        let result = self
+            .send_stream(
+                request,
+                Operation {
+                    id: "createTranscription",
+                    name: "create_transcription",
+                    path: "/audio/transcriptions",
+                },
+            )
+            .await?;
+        EventStream::from_response(result).await
+    }
+}
@@ -0,0 +1,282 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use bytes::Bytes;
+use futures::{Stream, ready};
+use serde::Deserialize;
+use tracing::{Span, field::Empty};
+
+use crate::{
+    ConversaError, ConversaResult,
+    meta::{TokenUsage, media_type},
+    transport::{BodyStream, HttpRequest, HttpResponse, RequestBody},
+};
+
+// Every operation is recorded in a span with the attributes of the OpenTelemetry semantic
+// conventions for HTTP clients and, for the operations generating content, for generative AI.
+// The span stays open until the response body is received, so that the token usage reported in
+// it can be recorded as well.
+
+/// The `gen_ai.operation.name` of the operations covered by the generative AI conventions.
+fn gen_ai_operation_name(operation_id: &str) -> Option<&'static str> {
+    match operation_id {
+        "createChatCompletion" | "createResponse" => Some("chat"),
+        "createCompletion" => Some("text_completion"),
+        "createEmbedding" => Some("embeddings"),
+        "createAssistant" => Some("create_agent"),
+        "createRun" | "createThreadAndRun" => Some("invoke_agent"),
+        _ => None,
+    }
+}
+
+#[derive(Deserialize)]
+struct RequestSummary {
+    model: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ResponseSummary {
+    id: Option<String>,
+    model: Option<String>,
+    #[serde(default)]
+    choices: Vec<ChoiceSummary>,
+    usage: Option<TokenUsage>,
+}
+
+#[derive(Deserialize)]
+struct ChoiceSummary {
+    finish_reason: Option<String>,
+}
+
+/// The span of an operation, from sending the request until the response is received.
+pub(crate) struct OperationTrace {
+    pub(crate) span: Span,
+    started: Instant,
+    gen_ai: bool,
+}
+
+impl OperationTrace {
+    pub(crate) fn start(request: &HttpRequest) -> Self {
+        Self {
+            span: operation_span(request),
+            started: Instant::now(),
+            gen_ai: gen_ai_operation_name(request.operation.id).is_some(),
+        }
+    }
+
+    /// Records the outcome of sending the request. The span of a successful request is kept
+    /// open by the body of the response until it is completely received.
+    pub(crate) fn record_response(
+        self,
+        result: ConversaResult<HttpResponse>,
+        streamed: bool,
+    ) -> ConversaResult<HttpResponse> {
+        let Self {
+            span,
+            started,
+            gen_ai,
+        } = self;
+        let mut response = match result {
+            Ok(response) => response,
+            Err(e) => {
+                record_error(&span, &e);
+                record_latency(&span, started);
+                return Err(e);
+            }
+        };
+
+        let status = response.status();
+        span.record("http.response.status_code", status.as_u16());
+        if let Some(request_id) = response
+            .headers()
+            .get("x-request-id")
+            .and_then(|v| v.to_str().ok())
+        {
+            span.record("openai.request_id", request_id);
+        }
+        if status.is_client_error() || status.is_server_error() {
+            span.record("error.type", status.as_str());
+            span.record("otel.status_code", "ERROR");
+        }
+
+        let is_json = response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok())
+            .and_then(media_type)
+            .is_some_and(|m| m == "application/json");
+        let read_summary = !streamed && status.is_success() && is_json && !span.is_disabled();
+        response.body = Box::pin(TracedBody {
+            body: response.body,
+            span,
+            started,
+            gen_ai,
+            received: read_summary.then(Vec::new),
+            finished: false,
+        });
+        Ok(response)
+    }
+}
+
+fn operation_span(request: &HttpRequest) -> Span {
+    let operation = request.operation;
+    let gen_ai_operation = gen_ai_operation_name(operation.id);
+    let span = tracing::info_span!(
+        "operation",
+        otel.name = Empty,
+        otel.kind = "client",
+        otel.status_code = Empty,
+        openai.operation_id = operation.id,
+        openai.request_id = Empty,
+        http.request.method = %request.method,
+        http.response.status_code = Empty,
+        url.template = operation.path,
+        server.address = Empty,
+        error.type = Empty,
+        latency_ms = Empty,
+        gen_ai.provider.name = gen_ai_operation.map(|_| "openai"),
+        gen_ai.operation.name = gen_ai_operation,
+        gen_ai.request.model = Empty,
+        gen_ai.response.id = Empty,
+        gen_ai.response.model = Empty,
+        gen_ai.response.finish_reasons = Empty,
+        gen_ai.usage.input_tokens = Empty,
+        gen_ai.usage.output_tokens = Empty,
+    );
+    if span.is_disabled() {
+        return span;
+    }
+
+    if let Some(host) = reqwest::Url::parse(&request.url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+    {
+        span.record("server.address", host);
+    }
+    let request_model = match (gen_ai_operation, &request.body) {
+        (Some(_), RequestBody::Json(body)) => serde_json::from_slice::<RequestSummary>(body)
+            .ok()
+            .and_then(|r| r.model),
+        _ => None,
+    };
+    if let Some(model) = &request_model {
+        span.record("gen_ai.request.model", model.as_str());
+    }
+    // The span name the conventions ask for, since tracing only allows static names
+    let name = match (gen_ai_operation, request_model) {
+        (Some(gen_ai_operation), Some(model)) => format!("{gen_ai_operation} {model}"),
+        (Some(gen_ai_operation), None) => gen_ai_operation.to_string(),
+        (None, _) => format!("{} {}", request.method, operation.path),
+    };
+    span.record("otel.name", name);
+    span
+}
+
+fn record_error(span: &Span, error: &ConversaError) {
+    let error_type = match error {
+        ConversaError::Http(e) if e.is_timeout() => "timeout",
+        ConversaError::Http(e) if e.is_connect() => "connect",
+        ConversaError::Transport(e) if e.is_timeout() => "timeout",
+        ConversaError::Transport(e) if e.is_connect() => "connect",
+        ConversaError::Http(_) | ConversaError::Transport(_) => "transport",
+        _ => "client",
+    };
+    span.record("error.type", error_type);
+    span.record("otel.status_code", "ERROR");
+}
+
+fn record_latency(span: &Span, started: Instant) {
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+}
+
+/// The body of a response, which records the latency and the usage reported in it once it is
+/// completely received.
+struct TracedBody {
+    body: BodyStream,
+    span: Span,
+    started: Instant,
+    gen_ai: bool,
+    /// The body received so far, if it is decoded for the usage.
+    received: Option<Vec<u8>>,
+    finished: bool,
+}
+
+impl TracedBody {
+    fn finish(&mut self) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+        record_latency(&self.span, self.started);
+
+        let Some(summary) = self
+            .received
+            .take()
+            .and_then(|r| serde_json::from_slice::<ResponseSummary>(&r).ok())
+        else {
+            return;
+        };
+        if self.gen_ai {
+            if let Some(id) = &summary.id {
+                self.span.record("gen_ai.response.id", id.as_str());
+            }
+            if let Some(model) = &summary.model {
+                self.span.record("gen_ai.response.model", model.as_str());
+            }
+            let finish_reasons: Vec<_> = summary
+                .choices
+                .iter()
+                .filter_map(|c| c.finish_reason.as_deref())
+                .collect();
+            if !finish_reasons.is_empty() {
+                self.span.record(
+                    "gen_ai.response.finish_reasons",
+                    tracing::field::debug(&finish_reasons),
+                );
+            }
+        }
+        if let Some(usage) = summary.usage {
+            if let Some(input_tokens) = usage.input_tokens {
+                self.span.record("gen_ai.usage.input_tokens", input_tokens);
+            }
+            if let Some(output_tokens) = usage.output_tokens {
+                self.span
+                    .record("gen_ai.usage.output_tokens", output_tokens);
+            }
+        }
+    }
+}
+
+impl Stream for TracedBody {
+    type Item = ConversaResult<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let item = ready!(this.body.as_mut().poll_next(cx));
+        match &item {
+            Some(Ok(bytes)) => {
+                if let Some(received) = &mut this.received {
+                    received.extend_from_slice(bytes);
+                }
+            }
+            Some(Err(e)) => {
+                record_error(&this.span, e);
+                this.received = None;
+                this.finish();
+            }
+            None => this.finish(),
+        }
+        Poll::Ready(item)
+    }
+}
+
+impl Drop for TracedBody {
+    /// A body dropped before it was completely received has no usage to record.
+    fn drop(&mut self) {
+        self.received = None;
+        self.finish();
+    }
+}
@@ -0,0 +1,297 @@
+use std::{pin::Pin, time::Duration};
+
+use bytes::Bytes;
+use futures::{Stream, TryStreamExt, future::BoxFuture};
+use reqwest::{
+    Method, StatusCode,
+    header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{ConversaError, ConversaResult, multipart::Form};
+
+// The operations describe their requests independently of the HTTP library and hand them to a
+// `Transport` to be sent. The client uses reqwest unless another transport is configured, for
+// example an in-memory fake in tests or an existing HTTP stack with its own instrumentation.
+
+/// The body of a response, received chunk by chunk.
+pub type BodyStream = Pin<Box<dyn Stream<Item = ConversaResult<Bytes>> + Send>>;
+
+/// Sends the requests of the client.
+pub trait Transport: Send + Sync {
+    /// Sends the request and returns the response as soon as its headers are received. Error
+    /// status codes are returned as responses, only failing to exchange the request and the
+    /// response is an error.
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, ConversaResult<HttpResponse>>;
+}
+
+/// The body of a request.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum RequestBody {
+    #[default]
+    Empty,
+    /// A JSON document, the `content-type` header is already set.
+    Json(Bytes),
+    /// A `multipart/form-data` form. The transport sets the `content-type` header with the
+    /// boundary it chooses.
+    Multipart(Form),
+}
+
+/// A request of an operation, ready to be sent by a [`Transport`].
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    /// The address of the endpoint without the query.
+    pub url: String,
+    /// The query parameters, which are not yet encoded.
+    pub query: Vec<(String, String)>,
+    pub headers: HeaderMap,
+    pub body: RequestBody,
+    /// Time allowed until the whole response is received.
+    pub timeout: Option<Duration>,
+    /// The operation the request belongs to.
+    pub operation: Operation,
+}
+
+/// Identifies an operation of the API.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Operation {
+    /// The `operationId` in the OpenAPI specification, e.g. `createChatCompletion`.
+    pub id: &'static str,
+    /// The name of the method of the client, e.g. `create_chat_completion`.
+    pub name: &'static str,
+    /// The path in the OpenAPI specification with the parameters not filled in, e.g.
+    /// `/threads/{thread_id}/runs`.
+    pub path: &'static str,
+}
+
+impl HttpRequest {
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            query: Vec::new(),
+            headers: HeaderMap::new(),
+            body: RequestBody::Empty,
+            timeout: None,
+            operation: Operation::default(),
+        }
+    }
+
+    /// Sets the `authorization` header.
+    pub(crate) fn bearer_auth(self, token: &str) -> ConversaResult<Self> {
+        self.secret_header(AUTHORIZATION, &format!("Bearer {token}"))
+    }
+
+    /// Sets a header holding a credential, which is left out of debug output.
+    pub(crate) fn secret_header(mut self, name: HeaderName, value: &str) -> ConversaResult<Self> {
+        let mut value = HeaderValue::from_str(value)
+            .map_err(|_| ConversaError::ClientError("Invalid API key".to_string()))?;
+        value.set_sensitive(true);
+        self.headers.insert(name, value);
+        Ok(self)
+    }
+
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Adds query parameters. Strings are sent as they are and any other value as JSON.
+    pub fn query<T: Serialize>(mut self, parameters: &[(&str, T)]) -> ConversaResult<Self> {
+        for (name, value) in parameters {
+            self.query.push((name.to_string(), text_value(value)?));
+        }
+        Ok(self)
+    }
+
+    pub fn json<T: Serialize + ?Sized>(mut self, body: &T) -> ConversaResult<Self> {
+        self.body = RequestBody::Json(serde_json::to_vec(body)?.into());
+        self.headers
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        Ok(self)
+    }
+
+    pub fn multipart(mut self, form: Form) -> Self {
+        self.body = RequestBody::Multipart(form);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The `model` of the body, if it has one.
+    pub(crate) fn model(&self) -> Option<String> {
+        #[derive(Deserialize)]
+        struct ModelField {
+            model: Option<String>,
+        }
+
+        match &self.body {
+            RequestBody::Empty => None,
+            RequestBody::Json(body) => serde_json::from_slice::<ModelField>(body).ok()?.model,
+            RequestBody::Multipart(form) => {
+                let (_, model) = form.parts().iter().find(|(name, _)| name == "model")?;
+                String::from_utf8(model.data.to_vec()).ok()
+            }
+        }
+    }
+}
+
+/// Text of a query parameter or form field. Strings are sent without quotes and any other
+/// value as JSON.
+pub(crate) fn text_value<T: Serialize + ?Sized>(value: &T) -> ConversaResult<String> {
+    match serde_json::to_value(value)? {
+        serde_json::Value::String(s) => Ok(s),
+        v => Ok(v.to_string()),
+    }
+}
+
+/// A response whose body has not been received yet.
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: BodyStream,
+}
+
+impl HttpResponse {
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Receives the whole body.
+    pub async fn bytes(self) -> ConversaResult<Bytes> {
+        let chunks: Vec<Bytes> = self.body.try_collect().await?;
+        Ok(chunks.concat().into())
+    }
+
+    pub fn bytes_stream(self) -> BodyStream {
+        self.body
+    }
+}
+
+impl std::fmt::Debug for HttpResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HttpResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+/// What went wrong in a custom [`Transport`], which decides whether the request is retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportErrorKind {
+    /// No connection could be established, so the request never reached the server.
+    Connect,
+    /// The request or the response took too long.
+    Timeout,
+    Other,
+}
+
+/// An error of a custom [`Transport`]. The reqwest transport reports
+/// [`ConversaError::Http`] instead.
+#[derive(Debug)]
+pub struct TransportError {
+    kind: TransportErrorKind,
+    source: Box<dyn std::error::Error + Send + Sync>,
+}
+
+impl TransportError {
+    pub fn new(
+        kind: TransportErrorKind,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        Self {
+            kind,
+            source: source.into(),
+        }
+    }
+
+    pub fn kind(&self) -> TransportErrorKind {
+        self.kind
+    }
+
+    pub fn is_connect(&self) -> bool {
+        self.kind == TransportErrorKind::Connect
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        self.kind == TransportErrorKind::Timeout
+    }
+}
+
+impl std::fmt::Display for TransportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.source.fmt(f)
+    }
+}
+
+impl std::error::Error for TransportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+/// The default [`Transport`], which sends the requests with a [`reqwest::Client`].
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+
+    fn build_request(&self, request: HttpRequest) -> ConversaResult<reqwest::RequestBuilder> {
+        let mut builder = self
+            .client
+            .request(request.method, &request.url)
+            .headers(request.headers);
+        if !request.query.is_empty() {
+            builder = builder.query(&request.query);
+        }
+        builder = match request.body {
+            RequestBody::Empty => builder,
+            RequestBody::Json(body) => builder.body(body),
+            RequestBody::Multipart(form) => {
+                let mut multipart_form = reqwest::multipart::Form::new();
+                for (name, part) in form.into_parts() {
+                    let mut multipart_part = reqwest::multipart::Part::bytes(part.data.to_vec());
+                    if let Some(content_type) = &part.content_type {
+                        multipart_part = multipart_part.mime_str(content_type)?;
+                    }
+                    if let Some(file_name) = part.file_name {
+                        multipart_part = multipart_part.file_name(file_name);
+                    }
+                    multipart_form = multipart_form.part(name, multipart_part);
+                }
+                builder.multipart(multipart_form)
+            }
+        };
+        if let Some(timeout) = request.timeout {
+            builder = builder.timeout(timeout);
+        }
+        Ok(builder)
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, ConversaResult<HttpResponse>> {
+        Box::pin(async move {
+            let response = self.build_request(request)?.send().await?;
+            Ok(HttpResponse {
+                status: response.status(),
+                headers: response.headers().clone(),
+                body: Box::pin(response.bytes_stream().map_err(ConversaError::from)),
+            })
+        })
+    }
+}
@@ -0,0 +1,176 @@
+use futures::{StreamExt, TryStreamExt};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::{
+    ConversaError, ConversaResult, OpenAIClient,
+    multipart::File,
+    types::{
+        AddUploadPartRequest, CompleteUploadRequest, CreateUploadRequest,
+        CreateUploadRequestPurpose, OpenAIFile,
+    },
+};
+
+// Files larger than what a single request accepts have to be sent through the Uploads API,
+// which takes the file in parts and assembles it on completion. The helpers here drive the
+// whole sequence of requests.
+
+/// The largest part accepted by the Uploads API.
+pub const MAX_PART_SIZE: usize = 64 * 1024 * 1024;
+
+/// Settings of a chunked upload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadOptions {
+    /// Size of each part in bytes, at most [`MAX_PART_SIZE`].
+    pub part_size: usize,
+    /// Number of parts that are uploaded at the same time.
+    pub concurrency: usize,
+    /// Optional MD5 checksum of the whole file which the server verifies on completion.
+    pub md5: Option<String>,
+}
+
+impl Default for UploadOptions {
+    fn default() -> Self {
+        Self {
+            part_size: MAX_PART_SIZE,
+            concurrency: 4,
+            md5: None,
+        }
+    }
+}
+
+impl OpenAIClient {
+    /// Uploads the file at `path` in parts and returns the resulting file object. The MIME type
+    /// is derived from the file extension.
+    pub async fn upload_file(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        purpose: CreateUploadRequestPurpose,
+        options: UploadOptions,
+    ) -> ConversaResult<OpenAIFile> {
+        let path = path.as_ref();
+        let filename = path
+            .file_name()
+            .and_then(|f| f.to_str())
+            .ok_or(ConversaError::IoError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Path does not have a valid file name",
+            )))?
+            .to_string();
+        let mime_type = File {
+            file_name: filename.clone(),
+            file_data: Vec::new(),
+        }
+        .mime_type()
+        .to_string();
+        let file = tokio::fs::File::open(path).await?;
+        let bytes = file.metadata().await?.len();
+
+        self.upload_reader(file, filename, bytes, mime_type, purpose, options)
+            .await
+    }
+
+    /// Uploads `bytes` bytes read from `reader` in parts and returns the resulting file object.
+    /// If any part fails the upload is cancelled.
+    pub async fn upload_reader<R>(
+        &self,
+        reader: R,
+        filename: String,
+        bytes: u64,
+        mime_type: String,
+        purpose: CreateUploadRequestPurpose,
+        options: UploadOptions,
+    ) -> ConversaResult<OpenAIFile>
+    where
+        R: AsyncRead + Unpin,
+    {
+        if options.part_size == 0 || options.part_size > MAX_PART_SIZE {
+            return Err(ConversaError::ClientError(format!(
+                "Part size must be between 1 and {MAX_PART_SIZE} bytes"
+            )));
+        }
+        if options.concurrency == 0 {
+            return Err(ConversaError::ClientError(
+                "Concurrency must be at least 1".to_string(),
+            ));
+        }
+
+        let upload = self
+            .create_upload(CreateUploadRequest {
+                filename: filename.clone(),
+                purpose,
+                bytes,
+                mime_type,
+            })
+            .await?;
+
+        let part_ids = match self
+            .upload_parts(&upload.id, reader, &filename, bytes, &options)
+            .await
+        {
+            Ok(part_ids) => part_ids,
+            Err(e) => {
+                // The original error is more useful than a failure to cancel
+                let _ = self.cancel_upload(&upload.id).await;
+                return Err(e);
+            }
+        };
+
+        let upload = self
+            .complete_upload(
+                &upload.id,
+                CompleteUploadRequest {
+                    part_ids,
+                    md5: options.md5,
+                },
+            )
+            .await?;
+        upload.file.map(|f| f.open_a_i_file).ok_or_else(|| {
+            ConversaError::InvalidData(format!("Upload {} completed without a file", upload.id))
+        })
+    }
+
+    async fn upload_parts<R>(
+        &self,
+        upload_id: &str,
+        reader: R,
+        filename: &str,
+        bytes: u64,
+        options: &UploadOptions,
+    ) -> ConversaResult<Vec<String>>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let part_size = options.part_size as u64;
+        let chunks =
+            futures::stream::try_unfold((reader, 0), move |(mut reader, read)| async move {
+                if read == bytes {
+                    return Ok(None);
+                }
+                let length = part_size.min(bytes - read);
+                let mut data = Vec::with_capacity(length as usize);
+                (&mut reader).take(length).read_to_end(&mut data).await?;
+                if data.len() as u64 != length {
+                    return Err(ConversaError::InvalidData(format!(
+                        "Expected {bytes} bytes but the reader ended after {}",
+                        read + data.len() as u64
+                    )));
+                }
+                Ok(Some((data, (reader, read + length))))
+            });
+
+        chunks
+            .enumerate()
+            .map(|(index, data)| async move {
+                let request_body = AddUploadPartRequest {
+                    data: File {
+                        file_name: format!("{filename}.part{index}"),
+                        file_data: data?,
+                    },
+                };
+                Ok(self.add_upload_part(upload_id, request_body).await?.id)
+            })
+            .buffered(options.concurrency)
+            .try_collect()
+            .await
+    }
+}
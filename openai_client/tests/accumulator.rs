@@ -0,0 +1,109 @@
+use conversa_openai_client::{
+    accumulator::{ChatCompletionAccumulator, accumulate},
+    types::{CreateChatCompletionResponse, CreateChatCompletionStreamResponse},
+};
+
+fn chunk(json: &str) -> CreateChatCompletionStreamResponse {
+    serde_json::from_str(json).unwrap()
+}
+
+fn content_chunks() -> Vec<CreateChatCompletionStreamResponse> {
+    vec![
+        chunk(
+            r#"{"id":"chatcmpl-1","object":"chat.completion.chunk","created":1752653808,"model":"gpt-4.1","system_fingerprint":"fp_1","choices":[{"index":0,"delta":{"role":"assistant","content":""},"logprobs":null,"finish_reason":null}]}"#,
+        ),
+        chunk(
+            r#"{"id":"chatcmpl-1","object":"chat.completion.chunk","created":1752653808,"model":"gpt-4.1","system_fingerprint":"fp_1","choices":[{"index":0,"delta":{"content":"The capital of France"},"logprobs":null,"finish_reason":null}]}"#,
+        ),
+        chunk(
+            r#"{"id":"chatcmpl-1","object":"chat.completion.chunk","created":1752653808,"model":"gpt-4.1","system_fingerprint":"fp_1","choices":[{"index":0,"delta":{"content":" is Paris."},"logprobs":null,"finish_reason":null}]}"#,
+        ),
+        chunk(
+            r#"{"id":"chatcmpl-1","object":"chat.completion.chunk","created":1752653808,"model":"gpt-4.1","system_fingerprint":"fp_1","choices":[{"index":0,"delta":{},"logprobs":null,"finish_reason":"stop"}]}"#,
+        ),
+        chunk(
+            r#"{"id":"chatcmpl-1","object":"chat.completion.chunk","created":1752653808,"model":"gpt-4.1","system_fingerprint":"fp_1","choices":[],"usage":{"prompt_tokens":14,"completion_tokens":8,"total_tokens":22}}"#,
+        ),
+    ]
+}
+
+#[tokio::test]
+async fn accumulate_content_chunks() {
+    let expected: CreateChatCompletionResponse = serde_json::from_str(
+        r#"{
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 1752653808,
+            "model": "gpt-4.1",
+            "system_fingerprint": "fp_1",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "The capital of France is Paris."},
+                "finish_reason": "stop"
+            }],
+            "usage": {"prompt_tokens": 14, "completion_tokens": 8, "total_tokens": 22}
+        }"#,
+    )
+    .unwrap();
+
+    let response = accumulate(futures::stream::iter(content_chunks().into_iter().map(Ok)))
+        .await
+        .unwrap();
+
+    assert_eq!(response, expected);
+}
+
+#[test]
+fn accumulate_tool_call_fragments() {
+    let mut accumulator = ChatCompletionAccumulator::new();
+    accumulator.push(chunk(
+        r#"{"id":"chatcmpl-2","object":"chat.completion.chunk","created":1752653808,"model":"gpt-4.1","choices":[{"index":0,"delta":{"role":"assistant","tool_calls":[{"index":0,"id":"call_1","type":"function","function":{"name":"get_weather","arguments":""}},{"index":1,"id":"call_2","type":"function","function":{"name":"get_time","arguments":""}}]},"finish_reason":null}]}"#,
+    ));
+    accumulator.push(chunk(
+        r#"{"id":"chatcmpl-2","object":"chat.completion.chunk","created":1752653808,"model":"gpt-4.1","choices":[{"index":0,"delta":{"tool_calls":[{"index":1,"function":{"arguments":"{\"city\":"}},{"index":0,"function":{"arguments":"{\"city\":\"Paris\"}"}}]},"finish_reason":null}]}"#,
+    ));
+
+    let partial_tool_calls = &accumulator.choices()[0].tool_calls;
+    assert_eq!(partial_tool_calls[0].arguments, r#"{"city":"Paris"}"#);
+    assert_eq!(partial_tool_calls[1].arguments, r#"{"city":"#);
+
+    accumulator.push(chunk(
+        r#"{"id":"chatcmpl-2","object":"chat.completion.chunk","created":1752653808,"model":"gpt-4.1","choices":[{"index":0,"delta":{"tool_calls":[{"index":1,"function":{"arguments":"\"Lisbon\"}"}}]},"finish_reason":"tool_calls"}]}"#,
+    ));
+
+    let expected: CreateChatCompletionResponse = serde_json::from_str(
+        r#"{
+            "id": "chatcmpl-2",
+            "object": "chat.completion",
+            "created": 1752653808,
+            "model": "gpt-4.1",
+            "choices": [{
+                "index": 0,
+                "message": {
+                    "role": "assistant",
+                    "tool_calls": [
+                        {"id": "call_1", "type": "function", "function": {"name": "get_weather", "arguments": "{\"city\":\"Paris\"}"}},
+                        {"id": "call_2", "type": "function", "function": {"name": "get_time", "arguments": "{\"city\":\"Lisbon\"}"}}
+                    ]
+                },
+                "finish_reason": "tool_calls"
+            }]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(accumulator.finish().unwrap(), expected);
+}
+
+#[test]
+fn accumulate_interrupted_stream_fails() {
+    let mut accumulator = ChatCompletionAccumulator::new();
+    for chunk in content_chunks().into_iter().take(2) {
+        accumulator.push(chunk);
+    }
+
+    assert_eq!(
+        accumulator.choices()[0].content.as_deref(),
+        Some("The capital of France")
+    );
+    assert!(accumulator.finish().is_err());
+}
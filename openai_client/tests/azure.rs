@@ -0,0 +1,112 @@
+mod common;
+
+use common::{MODEL_LIST, MockResponse, MockServer, embedding_request};
+use conversa_openai_client::{
+    OpenAIClient,
+    azure::{AzureAuth, AzureConfig},
+    multipart::File,
+    types::{CreateTranscriptionRequest, CreateTranscriptionRequestModel},
+};
+
+const CHAT_COMPLETION: &str = r#"{"id":"chatcmpl-1","object":"chat.completion","created":1752653808,"model":"gpt-4o","choices":[]}"#;
+
+fn azure_client(server: &MockServer, azure: AzureConfig) -> OpenAIClient {
+    server.builder().azure(azure).build().unwrap()
+}
+
+#[tokio::test]
+async fn model_operations_are_sent_to_the_deployment() {
+    let server = MockServer::with_responses(vec![MockResponse::json(200, CHAT_COMPLETION)]).await;
+    let client = azure_client(&server, AzureConfig::new("2024-10-21"));
+
+    client
+        .create_chat_completion(
+            serde_json::from_str(
+                r#"{"model":"gpt-4o","messages":[{"role":"user","content":"Capital of France?"}]}"#,
+            )
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(
+        request.path,
+        "/openai/deployments/gpt-4o/chat/completions?api-version=2024-10-21"
+    );
+    assert_eq!(request.header("api-key"), Some("test-key"));
+    assert_eq!(request.header("authorization"), None);
+}
+
+#[tokio::test]
+async fn models_are_mapped_to_their_deployments() {
+    let server = MockServer::with_responses(vec![MockResponse::json(
+        200,
+        r#"{"object":"list","data":[],"model":"text-embedding-3-small","usage":{"prompt_tokens":5,"total_tokens":5}}"#,
+    )])
+    .await;
+    let client = azure_client(
+        &server,
+        AzureConfig::new("2024-10-21").with_deployment("text-embedding-3-small", "embeddings-eu"),
+    );
+
+    client
+        .create_embedding(embedding_request("text-embedding-3-small"))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/openai/deployments/embeddings-eu/embeddings?api-version=2024-10-21"
+    );
+}
+
+#[tokio::test]
+async fn other_operations_are_sent_to_the_resource() {
+    let server = MockServer::with_responses(vec![MockResponse::json(200, MODEL_LIST)]).await;
+    let client = azure_client(
+        &server,
+        AzureConfig::new("2024-10-21").with_auth(AzureAuth::EntraId),
+    );
+
+    client.list_models().await.unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/openai/models?api-version=2024-10-21");
+    assert_eq!(request.header("authorization"), Some("Bearer test-key"));
+    assert_eq!(request.header("api-key"), None);
+}
+
+#[tokio::test]
+async fn form_operations_are_sent_to_the_deployment() {
+    let server =
+        MockServer::with_responses(vec![MockResponse::json(200, r#"{"text":"Hola"}"#)]).await;
+    let client = azure_client(
+        &server,
+        AzureConfig::new("2024-10-21").with_deployment("whisper-1", "whisper"),
+    );
+
+    client
+        .create_transcription(CreateTranscriptionRequest {
+            file: File {
+                file_name: "audio.mp3".to_string(),
+                file_data: vec![0xff, 0xfb, 0x90, 0x00],
+            },
+            model: CreateTranscriptionRequestModel::String("whisper-1".to_string()),
+            language: None,
+            prompt: None,
+            response_format: None,
+            temperature: None,
+            include: None,
+            timestamp_granularities: None,
+            stream: None,
+            chunking_strategy: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/openai/deployments/whisper/audio/transcriptions?api-version=2024-10-21"
+    );
+}
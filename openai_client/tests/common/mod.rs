@@ -0,0 +1,356 @@
+#![allow(dead_code)]
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use conversa_openai_client::{OpenAIClient, OpenAIClientBuilder};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+/// An empty list of models, the simplest successful response.
+pub const MODEL_LIST: &str = r#"{"object":"list","data":[]}"#;
+
+/// A request for the embedding of a single word with `model`.
+pub fn embedding_request(model: &str) -> conversa_openai_client::types::CreateEmbeddingRequest {
+    use conversa_openai_client::types::{
+        CreateEmbeddingRequest, CreateEmbeddingRequestInput, CreateEmbeddingRequestModel,
+    };
+
+    CreateEmbeddingRequest {
+        input: CreateEmbeddingRequestInput::String("Paris".to_string()),
+        model: CreateEmbeddingRequestModel::String(model.to_string()),
+        encoding_format: None,
+        dimensions: None,
+        user: None,
+    }
+}
+
+/// A request for a short speech, as sent by the audio tests.
+#[cfg(feature = "audio")]
+pub fn speech_request() -> conversa_openai_client::types::CreateSpeechRequest {
+    use conversa_openai_client::types::{
+        CreateSpeechRequest, CreateSpeechRequestModel, VoiceIdsShared,
+    };
+
+    CreateSpeechRequest {
+        model: CreateSpeechRequestModel::String("tts-1".to_string()),
+        input: "Paris".to_string(),
+        instructions: None,
+        voice: VoiceIdsShared::String("alloy".to_string()),
+        response_format: None,
+        speed: None,
+        stream_format: None,
+    }
+}
+
+/// A request as it was received by the [`MockServer`].
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).unwrap()
+    }
+
+    pub fn body_string(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Splits a `multipart/form-data` body into its parts.
+    pub fn form_parts(&self) -> Vec<FormPart> {
+        let content_type = self.header("content-type").unwrap();
+        let boundary = format!("--{}", content_type.split_once("boundary=").unwrap().1);
+        let mut parts = Vec::new();
+        let mut rest = &self.body[..];
+        while let Some(start) = find(rest, boundary.as_bytes()) {
+            rest = &rest[start + boundary.len()..];
+            if rest.starts_with(b"--") {
+                break;
+            }
+            let head_end = find(rest, b"\r\n\r\n").unwrap();
+            let head = String::from_utf8_lossy(&rest[2..head_end]).into_owned();
+            let data_end = find(rest, boundary.as_bytes()).unwrap() - 2;
+            let mut part = FormPart {
+                name: String::new(),
+                file_name: None,
+                content_type: None,
+                data: rest[head_end + 4..data_end].to_vec(),
+            };
+            for line in head.split("\r\n") {
+                let (name, value) = line.split_once(':').unwrap();
+                if name.eq_ignore_ascii_case("content-type") {
+                    part.content_type = Some(value.trim().to_string());
+                } else if name.eq_ignore_ascii_case("content-disposition") {
+                    for parameter in value.split(';').skip(1) {
+                        let (key, value) = parameter.trim().split_once('=').unwrap();
+                        let value = value.trim_matches('"').to_string();
+                        match key {
+                            "name" => part.name = value,
+                            "filename" => part.file_name = Some(value),
+                            _ => (),
+                        }
+                    }
+                }
+            }
+            parts.push(part);
+        }
+        parts
+    }
+
+    /// The text of the form fields called `name`, in order.
+    pub fn form_values(&self, name: &str) -> Vec<String> {
+        self.form_parts()
+            .into_iter()
+            .filter(|p| p.name == name)
+            .map(|p| String::from_utf8(p.data).unwrap())
+            .collect()
+    }
+}
+
+/// A part of a `multipart/form-data` body.
+#[derive(Debug, Clone)]
+pub struct FormPart {
+    pub name: String,
+    pub file_name: Option<String>,
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+fn find(data: &[u8], pattern: &[u8]) -> Option<usize> {
+    data.windows(pattern.len()).position(|w| w == pattern)
+}
+
+/// A canned response. The body is written chunk by chunk with a short pause in between
+/// so that the client receives the chunks separately.
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<Vec<u8>>,
+    /// Time waited before the response is written.
+    pub delay: Duration,
+    pub chunk_delay: Duration,
+}
+
+impl MockResponse {
+    pub fn new(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: Vec::new(),
+            delay: Duration::ZERO,
+            chunk_delay: Duration::from_millis(5),
+        }
+    }
+
+    pub fn json(status: u16, body: &str) -> Self {
+        Self::new(status)
+            .header("content-type", "application/json")
+            .body(body.as_bytes())
+    }
+
+    pub fn event_stream(chunks: &[&str]) -> Self {
+        let mut response = Self::new(200).header("content-type", "text/event-stream");
+        response.body = chunks.iter().map(|c| c.as_bytes().to_vec()).collect();
+        response
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn body(mut self, body: &[u8]) -> Self {
+        self.body = vec![body.to_vec()];
+        self
+    }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn chunk_delay(mut self, chunk_delay: Duration) -> Self {
+        self.chunk_delay = chunk_delay;
+        self
+    }
+}
+
+/// Minimal HTTP/1.1 server standing in for the OpenAI API.
+pub struct MockServer {
+    pub address: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockServer {
+    /// Starts a server answering every request with the result of `handler`.
+    pub async fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&RecordedRequest) -> MockResponse + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let handler = Arc::new(handler);
+
+        let recorded_requests = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                let handler = handler.clone();
+                let recorded_requests = recorded_requests.clone();
+                tokio::spawn(async move {
+                    handle_connection(socket, handler.as_ref(), &recorded_requests).await;
+                });
+            }
+        });
+
+        Self { address, requests }
+    }
+
+    /// Starts a server answering the requests with `responses` in order.
+    pub async fn with_responses(responses: Vec<MockResponse>) -> Self {
+        let responses = Mutex::new(VecDeque::from(responses));
+        Self::start(move |_| {
+            responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("No more responses configured")
+        })
+        .await
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn builder(&self) -> OpenAIClientBuilder {
+        OpenAIClientBuilder::new(self.address.clone(), "test-key".to_string())
+    }
+
+    pub fn client(&self) -> OpenAIClient {
+        self.builder().build().unwrap()
+    }
+}
+
+async fn handle_connection(
+    mut socket: TcpStream,
+    handler: &(dyn Fn(&RecordedRequest) -> MockResponse + Send + Sync),
+    requests: &Mutex<Vec<RecordedRequest>>,
+) {
+    let Some(request) = read_request(&mut socket).await else {
+        return;
+    };
+    requests.lock().unwrap().push(request.clone());
+    let response = handler(&request);
+    tokio::time::sleep(response.delay).await;
+
+    let mut head = format!("HTTP/1.1 {} Mock\r\nconnection: close\r\n", response.status);
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str("\r\n");
+    if socket.write_all(head.as_bytes()).await.is_err() {
+        return;
+    }
+    for chunk in &response.body {
+        if socket.write_all(chunk).await.is_err() || socket.flush().await.is_err() {
+            return;
+        }
+        tokio::time::sleep(response.chunk_delay).await;
+    }
+    let _ = socket.shutdown().await;
+}
+
+async fn read_request(socket: &mut TcpStream) -> Option<RecordedRequest> {
+    let mut data = Vec::new();
+    let head_end = loop {
+        if let Some(i) = data.windows(4).position(|w| w == b"\r\n\r\n") {
+            break i;
+        }
+        read_more(socket, &mut data).await?;
+    };
+
+    let head = String::from_utf8_lossy(&data[..head_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split(' ');
+    let method = request_line.next()?.to_string();
+    let path = request_line.next()?.to_string();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|l| l.split_once(':'))
+        .map(|(n, v)| (n.trim().to_lowercase(), v.trim().to_string()))
+        .collect();
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.clone())
+    };
+
+    let mut rest = data[head_end + 4..].to_vec();
+    let body = if let Some(length) = header("content-length") {
+        let length: usize = length.parse().ok()?;
+        while rest.len() < length {
+            read_more(socket, &mut rest).await?;
+        }
+        rest.truncate(length);
+        rest
+    } else if header("transfer-encoding").is_some_and(|t| t.contains("chunked")) {
+        let mut body = Vec::new();
+        loop {
+            let line_end = loop {
+                if let Some(i) = rest.windows(2).position(|w| w == b"\r\n") {
+                    break i;
+                }
+                read_more(socket, &mut rest).await?;
+            };
+            let size_str = String::from_utf8_lossy(&rest[..line_end]).into_owned();
+            let size = usize::from_str_radix(size_str.split(';').next()?.trim(), 16).ok()?;
+            while rest.len() < line_end + 2 + size + 2 {
+                read_more(socket, &mut rest).await?;
+            }
+            body.extend_from_slice(&rest[line_end + 2..line_end + 2 + size]);
+            rest.drain(..line_end + 2 + size + 2);
+            if size == 0 {
+                break body;
+            }
+        }
+    } else {
+        Vec::new()
+    };
+
+    Some(RecordedRequest {
+        method,
+        path,
+        headers,
+        body,
+    })
+}
+
+async fn read_more(socket: &mut TcpStream, data: &mut Vec<u8>) -> Option<()> {
+    let mut buffer = [0; 8192];
+    let n = socket.read(&mut buffer).await.ok()?;
+    if n == 0 {
+        return None;
+    }
+    data.extend_from_slice(&buffer[..n]);
+    Some(())
+}
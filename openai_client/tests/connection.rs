@@ -0,0 +1,165 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{MODEL_LIST, MockResponse, MockServer, speech_request};
+use conversa_openai_client::{ConversaError, OpenAIClientBuilder};
+use futures::StreamExt;
+
+fn audio_chunks(count: usize) -> MockResponse {
+    let mut response = MockResponse::new(200).header("content-type", "audio/mpeg");
+    response.body = vec![vec![0; 16]; count];
+    response
+}
+
+#[tokio::test]
+async fn request_timeout_aborts_slow_responses() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::json(200, MODEL_LIST).delay(Duration::from_millis(500)),
+    ])
+    .await;
+    let client = server
+        .builder()
+        .request_timeout(Duration::from_millis(50))
+        .build()
+        .unwrap();
+
+    let error = client.list_models().await.unwrap_err();
+
+    match &error {
+        ConversaError::Http(e) => assert!(e.is_timeout()),
+        e => panic!("Unexpected error {e:?}"),
+    }
+    assert!(error.is_retryable());
+}
+
+#[tokio::test]
+async fn request_timeout_does_not_limit_streams() {
+    let server =
+        MockServer::with_responses(vec![audio_chunks(4).chunk_delay(Duration::from_millis(40))])
+            .await;
+    let client = server
+        .builder()
+        .request_timeout(Duration::from_millis(50))
+        .build()
+        .unwrap();
+
+    let chunks: Vec<_> = client
+        .create_speech_audio_stream(speech_request())
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    assert!(chunks.iter().all(|c| c.is_ok()));
+    assert_eq!(
+        chunks
+            .iter()
+            .map(|c| c.as_ref().unwrap().len())
+            .sum::<usize>(),
+        64
+    );
+}
+
+#[tokio::test]
+async fn read_timeout_aborts_stalled_streams() {
+    let server = MockServer::with_responses(vec![
+        audio_chunks(2).chunk_delay(Duration::from_millis(500)),
+    ])
+    .await;
+    let client = server
+        .builder()
+        .read_timeout(Duration::from_millis(50))
+        .build()
+        .unwrap();
+
+    let chunks: Vec<_> = client
+        .create_speech_audio_stream(speech_request())
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    match chunks.last() {
+        Some(Err(ConversaError::Http(e))) => assert!(e.is_timeout()),
+        c => panic!("Unexpected chunk {c:?}"),
+    }
+}
+
+#[tokio::test]
+async fn read_timeout_ends_event_streams_pausing_longer() {
+    let chunk = r#"data: {"id":"chatcmpl-1","object":"chat.completion.chunk","created":1752653808,"model":"gpt-4.1","choices":[{"index":0,"delta":{"content":"Paris"},"finish_reason":null}]}"#;
+    let server = MockServer::with_responses(vec![
+        MockResponse::event_stream(&[&format!("{chunk}\n\n"), "data: [DONE]\n\n"])
+            .chunk_delay(Duration::from_millis(500)),
+    ])
+    .await;
+    let client = server
+        .builder()
+        .read_timeout(Duration::from_millis(50))
+        .build()
+        .unwrap();
+
+    let events: Vec<_> = client
+        .create_chat_completion_stream(
+            serde_json::from_str(
+                r#"{"model":"gpt-4.1","messages":[{"role":"user","content":"Capital of France?"}]}"#,
+            )
+            .unwrap(),
+        )
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    match events.last() {
+        Some(Err(ConversaError::Http(e))) => assert!(e.is_timeout()),
+        e => panic!("Unexpected event {e:?}"),
+    }
+}
+
+#[tokio::test]
+async fn requests_are_sent_through_the_proxy() {
+    let proxy = MockServer::with_responses(vec![MockResponse::json(200, MODEL_LIST)]).await;
+    let client = OpenAIClientBuilder::new(
+        "http://api.example.invalid/v1".to_string(),
+        "test-key".to_string(),
+    )
+    .proxy(reqwest::Proxy::http(&proxy.address).unwrap())
+    .build()
+    .unwrap();
+
+    client.list_models().await.unwrap();
+
+    assert_eq!(
+        proxy.requests()[0].path,
+        "http://api.example.invalid/v1/models"
+    );
+}
+
+#[tokio::test]
+async fn preconfigured_http_client_is_used() {
+    let server = MockServer::with_responses(vec![MockResponse::json(200, MODEL_LIST)]).await;
+    let http_client = reqwest::Client::builder()
+        .user_agent("gateway-client/1.0")
+        .build()
+        .unwrap();
+    let client = server.builder().http_client(http_client).build().unwrap();
+
+    client.list_models().await.unwrap();
+
+    assert_eq!(
+        server.requests()[0].header("user-agent"),
+        Some("gateway-client/1.0")
+    );
+}
+
+#[tokio::test]
+async fn http_client_can_not_be_combined_with_connection_options() {
+    let result = OpenAIClientBuilder::new("http://localhost".to_string(), "test-key".to_string())
+        .http_client(reqwest::Client::new())
+        .connect_timeout(Duration::from_secs(1))
+        .build();
+
+    assert!(matches!(result, Err(ConversaError::ClientError(_))));
+}
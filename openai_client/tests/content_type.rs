@@ -0,0 +1,62 @@
+mod common;
+
+use common::{MODEL_LIST, MockResponse, MockServer, speech_request};
+use conversa_openai_client::client::CreateSpeechResponse;
+
+#[tokio::test]
+async fn content_type_parameters_are_ignored() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::new(200)
+            .header("content-type", "Application/JSON; charset=utf-8")
+            .body(MODEL_LIST.as_bytes()),
+    ])
+    .await;
+
+    let response = server.client().list_models_with_meta().await.unwrap();
+
+    assert!(response.data.data.is_empty());
+    assert_eq!(
+        response.meta.content_type.as_deref(),
+        Some("application/json")
+    );
+}
+
+#[tokio::test]
+async fn missing_content_type_does_not_fail() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::new(200).body(MODEL_LIST.as_bytes()),
+        MockResponse::new(200),
+    ])
+    .await;
+    let client = server.client();
+
+    let response = client.list_models_with_meta().await.unwrap();
+    assert!(response.data.data.is_empty());
+    assert_eq!(response.meta.content_type, None);
+
+    client.delete_response("resp_1").await.unwrap();
+}
+
+#[tokio::test]
+async fn unknown_content_type_falls_back_to_the_declared_one() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::new(200)
+            .header("content-type", "audio/mpeg")
+            .body(&[1, 2, 3]),
+        MockResponse::new(200).body(&[4, 5]),
+    ])
+    .await;
+    let client = server.client();
+
+    let speech = client.create_speech(speech_request()).await.unwrap();
+    assert_eq!(
+        speech,
+        CreateSpeechResponse::ApplicationOctetStream(vec![1, 2, 3])
+    );
+
+    let speech = client.create_speech(speech_request()).await.unwrap();
+    assert_eq!(
+        speech,
+        CreateSpeechResponse::ApplicationOctetStream(vec![4, 5])
+    );
+}
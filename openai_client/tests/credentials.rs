@@ -0,0 +1,171 @@
+mod common;
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, SystemTime},
+};
+
+use common::{MODEL_LIST, MockResponse, MockServer, RecordedRequest};
+use conversa_openai_client::{
+    ConversaResult,
+    credentials::{Cached, CredentialProvider, FileKey, KeyPool},
+    retry::RetryPolicy,
+};
+use futures::future::{BoxFuture, join_all};
+
+/// Rejects the requests made with `key-a` as rate limited.
+fn limited_first_key(request: &RecordedRequest) -> MockResponse {
+    match request.header("authorization") {
+        Some("Bearer key-a") => MockResponse::json(
+            429,
+            r#"{"error":{"message":"Rate limit reached","type":"requests","param":null,"code":"rate_limit_exceeded"}}"#,
+        ),
+        _ => MockResponse::json(200, MODEL_LIST),
+    }
+}
+
+fn authorizations(server: &MockServer) -> Vec<String> {
+    server
+        .requests()
+        .iter()
+        .map(|r| r.header("authorization").unwrap_or_default().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn key_pool_skips_rate_limited_keys() {
+    let server = MockServer::start(limited_first_key).await;
+    let client = server
+        .builder()
+        .credential_provider(KeyPool::new(["key-a", "key-b", "key-c"]))
+        .build()
+        .unwrap();
+
+    client.list_models().await.unwrap_err();
+    client.list_models().await.unwrap();
+    client.list_models().await.unwrap();
+    client.list_models().await.unwrap();
+
+    assert_eq!(
+        authorizations(&server),
+        [
+            "Bearer key-a",
+            "Bearer key-b",
+            "Bearer key-c",
+            "Bearer key-b"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn retries_use_the_next_key_of_the_pool() {
+    let server = MockServer::start(limited_first_key).await;
+    let client = server
+        .builder()
+        .credential_provider(KeyPool::new(["key-a", "key-b"]))
+        .retry_policy(
+            RetryPolicy::default().with_delays(Duration::from_millis(1), Duration::from_millis(1)),
+        )
+        .build()
+        .unwrap();
+
+    client.list_models().await.unwrap();
+
+    assert_eq!(authorizations(&server), ["Bearer key-a", "Bearer key-b"]);
+}
+
+#[tokio::test]
+async fn key_pool_uses_cooled_down_keys_again() {
+    let server = MockServer::start(limited_first_key).await;
+    let client = server
+        .builder()
+        .credential_provider(
+            KeyPool::new(["key-a", "key-b"]).with_cool_down(Duration::from_millis(50)),
+        )
+        .build()
+        .unwrap();
+
+    client.list_models().await.unwrap_err();
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    client.list_models().await.unwrap();
+    client.list_models().await.unwrap_err();
+
+    assert_eq!(
+        authorizations(&server),
+        ["Bearer key-a", "Bearer key-b", "Bearer key-a"]
+    );
+}
+
+#[tokio::test]
+async fn file_key_is_read_again_when_modified() {
+    let server = MockServer::start(|_| MockResponse::json(200, MODEL_LIST)).await;
+    let path = std::env::temp_dir().join(format!("openai-key-{}", std::process::id()));
+    std::fs::write(&path, "key-1\n").unwrap();
+    let client = server
+        .builder()
+        .credential_provider(FileKey::new(&path))
+        .build()
+        .unwrap();
+
+    client.list_models().await.unwrap();
+    client.list_models().await.unwrap();
+    std::fs::write(&path, "key-2\n").unwrap();
+    std::fs::File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(1))
+        .unwrap();
+    client.list_models().await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        authorizations(&server),
+        ["Bearer key-1", "Bearer key-1", "Bearer key-2"]
+    );
+}
+
+/// Takes a while to hand out a token and counts how often it was asked.
+struct SlowKey {
+    calls: Arc<AtomicUsize>,
+}
+
+impl CredentialProvider for SlowKey {
+    fn token(&self) -> BoxFuture<'_, ConversaResult<String>> {
+        Box::pin(async {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(format!("key-{call}"))
+        })
+    }
+}
+
+#[tokio::test]
+async fn cached_token_is_fetched_once_for_concurrent_requests() {
+    let server = MockServer::start(|_| MockResponse::json(200, MODEL_LIST)).await;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let client = server
+        .builder()
+        .credential_provider(Cached::new(
+            SlowKey {
+                calls: calls.clone(),
+            },
+            Duration::from_millis(100),
+        ))
+        .build()
+        .unwrap();
+
+    join_all((0..5).map(|_| client.list_models())).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    // Once expired, the token is fetched again once for all the waiting requests
+    tokio::time::sleep(Duration::from_millis(120)).await;
+    join_all((0..5).map(|_| client.list_models())).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    let authorizations = authorizations(&server);
+    assert_eq!(authorizations[..5], ["Bearer key-1"; 5]);
+    assert_eq!(authorizations[5..], ["Bearer key-2"; 5]);
+}
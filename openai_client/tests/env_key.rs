@@ -0,0 +1,27 @@
+mod common;
+
+// Changing the environment while other threads read it is undefined behaviour, so this test has
+// a binary of its own.
+
+use common::{MODEL_LIST, MockResponse, MockServer};
+use conversa_openai_client::credentials::EnvKey;
+
+#[tokio::test]
+async fn env_key_is_read_for_every_request() {
+    let server = MockServer::start(|_| MockResponse::json(200, MODEL_LIST)).await;
+    let client = server
+        .builder()
+        .credential_provider(EnvKey::new("CONVERSA_TEST_ENV_KEY"))
+        .build()
+        .unwrap();
+
+    client.list_models().await.unwrap_err();
+    // SAFETY: this is the only test of the binary and no request is running, so no other thread
+    // reads the environment
+    unsafe { std::env::set_var("CONVERSA_TEST_ENV_KEY", "key-1") };
+    client.list_models().await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].header("authorization"), Some("Bearer key-1"));
+}
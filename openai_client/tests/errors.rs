@@ -0,0 +1,185 @@
+mod common;
+
+use std::error::Error;
+
+use common::{MockResponse, MockServer};
+use conversa_openai_client::{
+    ConversaError, OpenAIClientBuilder,
+    client::ApiErrorBody,
+    types::{CreateEvalRequest, CreateEvalRequestDataSourceConfig, ProjectUpdateRequest},
+};
+
+#[tokio::test]
+async fn api_errors_carry_status_request_id_and_details() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::json(
+            400,
+            r#"{"error":{"message":"This model's maximum context length is 8192 tokens","type":"invalid_request_error","param":"messages","code":"context_length_exceeded"}}"#,
+        )
+        .header("x-request-id", "req_abc"),
+    ])
+    .await;
+
+    let error = server.client().list_models().await.unwrap_err();
+
+    assert_eq!(error.status_code(), Some(400));
+    assert_eq!(error.request_id(), Some("req_abc"));
+    let api_error = error.api_error().unwrap();
+    assert_eq!(api_error.code.as_deref(), Some("context_length_exceeded"));
+    assert_eq!(api_error.r#type.as_deref(), Some("invalid_request_error"));
+    assert_eq!(api_error.param.as_deref(), Some("messages"));
+    assert!(error.is_context_length_exceeded());
+    assert!(!error.is_retryable());
+    assert!(!error.is_rate_limited());
+    assert!(error.source().is_some());
+    assert_eq!(
+        error.to_string(),
+        "API error 400 (context_length_exceeded): This model's maximum context length is 8192 tokens [request id req_abc]"
+    );
+}
+
+#[tokio::test]
+async fn plain_text_error_bodies_are_kept() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::new(502)
+            .header("content-type", "text/html")
+            .body(b"<html>Bad gateway</html>"),
+    ])
+    .await;
+
+    let error = server.client().list_models().await.unwrap_err();
+
+    let api_error = error.api_error().unwrap();
+    assert_eq!(api_error.status_code(), 502);
+    assert_eq!(api_error.message, "<html>Bad gateway</html>");
+    assert_eq!(api_error.body, "<html>Bad gateway</html>");
+    assert_eq!(api_error.code, None);
+    assert!(error.is_retryable());
+}
+
+#[tokio::test]
+async fn rate_limits_and_quota_are_told_apart() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::json(
+            429,
+            r#"{"error":{"message":"Rate limit reached","type":"requests","param":null,"code":"rate_limit_exceeded"}}"#,
+        ),
+        MockResponse::json(
+            429,
+            r#"{"error":{"message":"You exceeded your current quota","type":"insufficient_quota","param":null,"code":"insufficient_quota"}}"#,
+        ),
+    ])
+    .await;
+    let client = server.client();
+
+    let rate_limited = client.list_models().await.unwrap_err();
+    assert!(rate_limited.is_rate_limited());
+    assert!(rate_limited.is_retryable());
+
+    let out_of_quota = client.list_models().await.unwrap_err();
+    assert!(!out_of_quota.is_rate_limited());
+    assert!(!out_of_quota.is_retryable());
+}
+
+#[tokio::test]
+async fn authentication_errors_are_detected() {
+    let server = MockServer::with_responses(vec![MockResponse::json(
+        401,
+        r#"{"error":{"message":"Incorrect API key provided","type":"invalid_request_error","param":null,"code":"invalid_api_key"}}"#,
+    )])
+    .await;
+
+    let error = server.client().list_models().await.unwrap_err();
+
+    assert!(error.is_auth_error());
+    assert!(!error.is_retryable());
+}
+
+#[tokio::test]
+async fn connection_errors_are_retryable() {
+    let client = OpenAIClientBuilder::new("http://127.0.0.1:1".to_string(), "test-key".to_string())
+        .build()
+        .unwrap();
+
+    let error = client.list_models().await.unwrap_err();
+
+    assert!(matches!(error, ConversaError::Http(_)));
+    assert!(error.is_retryable());
+    assert_eq!(error.status_code(), None);
+    assert_eq!(error.request_id(), None);
+}
+
+#[tokio::test]
+async fn declared_error_responses_are_decoded_with_their_schema() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::json(
+            404,
+            r#"{"code":"not_found","message":"No response found with id 'resp_1'","param":null,"type":"invalid_request_error"}"#,
+        ),
+        MockResponse::json(
+            400,
+            r#"{"error":{"code":null,"message":"Project name is required","param":"name","type":"invalid_request_error"}}"#,
+        ),
+    ])
+    .await;
+    let client = server.client();
+
+    let error = client.delete_response("resp_1").await.unwrap_err();
+    let api_error = error.api_error().unwrap();
+    assert_eq!(api_error.code.as_deref(), Some("not_found"));
+    match &api_error.details {
+        Some(ApiErrorBody::Error(e)) => assert_eq!(e.code.as_deref(), Some("not_found")),
+        d => panic!("Unexpected details {d:?}"),
+    }
+
+    let error = client
+        .modify_project(
+            "proj_1",
+            ProjectUpdateRequest {
+                name: String::new(),
+            },
+        )
+        .await
+        .unwrap_err();
+    match &error.api_error().unwrap().details {
+        Some(ApiErrorBody::ErrorResponse(e)) => {
+            assert_eq!(e.error.param.as_deref(), Some("name"))
+        }
+        d => panic!("Unexpected details {d:?}"),
+    }
+}
+
+#[tokio::test]
+async fn undeclared_error_responses_have_no_details() {
+    let server = MockServer::with_responses(vec![MockResponse::json(
+        500,
+        r#"{"error":{"message":"The server had an error","type":"server_error","param":null,"code":null}}"#,
+    )])
+    .await;
+
+    let error = server.client().delete_response("resp_1").await.unwrap_err();
+
+    let api_error = error.api_error().unwrap();
+    assert_eq!(api_error.message, "The server had an error");
+    assert_eq!(api_error.details, None);
+}
+
+#[tokio::test]
+async fn declared_created_status_is_a_success() {
+    let server = MockServer::with_responses(vec![MockResponse::json(
+        201,
+        r#"{"object":"eval","id":"eval_1","name":"Eval","data_source_config":"custom","testing_criteria":[],"created_at":1752653808,"metadata":{}}"#,
+    )])
+    .await;
+    let request = CreateEvalRequest {
+        name: Some("Eval".to_string()),
+        metadata: None,
+        data_source_config: CreateEvalRequestDataSourceConfig("custom".to_string()),
+        testing_criteria: Vec::new(),
+    };
+
+    let eval = server.client().create_eval(request).await.unwrap();
+
+    assert_eq!(eval.id, "eval_1");
+    assert_eq!(server.requests()[0].json()["name"], "Eval");
+}
@@ -0,0 +1,140 @@
+mod common;
+
+use common::{MODEL_LIST, MockResponse, MockServer};
+use conversa_openai_client::types::{ValidateGraderRequest, ValidateGraderRequestGrader};
+
+#[tokio::test]
+async fn organization_project_and_default_headers_are_sent() {
+    let server = MockServer::start(|_| MockResponse::json(200, MODEL_LIST)).await;
+    let client = server
+        .builder()
+        .organization("org-1")
+        .project("proj_1")
+        .default_header("X-Team", "search")
+        .default_header("x-team", "billing")
+        .build()
+        .unwrap();
+
+    client.list_models().await.unwrap();
+    client.list_models().await.unwrap();
+
+    for request in server.requests() {
+        assert_eq!(request.header("openai-organization"), Some("org-1"));
+        assert_eq!(request.header("openai-project"), Some("proj_1"));
+        assert_eq!(request.header("x-team"), Some("billing"));
+        assert_eq!(request.header("authorization"), Some("Bearer test-key"));
+    }
+}
+
+#[tokio::test]
+async fn default_headers_do_not_replace_operation_headers() {
+    let server = MockServer::start(|_| MockResponse::json(200, MODEL_LIST)).await;
+    let client = server
+        .builder()
+        .default_header("Authorization", "Bearer other-key")
+        .build()
+        .unwrap();
+
+    client.list_models().await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].header("authorization"), Some("Bearer test-key"));
+    assert_eq!(
+        requests[0]
+            .headers
+            .iter()
+            .filter(|(n, _)| n == "authorization")
+            .count(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn invalid_default_header_fails_build() {
+    let server = MockServer::start(|_| MockResponse::json(200, MODEL_LIST)).await;
+
+    assert!(
+        server
+            .builder()
+            .default_header("X-Team", "line\nbreak")
+            .build()
+            .is_err()
+    );
+    assert!(
+        server
+            .builder()
+            .default_header("X Team", "search")
+            .build()
+            .is_err()
+    );
+}
+
+const EMPTY_LIST: &str =
+    r#"{"object":"list","data":[],"first_id":"","last_id":"","has_more":false}"#;
+
+#[tokio::test]
+async fn beta_operations_send_openai_beta_header() {
+    let server = MockServer::start(|r| {
+        if r.path == "/models" {
+            MockResponse::json(200, MODEL_LIST)
+        } else {
+            MockResponse::json(200, EMPTY_LIST)
+        }
+    })
+    .await;
+    let client = server.client();
+
+    client
+        .list_assistants(None, None, None, None)
+        .await
+        .unwrap();
+    client
+        .list_vector_stores(None, None, None, None)
+        .await
+        .unwrap();
+    client.list_models().await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].header("openai-beta"), Some("assistants=v2"));
+    assert_eq!(requests[1].header("openai-beta"), Some("assistants=v2"));
+    assert_eq!(requests[2].header("openai-beta"), None);
+}
+
+#[tokio::test]
+async fn beta_operations_of_other_groups_send_no_openai_beta_header() {
+    let server = MockServer::with_responses(vec![MockResponse::json(200, "{}")]).await;
+
+    server
+        .client()
+        .validate_grader(ValidateGraderRequest {
+            grader: ValidateGraderRequestGrader("string_check".to_string()),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(server.requests()[0].header("openai-beta"), None);
+}
+
+#[tokio::test]
+async fn openai_beta_header_can_be_overridden_or_disabled() {
+    let server = MockServer::start(|_| MockResponse::json(200, EMPTY_LIST)).await;
+
+    let client = server
+        .builder()
+        .openai_beta("assistants=v3")
+        .build()
+        .unwrap();
+    client
+        .list_assistants(Some(1), None, None, None)
+        .await
+        .unwrap();
+    let client = server.builder().without_openai_beta().build().unwrap();
+    client
+        .list_assistants(Some(1), None, None, None)
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].header("openai-beta"), Some("assistants=v3"));
+    assert_eq!(requests[1].header("openai-beta"), None);
+}
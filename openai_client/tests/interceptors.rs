@@ -0,0 +1,183 @@
+mod common;
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use common::{MODEL_LIST, MockResponse, MockServer};
+use conversa_openai_client::{
+    ConversaError, ConversaResult,
+    interceptor::Interceptor,
+    retry::RetryPolicy,
+    transport::{HttpRequest, HttpResponse},
+};
+use reqwest::{
+    StatusCode,
+    header::{HeaderMap, HeaderName, HeaderValue},
+};
+
+/// Records what it sees in a shared log, prefixed with its name.
+struct AuditLog {
+    name: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+impl Interceptor for AuditLog {
+    fn before_send(&self, request: &mut HttpRequest) -> ConversaResult<Option<HttpResponse>> {
+        self.log.lock().unwrap().push(format!(
+            "{} {} {} {}",
+            self.name, request.operation.name, request.method, request.url
+        ));
+        Ok(None)
+    }
+
+    fn after_receive(
+        &self,
+        request: &HttpRequest,
+        response: &mut HttpResponse,
+    ) -> ConversaResult<()> {
+        self.log.lock().unwrap().push(format!(
+            "{} {} {}",
+            self.name,
+            request.operation.id,
+            response.status().as_u16()
+        ));
+        Ok(())
+    }
+}
+
+struct TenantHeader(&'static str);
+
+impl Interceptor for TenantHeader {
+    fn before_send(&self, request: &mut HttpRequest) -> ConversaResult<Option<HttpResponse>> {
+        request.headers.insert(
+            HeaderName::from_static("x-tenant"),
+            HeaderValue::from_static(self.0),
+        );
+        Ok(None)
+    }
+}
+
+/// Answers every request from a cache instead of sending it.
+struct Cached;
+
+impl Interceptor for Cached {
+    fn before_send(&self, _request: &mut HttpRequest) -> ConversaResult<Option<HttpResponse>> {
+        Ok(Some(HttpResponse {
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            body: Box::pin(futures::stream::once(async {
+                Ok(Bytes::from_static(MODEL_LIST.as_bytes()))
+            })),
+        }))
+    }
+}
+
+struct Forbidden;
+
+impl Interceptor for Forbidden {
+    fn before_send(&self, request: &mut HttpRequest) -> ConversaResult<Option<HttpResponse>> {
+        Err(ConversaError::ClientError(format!(
+            "{} is not allowed",
+            request.operation.name
+        )))
+    }
+}
+
+#[tokio::test]
+async fn interceptors_see_every_request_and_response_in_order() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::json(503, r#"{"error":{"message":"Overloaded"}}"#),
+        MockResponse::json(200, MODEL_LIST),
+    ])
+    .await;
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let client = server
+        .builder()
+        .interceptor(AuditLog {
+            name: "outer",
+            log: log.clone(),
+        })
+        .interceptor(AuditLog {
+            name: "inner",
+            log: log.clone(),
+        })
+        .retry_policy(
+            RetryPolicy::default().with_delays(Duration::from_millis(1), Duration::from_millis(1)),
+        )
+        .build()
+        .unwrap();
+
+    client.list_models().await.unwrap();
+
+    let url = format!("{}/models", server.address);
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            format!("outer list_models GET {url}"),
+            format!("inner list_models GET {url}"),
+            "inner listModels 503".to_string(),
+            "outer listModels 503".to_string(),
+            format!("outer list_models GET {url}"),
+            format!("inner list_models GET {url}"),
+            "inner listModels 200".to_string(),
+            "outer listModels 200".to_string(),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn interceptors_can_modify_the_request() {
+    let server = MockServer::with_responses(vec![MockResponse::json(200, MODEL_LIST)]).await;
+    let client = server
+        .builder()
+        .interceptor(TenantHeader("tenant-1"))
+        .build()
+        .unwrap();
+
+    client.list_models().await.unwrap();
+
+    assert_eq!(server.requests()[0].header("x-tenant"), Some("tenant-1"));
+}
+
+#[tokio::test]
+async fn interceptors_can_answer_the_request() {
+    let server = MockServer::with_responses(Vec::new()).await;
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let client = server
+        .builder()
+        .interceptor(AuditLog {
+            name: "outer",
+            log: log.clone(),
+        })
+        .interceptor(Cached)
+        .interceptor(AuditLog {
+            name: "inner",
+            log: log.clone(),
+        })
+        .build()
+        .unwrap();
+
+    let models = client.list_models().await.unwrap();
+
+    assert!(models.data.is_empty());
+    assert!(server.requests().is_empty());
+    assert_eq!(log.lock().unwrap().len(), 2);
+    assert!(log.lock().unwrap()[1].starts_with("outer listModels 200"));
+}
+
+#[tokio::test]
+async fn interceptors_can_reject_the_request() {
+    let server = MockServer::with_responses(Vec::new()).await;
+    let client = server.builder().interceptor(Forbidden).build().unwrap();
+
+    match client.list_models().await {
+        Err(ConversaError::ClientError(message)) => {
+            assert_eq!(message, "list_models is not allowed")
+        }
+        r => panic!("Unexpected result {r:?}"),
+    }
+    assert!(server.requests().is_empty());
+}
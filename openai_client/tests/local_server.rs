@@ -0,0 +1,177 @@
+mod common;
+
+use common::{MockResponse, MockServer, RecordedRequest};
+use conversa_openai_client::{
+    OpenAIClient, OpenAIClientBuilder,
+    client::CreateChatCompletionResponse,
+    compat::CompatibilityProfile,
+    types::{CreateChatCompletionResponseChoicesFinishReason, EmbeddingObject, ModelObject},
+};
+
+/// Answers like Ollama, which leaves out several fields the OpenAI API always sends.
+fn ollama(request: &RecordedRequest) -> MockResponse {
+    match request.path.as_str() {
+        "/v1/chat/completions" => MockResponse::json(
+            200,
+            r#"{"id":"chatcmpl-42","object":"chat.completion","created":1752653808,"model":"llama3.2","choices":[{"index":0,"message":{"role":"assistant","content":"","tool_calls":[{"function":{"name":"get_weather","arguments":{"city":"Paris"}}}]},"finish_reason":null}],"usage":{"prompt_tokens":12,"completion_tokens":3}}"#,
+        ),
+        "/v1/embeddings" => MockResponse::json(
+            200,
+            r#"{"object":"list","data":[{"embedding":[0.5,0.25]}],"model":"nomic-embed-text"}"#,
+        ),
+        "/v1/models" => MockResponse::json(
+            200,
+            r#"{"object":"list","data":[{"id":"llama3.2:latest","object":"model","created":1752653808,"owned_by":"library","size":2019393189}]}"#,
+        ),
+        _ => MockResponse::json(
+            200,
+            r#"{"object":"list","data":[],"first_id":"","last_id":"","has_more":false}"#,
+        ),
+    }
+}
+
+async fn local_client(server: &MockServer) -> OpenAIClient {
+    OpenAIClientBuilder::local_server(format!("{}/v1", server.address))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn incomplete_chat_completions_are_accepted() {
+    let server = MockServer::start(ollama).await;
+    let client = local_client(&server).await;
+
+    let response = client
+        .create_chat_completion(
+            serde_json::from_str(
+                r#"{"model":"llama3.2","messages":[{"role":"user","content":"Weather in Paris?"}]}"#,
+            )
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let CreateChatCompletionResponse::ApplicationJson(completion) = response else {
+        panic!("Unexpected response {response:?}");
+    };
+    let choice = &completion.choices[0];
+    assert_eq!(
+        choice.finish_reason,
+        CreateChatCompletionResponseChoicesFinishReason::Stop
+    );
+    let tool_call = &choice.message.tool_calls.as_ref().unwrap()[0];
+    assert_eq!(tool_call.id, "call_0");
+    assert_eq!(tool_call.function.name, "get_weather");
+    assert_eq!(tool_call.function.arguments, r#"{"city":"Paris"}"#);
+    assert_eq!(completion.system_fingerprint, None);
+    assert_eq!(completion.usage.unwrap().total_tokens, 15);
+}
+
+fn chat_completion_with_choice(choice: &str) -> MockResponse {
+    MockResponse::json(
+        200,
+        &format!(
+            r#"{{"id":"chatcmpl-42","object":"chat.completion","created":1752653808,"model":"llama3.2","choices":[{choice}]}}"#
+        ),
+    )
+}
+
+#[tokio::test]
+async fn finish_reasons_are_completed() {
+    let server = MockServer::with_responses(vec![
+        chat_completion_with_choice(r#"{"message":{"content":"Sunny"}}"#),
+        chat_completion_with_choice(r#"{"message":{"content":"Sunny"},"finish_reason":"eos"}"#),
+        chat_completion_with_choice(r#"{"message":{"content":"Sunny"},"finish_reason":"length"}"#),
+    ])
+    .await;
+    let client = local_client(&server).await;
+
+    let mut finish_reasons = Vec::new();
+    for _ in 0..3 {
+        let response = client
+            .create_chat_completion(
+                serde_json::from_str(
+                    r#"{"model":"llama3.2","messages":[{"role":"user","content":"Weather in Paris?"}]}"#,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let CreateChatCompletionResponse::ApplicationJson(mut completion) = response else {
+            panic!("Unexpected response {response:?}");
+        };
+        finish_reasons.push(completion.choices.remove(0).finish_reason);
+    }
+
+    assert_eq!(
+        finish_reasons,
+        [
+            CreateChatCompletionResponseChoicesFinishReason::Stop,
+            CreateChatCompletionResponseChoicesFinishReason::Stop,
+            CreateChatCompletionResponseChoicesFinishReason::Length
+        ]
+    );
+}
+
+#[tokio::test]
+async fn incomplete_embeddings_are_accepted() {
+    let server = MockServer::start(ollama).await;
+    let client = local_client(&server).await;
+
+    let embeddings = client
+        .create_embedding(
+            serde_json::from_str(r#"{"input":"Paris","model":"nomic-embed-text"}"#).unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(embeddings.data[0].embedding, [0.5, 0.25]);
+    assert_eq!(embeddings.data[0].object, EmbeddingObject::Embedding);
+    assert_eq!(embeddings.usage.prompt_tokens, 0);
+}
+
+#[tokio::test]
+async fn models_with_extra_fields_are_accepted() {
+    let server = MockServer::start(ollama).await;
+    let client = local_client(&server).await;
+
+    let models = client.list_models().await.unwrap();
+
+    assert_eq!(models.data[0].id, "llama3.2:latest");
+    assert_eq!(models.data[0].object, ModelObject::Model);
+}
+
+#[tokio::test]
+async fn requests_carry_no_api_key_or_beta_header() {
+    let server = MockServer::start(ollama).await;
+    let client = local_client(&server).await;
+
+    client.list_models().await.unwrap();
+    client
+        .list_assistants(None, None, None, None)
+        .await
+        .unwrap();
+
+    for request in server.requests() {
+        assert_eq!(request.header("authorization"), None);
+        assert_eq!(request.header("openai-beta"), None);
+    }
+}
+
+#[tokio::test]
+async fn api_key_is_sent_when_given() {
+    let server = MockServer::start(ollama).await;
+    let client = server
+        .builder()
+        .compatibility_profile(CompatibilityProfile::LocalServer)
+        .build()
+        .unwrap();
+
+    client
+        .list_assistants(None, None, None, None)
+        .await
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.header("authorization"), Some("Bearer test-key"));
+}
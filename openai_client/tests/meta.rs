@@ -0,0 +1,80 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{MODEL_LIST, MockResponse, MockServer, speech_request};
+
+#[tokio::test]
+async fn response_metadata_is_returned_with_the_data() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::json(200, MODEL_LIST)
+            .header("x-request-id", "req_123")
+            .header("openai-processing-ms", "42")
+            .header("openai-version", "2020-10-01")
+            .header("openai-organization", "org-1")
+            .header("x-ratelimit-limit-requests", "5000")
+            .header("x-ratelimit-limit-tokens", "800000")
+            .header("x-ratelimit-remaining-requests", "4999")
+            .header("x-ratelimit-remaining-tokens", "799990")
+            .header("x-ratelimit-reset-requests", "12ms")
+            .header("x-ratelimit-reset-tokens", "1m2.5s"),
+    ])
+    .await;
+
+    let response = server.client().list_models_with_meta().await.unwrap();
+
+    assert!(response.data.data.is_empty());
+    let meta = response.meta;
+    assert_eq!(meta.status_code, 200);
+    assert_eq!(meta.request_id.as_deref(), Some("req_123"));
+    assert_eq!(meta.processing_time, Some(Duration::from_millis(42)));
+    assert_eq!(meta.openai_version.as_deref(), Some("2020-10-01"));
+    assert_eq!(meta.organization.as_deref(), Some("org-1"));
+    assert_eq!(meta.rate_limits.limit_requests, Some(5000));
+    assert_eq!(meta.rate_limits.limit_tokens, Some(800000));
+    assert_eq!(meta.rate_limits.remaining_requests, Some(4999));
+    assert_eq!(meta.rate_limits.remaining_tokens, Some(799990));
+    assert_eq!(
+        meta.rate_limits.reset_requests,
+        Some(Duration::from_millis(12))
+    );
+    assert_eq!(
+        meta.rate_limits.reset_tokens,
+        Some(Duration::from_millis(62500))
+    );
+    assert_eq!(meta.headers["x-request-id"], "req_123");
+}
+
+#[tokio::test]
+async fn missing_metadata_headers_are_none() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::json(200, MODEL_LIST).header("x-ratelimit-reset-tokens", "soon"),
+    ])
+    .await;
+
+    let meta = server.client().list_models_with_meta().await.unwrap().meta;
+
+    assert_eq!(meta.request_id, None);
+    assert_eq!(meta.processing_time, None);
+    assert_eq!(meta.rate_limits.remaining_tokens, None);
+    assert_eq!(meta.rate_limits.reset_tokens, None);
+}
+
+#[tokio::test]
+async fn stream_exposes_response_metadata() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::new(200)
+            .header("content-type", "audio/mpeg")
+            .header("x-request-id", "req_456")
+            .body(&[1, 2, 3]),
+    ])
+    .await;
+
+    let stream = server
+        .client()
+        .create_speech_audio_stream(speech_request())
+        .await
+        .unwrap();
+
+    assert_eq!(stream.meta().request_id.as_deref(), Some("req_456"));
+}
@@ -0,0 +1,271 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use conversa_openai_client::{
+    multipart::File,
+    types::{
+        CreateImageEditRequest, CreateImageEditRequestBackground, CreateImageEditRequestImage,
+        CreateImageEditRequestModel, CreateImageEditRequestOutputFormat,
+        CreateImageEditRequestQuality, CreateImageEditRequestSize, CreateImageVariationRequest,
+        CreateImageVariationRequestResponseFormat, CreateImageVariationRequestSize,
+        CreateTranscriptionRequest, CreateTranscriptionRequestChunkingStrategy,
+        CreateTranscriptionRequestModel, CreateTranslationRequest, CreateTranslationRequestModel,
+        CreateTranslationRequestResponseFormat, VadConfig, VadConfigType,
+    },
+};
+
+fn audio_file() -> File {
+    File {
+        file_name: "audio.mp3".to_string(),
+        file_data: vec![0xff, 0xfb, 0x90, 0x00],
+    }
+}
+
+fn image_file(file_name: &str) -> File {
+    File {
+        file_name: file_name.to_string(),
+        file_data: vec![0x89, 0x50, 0x4e, 0x47],
+    }
+}
+
+fn image_edit_request(image: CreateImageEditRequestImage) -> CreateImageEditRequest {
+    CreateImageEditRequest {
+        image,
+        prompt: "Add a beret".to_string(),
+        mask: None,
+        background: None,
+        model: None,
+        n: None,
+        size: None,
+        response_format: None,
+        output_format: None,
+        output_compression: None,
+        user: None,
+        quality: None,
+    }
+}
+
+const IMAGES_RESPONSE: &str = r#"{"created":1752653808,"data":[{"b64_json":"iVBORw0KGgo="}]}"#;
+
+#[tokio::test]
+async fn image_edit_form_contains_image_mask_and_options() {
+    let server = MockServer::with_responses(vec![MockResponse::json(200, IMAGES_RESPONSE)]).await;
+
+    let mut request_body =
+        image_edit_request(CreateImageEditRequestImage::File(image_file("otter.png")));
+    request_body.mask = Some(image_file("mask.PNG"));
+    request_body.background = Some(CreateImageEditRequestBackground::Transparent);
+    request_body.model = Some(CreateImageEditRequestModel::String(
+        "gpt-image-1".to_string(),
+    ));
+    request_body.n = Some(2);
+    request_body.size = Some(CreateImageEditRequestSize::Size1024x1024);
+    request_body.output_format = Some(CreateImageEditRequestOutputFormat::Webp);
+    request_body.output_compression = Some(80);
+    request_body.quality = Some(CreateImageEditRequestQuality::High);
+    let response = server
+        .client()
+        .create_image_edit(request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.data.unwrap().len(), 1);
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/images/edits");
+    let parts = request.form_parts();
+    assert_eq!(parts[0].name, "image");
+    assert_eq!(parts[0].file_name.as_deref(), Some("otter.png"));
+    assert_eq!(parts[0].content_type.as_deref(), Some("image/png"));
+    assert_eq!(parts[0].data, [0x89, 0x50, 0x4e, 0x47]);
+    let mask = parts.iter().find(|p| p.name == "mask").unwrap();
+    assert_eq!(mask.file_name.as_deref(), Some("mask.PNG"));
+    assert_eq!(mask.content_type.as_deref(), Some("image/png"));
+    assert_eq!(request.form_values("prompt"), ["Add a beret"]);
+    assert_eq!(request.form_values("background"), ["transparent"]);
+    assert_eq!(request.form_values("model"), ["gpt-image-1"]);
+    assert_eq!(request.form_values("n"), ["2"]);
+    assert_eq!(request.form_values("size"), ["1024x1024"]);
+    assert_eq!(request.form_values("output_format"), ["webp"]);
+    assert_eq!(request.form_values("output_compression"), ["80"]);
+    assert_eq!(request.form_values("quality"), ["high"]);
+    assert!(request.form_values("response_format").is_empty());
+}
+
+#[tokio::test]
+async fn image_edit_form_sends_multiple_images_as_array() {
+    let server = MockServer::with_responses(vec![MockResponse::json(200, IMAGES_RESPONSE)]).await;
+
+    let request_body = image_edit_request(CreateImageEditRequestImage::ArrayFile(vec![
+        image_file("first.jpg"),
+        image_file("second.webp"),
+    ]));
+    server
+        .client()
+        .create_image_edit(request_body)
+        .await
+        .unwrap();
+
+    let images: Vec<_> = server.requests()[0]
+        .form_parts()
+        .into_iter()
+        .filter(|p| p.name == "image[]")
+        .map(|p| (p.file_name.unwrap(), p.content_type.unwrap()))
+        .collect();
+    assert_eq!(
+        images,
+        [
+            ("first.jpg".to_string(), "image/jpeg".to_string()),
+            ("second.webp".to_string(), "image/webp".to_string())
+        ]
+    );
+}
+
+#[tokio::test]
+async fn image_variation_form_contains_image_and_options() {
+    let server = MockServer::with_responses(vec![MockResponse::json(200, IMAGES_RESPONSE)]).await;
+
+    let request_body = CreateImageVariationRequest {
+        image: image_file("otter.png"),
+        model: None,
+        n: Some(1),
+        response_format: Some(CreateImageVariationRequestResponseFormat::B64Json),
+        size: Some(CreateImageVariationRequestSize::Size256x256),
+        user: Some("user-1".to_string()),
+    };
+    server
+        .client()
+        .create_image_variation(request_body)
+        .await
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/images/variations");
+    let parts = request.form_parts();
+    assert_eq!(parts[0].name, "image");
+    assert_eq!(parts[0].content_type.as_deref(), Some("image/png"));
+    assert_eq!(request.form_values("n"), ["1"]);
+    assert_eq!(request.form_values("response_format"), ["b64_json"]);
+    assert_eq!(request.form_values("size"), ["256x256"]);
+    assert_eq!(request.form_values("user"), ["user-1"]);
+    assert!(request.form_values("model").is_empty());
+}
+
+#[tokio::test]
+async fn transcription_form_contains_every_field() {
+    let server =
+        MockServer::with_responses(vec![MockResponse::json(200, r#"{"text":"Hola"}"#)]).await;
+
+    let request_body = CreateTranscriptionRequest {
+        file: audio_file(),
+        model: CreateTranscriptionRequestModel::String("whisper-1".to_string()),
+        language: Some("es".to_string()),
+        prompt: Some("Reunión semanal".to_string()),
+        response_format: Some("verbose_json".to_string()),
+        temperature: Some(0.2),
+        include: Some(vec!["logprobs".to_string()]),
+        timestamp_granularities: Some(vec!["word".to_string(), "segment".to_string()]),
+        stream: Some(false),
+        chunking_strategy: Some(CreateTranscriptionRequestChunkingStrategy::VadConfig(
+            VadConfig {
+                r#type: VadConfigType::ServerVad,
+                prefix_padding_ms: Some(300),
+                silence_duration_ms: None,
+                threshold: None,
+            },
+        )),
+    };
+    server
+        .client()
+        .create_transcription(request_body)
+        .await
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/audio/transcriptions");
+    assert_eq!(request.form_values("model"), ["whisper-1"]);
+    assert_eq!(request.form_values("language"), ["es"]);
+    assert_eq!(request.form_values("prompt"), ["Reunión semanal"]);
+    assert_eq!(request.form_values("response_format"), ["verbose_json"]);
+    assert_eq!(request.form_values("temperature"), ["0.2"]);
+    assert_eq!(request.form_values("include[]"), ["logprobs"]);
+    assert_eq!(
+        request.form_values("timestamp_granularities[]"),
+        ["word", "segment"]
+    );
+    assert_eq!(request.form_values("stream"), ["false"]);
+    assert_eq!(
+        request.form_values("chunking_strategy"),
+        [r#"{"prefix_padding_ms":300,"type":"server_vad"}"#]
+    );
+    let file = request
+        .form_parts()
+        .into_iter()
+        .find(|p| p.name == "file")
+        .unwrap();
+    assert_eq!(file.file_name.as_deref(), Some("audio.mp3"));
+    assert_eq!(file.content_type.as_deref(), Some("audio/mpeg"));
+    assert_eq!(file.data, [0xff, 0xfb, 0x90, 0x00]);
+}
+
+#[tokio::test]
+async fn transcription_form_skips_unset_fields() {
+    let server =
+        MockServer::with_responses(vec![MockResponse::json(200, r#"{"text":"Hola"}"#)]).await;
+
+    let request_body = CreateTranscriptionRequest {
+        file: audio_file(),
+        model: CreateTranscriptionRequestModel::String("whisper-1".to_string()),
+        language: None,
+        prompt: None,
+        response_format: None,
+        temperature: None,
+        include: None,
+        timestamp_granularities: None,
+        stream: None,
+        chunking_strategy: Some(CreateTranscriptionRequestChunkingStrategy::String(
+            "auto".to_string(),
+        )),
+    };
+    server
+        .client()
+        .create_transcription(request_body)
+        .await
+        .unwrap();
+
+    let names: Vec<_> = server.requests()[0]
+        .form_parts()
+        .into_iter()
+        .map(|p| p.name)
+        .collect();
+    assert_eq!(names, ["model", "file", "chunking_strategy"]);
+    assert_eq!(
+        server.requests()[0].form_values("chunking_strategy"),
+        ["auto"]
+    );
+}
+
+#[tokio::test]
+async fn translation_form_contains_every_field() {
+    let server =
+        MockServer::with_responses(vec![MockResponse::json(200, r#"{"text":"Hello"}"#)]).await;
+
+    let request_body = CreateTranslationRequest {
+        file: audio_file(),
+        model: CreateTranslationRequestModel::String("whisper-1".to_string()),
+        prompt: Some("Weekly meeting".to_string()),
+        response_format: Some(CreateTranslationRequestResponseFormat::Json),
+        temperature: Some(0.0),
+    };
+    server
+        .client()
+        .create_translation(request_body)
+        .await
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/audio/translations");
+    assert_eq!(request.form_values("model"), ["whisper-1"]);
+    assert_eq!(request.form_values("prompt"), ["Weekly meeting"]);
+    assert_eq!(request.form_values("response_format"), ["json"]);
+    assert_eq!(request.form_values("temperature"), ["0"]);
+}
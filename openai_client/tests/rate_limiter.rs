@@ -0,0 +1,159 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::{MODEL_LIST, MockResponse, MockServer, embedding_request};
+use conversa_openai_client::{
+    OpenAIClient,
+    rate_limiter::{Budget, RateLimiter},
+    types::CreateChatCompletionRequest,
+};
+
+const EMBEDDING: &str = r#"{"object":"list","data":[],"model":"text-embedding-3-small","usage":{"prompt_tokens":5,"total_tokens":5}}"#;
+const CHAT_COMPLETION: &str = r#"{"id":"chatcmpl-1","object":"chat.completion","created":1752653808,"model":"gpt-4o","choices":[],"usage":{"prompt_tokens":10,"completion_tokens":5,"total_tokens":15}}"#;
+
+fn chat_request(max_completion_tokens: u64) -> CreateChatCompletionRequest {
+    serde_json::from_value(serde_json::json!({
+        "model": "gpt-4o",
+        "messages": [{"role": "user", "content": "Capital of France?"}],
+        "max_completion_tokens": max_completion_tokens,
+    }))
+    .unwrap()
+}
+
+async fn timed_embedding(client: &OpenAIClient, model: &str) -> Duration {
+    let started = Instant::now();
+    client
+        .create_embedding(embedding_request(model))
+        .await
+        .unwrap();
+    started.elapsed()
+}
+
+#[tokio::test]
+async fn requests_wait_for_the_budget_of_their_model() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(200, EMBEDDING).header("x-ratelimit-remaining-requests", "0")
+    })
+    .await;
+    // 1200 requests per minute allow one request every 50 ms once the budget is used up
+    let rate_limiter = RateLimiter::new().with_model_budget(
+        "text-embedding-3-small",
+        Budget::default().with_requests_per_minute(1200),
+    );
+    let client = server.builder().rate_limiter(rate_limiter).build().unwrap();
+
+    assert!(timed_embedding(&client, "text-embedding-3-small").await < Duration::from_millis(40));
+    assert!(timed_embedding(&client, "text-embedding-3-small").await >= Duration::from_millis(40));
+    assert!(timed_embedding(&client, "text-embedding-3-large").await < Duration::from_millis(40));
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn default_budget_applies_to_the_other_models() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(200, EMBEDDING).header("x-ratelimit-remaining-requests", "0")
+    })
+    .await;
+    let rate_limiter = RateLimiter::new()
+        .with_model_budget(
+            "text-embedding-3-small",
+            Budget::default().with_requests_per_minute(100_000),
+        )
+        .with_default_budget(Budget::default().with_requests_per_minute(1200));
+    let client = server.builder().rate_limiter(rate_limiter).build().unwrap();
+
+    timed_embedding(&client, "text-embedding-3-large").await;
+    assert!(timed_embedding(&client, "text-embedding-3-large").await >= Duration::from_millis(40));
+    assert!(timed_embedding(&client, "text-embedding-3-small").await < Duration::from_millis(40));
+}
+
+#[tokio::test]
+async fn cancelled_requests_give_back_their_budget() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(200, EMBEDDING).header("x-ratelimit-remaining-requests", "0")
+    })
+    .await;
+    // 600 requests per minute allow one request every 100 ms once the budget is used up
+    let rate_limiter = RateLimiter::new().with_model_budget(
+        "text-embedding-3-small",
+        Budget::default().with_requests_per_minute(600),
+    );
+    let client = server.builder().rate_limiter(rate_limiter).build().unwrap();
+
+    timed_embedding(&client, "text-embedding-3-small").await;
+    let cancelled = tokio::time::timeout(
+        Duration::from_millis(20),
+        client.create_embedding(embedding_request("text-embedding-3-small")),
+    )
+    .await;
+    assert!(cancelled.is_err());
+    tokio::time::sleep(Duration::from_millis(110)).await;
+
+    // Without the budget of the cancelled request, this one would wait for another 70 ms
+    assert!(timed_embedding(&client, "text-embedding-3-small").await < Duration::from_millis(40));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn token_estimates_are_corrected_with_the_usage() {
+    let server = MockServer::start(|_| MockResponse::json(200, CHAT_COMPLETION)).await;
+    // The first request asks for the whole budget of 6000 tokens but uses 15 of them
+    let rate_limiter = RateLimiter::new()
+        .with_model_budget("gpt-4o", Budget::default().with_tokens_per_minute(6000));
+    let client = server.builder().rate_limiter(rate_limiter).build().unwrap();
+
+    client
+        .create_chat_completion(chat_request(6000))
+        .await
+        .unwrap();
+    let started = Instant::now();
+    client
+        .create_chat_completion(chat_request(100))
+        .await
+        .unwrap();
+
+    assert!(started.elapsed() < Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn token_budget_is_waited_for() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(200, CHAT_COMPLETION).header("x-ratelimit-remaining-tokens", "0")
+    })
+    .await;
+    // 6000 tokens per minute are replenished at 100 tokens per second
+    let rate_limiter = RateLimiter::new()
+        .with_model_budget("gpt-4o", Budget::default().with_tokens_per_minute(6000));
+    let client = server.builder().rate_limiter(rate_limiter).build().unwrap();
+
+    client
+        .create_chat_completion(chat_request(10))
+        .await
+        .unwrap();
+    let started = Instant::now();
+    client
+        .create_chat_completion(chat_request(10))
+        .await
+        .unwrap();
+
+    // The second request is estimated at more than 30 tokens
+    assert!(started.elapsed() >= Duration::from_millis(300));
+}
+
+#[tokio::test]
+async fn requests_without_a_model_are_not_limited() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(200, MODEL_LIST).header("x-ratelimit-remaining-requests", "0")
+    })
+    .await;
+    let rate_limiter =
+        RateLimiter::new().with_default_budget(Budget::default().with_requests_per_minute(1));
+    let client = server.builder().rate_limiter(rate_limiter).build().unwrap();
+
+    let started = Instant::now();
+    client.list_models().await.unwrap();
+    client.list_models().await.unwrap();
+
+    assert!(started.elapsed() < Duration::from_millis(500));
+}
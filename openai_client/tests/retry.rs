@@ -0,0 +1,314 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::{MODEL_LIST, MockResponse, MockServer, embedding_request};
+use conversa_openai_client::{ConversaError, retry::RetryPolicy};
+
+const EMBEDDINGS: &str = r#"{"object":"list","data":[],"model":"text-embedding-3-small","usage":{"prompt_tokens":1,"total_tokens":1}}"#;
+
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy::default().with_delays(Duration::from_millis(1), Duration::from_millis(10))
+}
+
+fn overloaded() -> MockResponse {
+    MockResponse::json(
+        503,
+        r#"{"error":{"message":"Overloaded","type":"server_error","param":null,"code":null}}"#,
+    )
+}
+
+#[tokio::test]
+async fn idempotent_request_is_retried() {
+    let server = MockServer::with_responses(vec![
+        overloaded(),
+        MockResponse::json(429, "{}"),
+        MockResponse::json(200, MODEL_LIST),
+    ])
+    .await;
+    let client = server
+        .builder()
+        .retry_policy(fast_policy())
+        .build()
+        .unwrap();
+
+    let models = client.list_models().await.unwrap();
+
+    assert!(models.data.is_empty());
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn retries_stop_after_max_attempts() {
+    let server = MockServer::start(|_| overloaded()).await;
+    let client = server
+        .builder()
+        .retry_policy(fast_policy().with_max_attempts(2))
+        .build()
+        .unwrap();
+
+    let result = client.list_models().await;
+
+    assert_eq!(result.unwrap_err().status_code(), Some(503));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn client_errors_are_not_retried() {
+    let server = MockServer::start(|_| MockResponse::json(401, "{}")).await;
+    let client = server
+        .builder()
+        .retry_policy(fast_policy())
+        .build()
+        .unwrap();
+
+    assert!(client.list_models().await.is_err());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn non_idempotent_request_is_sent_once() {
+    let server = MockServer::start(|_| overloaded()).await;
+    let client = server
+        .builder()
+        .retry_policy(fast_policy())
+        .build()
+        .unwrap();
+
+    assert!(
+        client
+            .create_embedding(embedding_request("text-embedding-3-small"))
+            .await
+            .is_err()
+    );
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn safe_operation_is_retried() {
+    let server =
+        MockServer::with_responses(vec![overloaded(), MockResponse::json(200, EMBEDDINGS)]).await;
+    let client = server
+        .builder()
+        .retry_policy(fast_policy().with_safe_operation("createEmbedding"))
+        .build()
+        .unwrap();
+
+    client
+        .create_embedding(embedding_request("text-embedding-3-small"))
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].body, requests[1].body);
+}
+
+#[tokio::test]
+async fn retry_after_header_overrides_backoff() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::json(429, "{}").header("retry-after-ms", "200"),
+        MockResponse::json(200, MODEL_LIST),
+    ])
+    .await;
+    let client = server
+        .builder()
+        .retry_policy(
+            RetryPolicy::default().with_delays(Duration::from_secs(60), Duration::from_secs(60)),
+        )
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    client.list_models().await.unwrap();
+
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(200), "{elapsed:?}");
+    assert!(elapsed < Duration::from_secs(10), "{elapsed:?}");
+}
+
+#[tokio::test]
+async fn retry_after_header_is_capped() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::json(429, "{}").header("retry-after", "86400"),
+        MockResponse::json(200, MODEL_LIST),
+    ])
+    .await;
+    let client = server
+        .builder()
+        .retry_policy(fast_policy().with_max_retry_after(Duration::from_millis(50)))
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    client.list_models().await.unwrap();
+
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(50), "{elapsed:?}");
+    assert!(elapsed < Duration::from_secs(10), "{elapsed:?}");
+}
+
+#[tokio::test]
+async fn huge_retry_after_values_are_capped() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::json(429, "{}").header("retry-after-ms", "1e30"),
+        MockResponse::json(429, "{}").header("retry-after", "1e20"),
+        MockResponse::json(429, "{}").header("retry-after", "inf"),
+        MockResponse::json(429, "{}")
+            .header("retry-after", "Sun, 06 Nov 99999999999999999 08:49:37 GMT"),
+        MockResponse::json(200, MODEL_LIST),
+    ])
+    .await;
+    let client = server
+        .builder()
+        .retry_policy(
+            fast_policy()
+                .with_max_attempts(5)
+                .with_max_retry_after(Duration::from_millis(10)),
+        )
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    client.list_models().await.unwrap();
+
+    let elapsed = start.elapsed();
+    assert!(elapsed < Duration::from_secs(10), "{elapsed:?}");
+    assert_eq!(server.requests().len(), 5);
+}
+
+#[tokio::test]
+async fn retry_after_date_overrides_backoff() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::json(429, "{}").header("retry-after", "Sun, 06 Nov 1994 08:49:37 GMT"),
+        MockResponse::json(200, MODEL_LIST),
+    ])
+    .await;
+    let client = server
+        .builder()
+        .retry_policy(
+            RetryPolicy::default().with_delays(Duration::from_secs(60), Duration::from_secs(60)),
+        )
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    client.list_models().await.unwrap();
+
+    // The date is long past, so the request is retried right away
+    let elapsed = start.elapsed();
+    assert!(elapsed < Duration::from_secs(10), "{elapsed:?}");
+}
+
+#[tokio::test]
+async fn invalid_retry_after_falls_back_to_backoff() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::json(429, "{}").header("retry-after", "tomorrow"),
+        MockResponse::json(200, MODEL_LIST),
+    ])
+    .await;
+    let client = server
+        .builder()
+        .retry_policy(
+            RetryPolicy::default()
+                .with_delays(Duration::from_millis(100), Duration::from_millis(100))
+                .with_jitter(0.0),
+        )
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    client.list_models().await.unwrap();
+
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(100), "{elapsed:?}");
+}
+
+#[tokio::test]
+async fn insufficient_quota_is_not_retried() {
+    let server = MockServer::start(|_| {
+        MockResponse::json(
+            429,
+            r#"{"error":{"message":"You exceeded your current quota","type":"insufficient_quota","param":null,"code":"insufficient_quota"}}"#,
+        )
+    })
+    .await;
+    let client = server
+        .builder()
+        .retry_policy(fast_policy())
+        .build()
+        .unwrap();
+
+    let result = client.list_models().await;
+
+    assert_eq!(result.unwrap_err().status_code(), Some(429));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn timeouts_of_idempotent_requests_are_retried() {
+    let server = MockServer::with_responses(vec![
+        MockResponse::json(200, MODEL_LIST).delay(Duration::from_secs(5)),
+        MockResponse::json(200, MODEL_LIST),
+    ])
+    .await;
+    let client = server
+        .builder()
+        .request_timeout(Duration::from_millis(200))
+        .retry_policy(fast_policy())
+        .build()
+        .unwrap();
+
+    client.list_models().await.unwrap();
+
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn timeouts_of_other_requests_are_not_retried() {
+    let server =
+        MockServer::start(|_| MockResponse::json(200, EMBEDDINGS).delay(Duration::from_secs(5)))
+            .await;
+    let client = server
+        .builder()
+        .request_timeout(Duration::from_millis(200))
+        .retry_policy(fast_policy())
+        .build()
+        .unwrap();
+
+    let result = client
+        .create_embedding(embedding_request("text-embedding-3-small"))
+        .await;
+
+    assert!(result.unwrap_err().is_retryable());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn connection_errors_are_retried() {
+    // Reserve a port and close it again so that connecting to it fails
+    let address = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let client = conversa_openai_client::OpenAIClientBuilder::new(
+        format!("http://{address}"),
+        "test-key".to_string(),
+    )
+    .retry_policy(
+        RetryPolicy::default()
+            .with_max_attempts(3)
+            .with_delays(Duration::from_millis(100), Duration::from_millis(100))
+            .with_jitter(0.0),
+    )
+    .build()
+    .unwrap();
+
+    let start = Instant::now();
+    let result = client
+        .create_embedding(embedding_request("text-embedding-3-small"))
+        .await;
+
+    assert!(matches!(result, Err(ConversaError::Http(_))));
+    assert!(start.elapsed() >= Duration::from_millis(200));
+}
@@ -0,0 +1,452 @@
+mod common;
+
+use common::{MockResponse, MockServer, speech_request};
+use conversa_openai_client::{
+    ConversaError,
+    multipart::File,
+    types::{
+        AssistantStreamEvent, ChatCompletionRequestMessage, ChatCompletionRequestUserMessage,
+        ChatCompletionRequestUserMessageContent, ChatCompletionRequestUserMessageRole,
+        CreateChatCompletionRequest, CreateChatCompletionRequestObject,
+        CreateModelResponseProperties, CreateModelResponsePropertiesObject, CreateResponse,
+        CreateResponseObject, CreateResponseObjectInput, CreateSpeechResponseStreamEvent,
+        CreateTranscriptionRequest, CreateTranscriptionRequestModel,
+        CreateTranscriptionResponseStreamEvent, MessageStreamEvent, ModelIdsResponses,
+        ModelIdsShared, ModelResponseProperties, ResponseProperties, ResponseStreamEvent,
+        RunObjectStatus, RunStreamEvent, SubmitToolOutputsRunRequest,
+        SubmitToolOutputsRunRequestToolOutputs,
+    },
+};
+use futures::StreamExt;
+
+fn chat_request() -> CreateChatCompletionRequest {
+    CreateChatCompletionRequest {
+        create_model_response_properties: CreateModelResponseProperties {
+            model_response_properties: ModelResponseProperties {
+                metadata: None,
+                top_logprobs: None,
+                temperature: None,
+                top_p: None,
+                user: None,
+                service_tier: None,
+            },
+            object: CreateModelResponsePropertiesObject { top_logprobs: None },
+        },
+        object: CreateChatCompletionRequestObject {
+            messages: vec![
+                ChatCompletionRequestMessage::ChatCompletionRequestUserMessage(
+                    ChatCompletionRequestUserMessage {
+                        content: ChatCompletionRequestUserMessageContent::String(String::from(
+                            "What is the capital of France?",
+                        )),
+                        role: ChatCompletionRequestUserMessageRole::User,
+                        name: None,
+                    },
+                ),
+            ],
+            model: ModelIdsShared::String("gpt-4.1".to_string()),
+            modalities: None,
+            reasoning_effort: None,
+            max_completion_tokens: None,
+            frequency_penalty: None,
+            presence_penalty: None,
+            web_search_options: None,
+            top_logprobs: None,
+            response_format: None,
+            audio: None,
+            store: None,
+            stream: None,
+            stop: None,
+            logit_bias: None,
+            logprobs: None,
+            max_tokens: None,
+            n: None,
+            prediction: None,
+            seed: None,
+            stream_options: None,
+            tools: None,
+            tool_choice: None,
+            parallel_tool_calls: None,
+            function_call: None,
+            functions: None,
+        },
+    }
+}
+
+fn response_request() -> CreateResponse {
+    CreateResponse {
+        create_model_response_properties: CreateModelResponseProperties {
+            model_response_properties: ModelResponseProperties {
+                metadata: None,
+                top_logprobs: None,
+                temperature: None,
+                top_p: None,
+                user: None,
+                service_tier: None,
+            },
+            object: CreateModelResponsePropertiesObject { top_logprobs: None },
+        },
+        response_properties: ResponseProperties {
+            previous_response_id: None,
+            model: Some(ModelIdsResponses::ModelIdsShared(ModelIdsShared::String(
+                "gpt-4.1-nano".to_string(),
+            ))),
+            reasoning: None,
+            background: None,
+            max_output_tokens: None,
+            max_tool_calls: None,
+            text: None,
+            tools: None,
+            tool_choice: None,
+            prompt: None,
+            truncation: None,
+        },
+        object: CreateResponseObject {
+            input: Some(CreateResponseObjectInput::String(
+                "What is the capital of France?".to_string(),
+            )),
+            include: None,
+            parallel_tool_calls: None,
+            store: None,
+            instructions: None,
+            stream: None,
+        },
+    }
+}
+
+fn chat_chunk(content: &str) -> String {
+    format!(
+        r#"{{"id":"chatcmpl-1","object":"chat.completion.chunk","created":1752653808,"model":"gpt-4.1","choices":[{{"index":0,"delta":{{"content":"{content}"}},"finish_reason":null}}]}}"#
+    )
+}
+
+#[tokio::test]
+async fn chat_completion_stream_yields_chunks_until_done() {
+    let first = format!("data: {}\n\n", chat_chunk("The capital"));
+    let second = format!("data: {}\r\n\r\n", chat_chunk(" of France is Paris ✓"));
+    // Split in the middle of the multi-byte character
+    let (second_start, second_end) = second.as_bytes().split_at(second.find('✓').unwrap() + 1);
+    let mut response =
+        MockResponse::event_stream(&[": keep-alive\n\n", &first[..20], &first[20..]]);
+    response.body.push(second_start.to_vec());
+    response.body.push(second_end.to_vec());
+    response.body.push(b"data: [DONE]\n\n".to_vec());
+    response
+        .body
+        .push(format!("data: {}\n\n", chat_chunk("ignored")).into_bytes());
+    let server = MockServer::with_responses(vec![response]).await;
+
+    let stream = server
+        .client()
+        .create_chat_completion_stream(chat_request())
+        .await
+        .unwrap();
+    let chunks: Vec<_> = stream.collect().await;
+
+    let content: Vec<_> = chunks
+        .into_iter()
+        .map(|c| c.unwrap().choices[0].delta.content.clone().unwrap())
+        .collect();
+    assert_eq!(content, ["The capital", " of France is Paris ✓"]);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/chat/completions");
+    assert_eq!(requests[0].json()["stream"], true);
+}
+
+#[tokio::test]
+async fn chat_completion_stream_tolerates_content_type_variations() {
+    let body = format!("data: {}\n\ndata: [DONE]\n\n", chat_chunk("Paris"));
+    let server = MockServer::with_responses(vec![
+        MockResponse::new(200)
+            .header("content-type", "text/event-stream; charset=utf-8")
+            .body(body.as_bytes()),
+        MockResponse::new(200).body(body.as_bytes()),
+        MockResponse::json(200, &chat_chunk("Paris")),
+    ])
+    .await;
+    let client = server.client();
+
+    for _ in 0..2 {
+        let chunks: Vec<_> = client
+            .create_chat_completion_stream(chat_request())
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(chunks.len(), 1);
+    }
+
+    match client.create_chat_completion_stream(chat_request()).await {
+        Err(ConversaError::UnexpectedContentType(content_type)) => {
+            assert_eq!(content_type, "application/json")
+        }
+        _ => panic!("Unexpected result"),
+    }
+}
+
+#[tokio::test]
+async fn chat_completion_stream_returns_error_response() {
+    let server = MockServer::with_responses(vec![MockResponse::json(
+        400,
+        r#"{"error":{"message":"Invalid model","type":"invalid_request_error","param":"model","code":null}}"#,
+    )])
+    .await;
+
+    let result = server
+        .client()
+        .create_chat_completion_stream(chat_request())
+        .await;
+
+    match result {
+        Err(ConversaError::Api(e)) => {
+            assert_eq!(e.message, "Invalid model");
+            assert_eq!(e.status_code(), 400);
+        }
+        _ => panic!("Unexpected result"),
+    }
+}
+
+#[tokio::test]
+async fn chat_completion_stream_reports_error_event() {
+    let server = MockServer::with_responses(vec![MockResponse::event_stream(&[
+        &format!("data: {}\n\n", chat_chunk("The")),
+        "data: {\"error\":{\"message\":\"Server overloaded\",\"type\":\"server_error\",\"param\":null,\"code\":null}}\n\n",
+    ])])
+    .await;
+
+    let mut stream = server
+        .client()
+        .create_chat_completion_stream(chat_request())
+        .await
+        .unwrap();
+
+    assert!(stream.next().await.unwrap().is_ok());
+    match stream.next().await.unwrap() {
+        Err(ConversaError::Api(e)) => {
+            assert_eq!(e.message, "Server overloaded");
+            assert_eq!(e.r#type.as_deref(), Some("server_error"));
+        }
+        r => panic!("Unexpected item {r:?}"),
+    }
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn response_stream_uses_event_name_to_select_variant() {
+    let server = MockServer::with_responses(vec![MockResponse::event_stream(&[
+        "event: response.output_text.delta\ndata: {\"type\":\"response.output_text.delta\",\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"Paris\",\"sequence_number\":1}\n\n",
+        "data: {\"type\":\"response.output_text.done\",\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"text\":\"Paris\",\"sequence_number\":2}\n\n",
+    ])])
+    .await;
+
+    let events: Vec<_> = server
+        .client()
+        .create_response_stream(response_request())
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 2);
+    match &events[0] {
+        Ok(ResponseStreamEvent::ResponseTextDeltaEvent(e)) => assert_eq!(e.delta, "Paris"),
+        e => panic!("Unexpected event {e:?}"),
+    }
+    match &events[1] {
+        Ok(ResponseStreamEvent::ResponseTextDoneEvent(e)) => assert_eq!(e.text, "Paris"),
+        e => panic!("Unexpected event {e:?}"),
+    }
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/responses");
+    assert_eq!(requests[0].json()["stream"], true);
+}
+
+#[tokio::test]
+async fn response_stream_reports_error_event() {
+    let server = MockServer::with_responses(vec![MockResponse::event_stream(&[
+        "event: error\ndata: {\"type\":\"error\",\"code\":\"server_error\",\"message\":\"Something went wrong\",\"param\":null,\"sequence_number\":1}\n\n",
+    ])])
+    .await;
+
+    let mut stream = server
+        .client()
+        .create_response_stream(response_request())
+        .await
+        .unwrap();
+
+    match stream.next().await.unwrap() {
+        Err(ConversaError::Api(e)) => {
+            assert_eq!(e.code.as_deref(), Some("server_error"));
+            assert_eq!(e.message, "Something went wrong");
+        }
+        r => panic!("Unexpected item {r:?}"),
+    }
+}
+
+const RUN_OBJECT: &str = r#"{"id":"run_1","object":"thread.run","created_at":1752653808,"assistant_id":"asst_1","thread_id":"thread_1","status":"queued","started_at":null,"expires_at":1752654408,"cancelled_at":null,"failed_at":null,"completed_at":null,"required_action":null,"last_error":null,"model":"gpt-4.1","instructions":"","tools":[],"metadata":{},"incomplete_details":null,"usage":null,"temperature":1.0,"top_p":1.0,"max_prompt_tokens":null,"max_completion_tokens":null,"truncation_strategy":{"type":"auto","last_messages":null},"response_format":"auto","tool_choice":"auto","parallel_tool_calls":true}"#;
+
+#[tokio::test]
+async fn assistant_run_stream_yields_events() {
+    let server = MockServer::with_responses(vec![MockResponse::event_stream(&[
+        &format!("event: thread.run.queued\ndata: {RUN_OBJECT}\n\n"),
+        &format!(
+            "event: thread.run.requires_action\ndata: {}\n\n",
+            RUN_OBJECT.replace(r#""status":"queued""#, r#""status":"requires_action""#)
+        ),
+        "event: thread.message.delta\ndata: {\"id\":\"msg_1\",\"object\":\"thread.message.delta\",\"delta\":{\"content\":[{\"index\":0,\"type\":\"text\",\"text\":{\"value\":\"Paris\"}}]}}\n\n",
+        "event: done\ndata: [DONE]\n\n",
+    ])])
+    .await;
+
+    let request_body = SubmitToolOutputsRunRequest {
+        tool_outputs: vec![SubmitToolOutputsRunRequestToolOutputs {
+            tool_call_id: Some("call_1".to_string()),
+            output: Some("Paris".to_string()),
+        }],
+        stream: None,
+    };
+    let events: Vec<_> = server
+        .client()
+        .submit_tool_ouputs_to_run_stream("thread_1", "run_1", request_body)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 3);
+    match &events[0] {
+        Ok(AssistantStreamEvent::RunStreamEvent(RunStreamEvent::ThreadRunQueued(run))) => {
+            assert_eq!(run.status, RunObjectStatus::Queued);
+        }
+        e => panic!("Unexpected event {e:?}"),
+    }
+    match &events[1] {
+        Ok(AssistantStreamEvent::RunStreamEvent(RunStreamEvent::ThreadRunRequiresAction(run))) => {
+            assert_eq!(run.status, RunObjectStatus::RequiresAction);
+        }
+        e => panic!("Unexpected event {e:?}"),
+    }
+    match &events[2] {
+        Ok(AssistantStreamEvent::MessageStreamEvent(MessageStreamEvent::ThreadMessageDelta(
+            delta,
+        ))) => {
+            assert_eq!(delta.id, "msg_1");
+        }
+        e => panic!("Unexpected event {e:?}"),
+    }
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].path,
+        "/threads/thread_1/runs/run_1/submit_tool_outputs"
+    );
+    assert_eq!(requests[0].json()["stream"], true);
+    assert_eq!(requests[0].header("openai-beta"), Some("assistants=v2"));
+}
+
+#[tokio::test]
+async fn speech_audio_stream_writes_chunks() {
+    let mut response = MockResponse::new(200).header("content-type", "audio/mpeg");
+    response.body = vec![vec![0xff, 0xfb, 0x90], vec![0x00, 0x01], vec![0x02]];
+    let server = MockServer::with_responses(vec![response]).await;
+
+    let stream = server
+        .client()
+        .create_speech_audio_stream(speech_request())
+        .await
+        .unwrap();
+    let mut audio = Vec::new();
+    let length = stream.write_to(&mut audio).await.unwrap();
+
+    assert_eq!(length, 6);
+    assert_eq!(audio, [0xff, 0xfb, 0x90, 0x00, 0x01, 0x02]);
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/audio/speech");
+    assert_eq!(requests[0].json()["stream_format"], "audio");
+}
+
+#[tokio::test]
+async fn speech_stream_yields_audio_events() {
+    let server = MockServer::with_responses(vec![MockResponse::event_stream(&[
+        "data: {\"type\":\"speech.audio.delta\",\"audio\":\"//uQAAAB\"}\n\n",
+        "data: {\"type\":\"speech.audio.done\",\"usage\":{\"input_tokens\":7,\"output_tokens\":20,\"total_tokens\":27}}\n\n",
+    ])])
+    .await;
+
+    let events: Vec<_> = server
+        .client()
+        .create_speech_stream(speech_request())
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 2);
+    match &events[0] {
+        Ok(CreateSpeechResponseStreamEvent::SpeechAudioDeltaEvent(e)) => {
+            assert_eq!(e.audio, "//uQAAAB")
+        }
+        e => panic!("Unexpected event {e:?}"),
+    }
+    match &events[1] {
+        Ok(CreateSpeechResponseStreamEvent::SpeechAudioDoneEvent(e)) => {
+            assert_eq!(e.usage.total_tokens, 27)
+        }
+        e => panic!("Unexpected event {e:?}"),
+    }
+    assert_eq!(server.requests()[0].json()["stream_format"], "sse");
+}
+
+#[tokio::test]
+async fn transcription_stream_yields_text_events() {
+    let server = MockServer::with_responses(vec![MockResponse::event_stream(&[
+        "data: {\"type\":\"transcript.text.delta\",\"delta\":\"Hola\"}\n\n",
+        "data: {\"type\":\"transcript.text.delta\",\"delta\":\" mundo\"}\n\n",
+        "data: {\"type\":\"transcript.text.done\",\"text\":\"Hola mundo\"}\n\n",
+    ])])
+    .await;
+
+    let request_body = CreateTranscriptionRequest {
+        file: File {
+            file_name: "hola.wav".to_string(),
+            file_data: vec![0; 16],
+        },
+        model: CreateTranscriptionRequestModel::String("gpt-4o-mini-transcribe".to_string()),
+        language: None,
+        prompt: None,
+        response_format: None,
+        temperature: None,
+        include: None,
+        timestamp_granularities: None,
+        stream: None,
+        chunking_strategy: None,
+    };
+    let events: Vec<_> = server
+        .client()
+        .create_transcription_stream(request_body)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 3);
+    match &events[1] {
+        Ok(CreateTranscriptionResponseStreamEvent::TranscriptTextDeltaEvent(e)) => {
+            assert_eq!(e.delta, " mundo")
+        }
+        e => panic!("Unexpected event {e:?}"),
+    }
+    match &events[2] {
+        Ok(CreateTranscriptionResponseStreamEvent::TranscriptTextDoneEvent(e)) => {
+            assert_eq!(e.text, "Hola mundo")
+        }
+        e => panic!("Unexpected event {e:?}"),
+    }
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/audio/transcriptions");
+    assert!(
+        requests[0]
+            .body_string()
+            .contains("name=\"stream\"\r\n\r\ntrue\r\n")
+    );
+}
@@ -0,0 +1,173 @@
+mod common;
+
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use common::{MockResponse, MockServer};
+use tracing::{
+    Event, Metadata, Subscriber,
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+};
+
+type SpanFields = HashMap<String, String>;
+
+/// Keeps the fields of the spans named `operation`, in the order they were opened.
+#[derive(Clone, Default)]
+struct SpanRecorder {
+    next_id: Arc<AtomicU64>,
+    spans: Arc<Mutex<Vec<(Id, SpanFields)>>>,
+}
+
+impl SpanRecorder {
+    fn spans(&self) -> Vec<SpanFields> {
+        self.spans
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, f)| f.clone())
+            .collect()
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut SpanFields);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{value:?}"));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+}
+
+impl Subscriber for SpanRecorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let id = Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1);
+        if span.metadata().name() == "operation" {
+            let mut fields = SpanFields::new();
+            span.record(&mut FieldVisitor(&mut fields));
+            self.spans.lock().unwrap().push((id.clone(), fields));
+        }
+        id
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        if let Some((_, fields)) = self
+            .spans
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|(id, _)| id == span)
+        {
+            values.record(&mut FieldVisitor(fields));
+        }
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[tokio::test]
+async fn operations_are_recorded_in_spans() {
+    let recorder = SpanRecorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+    let server = MockServer::with_responses(vec![
+        MockResponse::json(
+            200,
+            r#"{"object":"list","data":[{"id":"text-embedding-3-small","object":"embedding","embedding":[0.5],"index":0}],"model":"text-embedding-3-small","usage":{"prompt_tokens":5,"total_tokens":5}}"#,
+        )
+        .header("x-request-id", "req_1"),
+        MockResponse::json(
+            404,
+            r#"{"error":{"message":"Not found","type":"invalid_request_error","param":null,"code":null}}"#,
+        )
+        .header("x-request-id", "req_2"),
+    ])
+    .await;
+    let client = server.client();
+
+    client
+        .create_embedding(
+            serde_json::from_str(r#"{"input":"Paris","model":"text-embedding-3-small"}"#).unwrap(),
+        )
+        .await
+        .unwrap();
+    client.retrieve_model("gpt-4o").await.unwrap_err();
+
+    let spans = recorder.spans();
+    assert_eq!(spans.len(), 2);
+
+    let embedding = &spans[0];
+    assert_eq!(embedding["otel.name"], "embeddings text-embedding-3-small");
+    assert_eq!(embedding["otel.kind"], "client");
+    assert_eq!(embedding["openai.operation_id"], "createEmbedding");
+    assert_eq!(embedding["openai.request_id"], "req_1");
+    assert_eq!(embedding["http.request.method"], "POST");
+    assert_eq!(embedding["http.response.status_code"], "200");
+    assert_eq!(embedding["url.template"], "/embeddings");
+    assert_eq!(embedding["server.address"], "127.0.0.1");
+    assert_eq!(embedding["gen_ai.provider.name"], "openai");
+    assert_eq!(embedding["gen_ai.operation.name"], "embeddings");
+    assert_eq!(embedding["gen_ai.request.model"], "text-embedding-3-small");
+    assert_eq!(embedding["gen_ai.response.model"], "text-embedding-3-small");
+    assert_eq!(embedding["gen_ai.usage.input_tokens"], "5");
+    assert!(embedding.contains_key("latency_ms"));
+    assert!(!embedding.contains_key("error.type"));
+
+    let model = &spans[1];
+    assert_eq!(model["otel.name"], "GET /models/{model}");
+    assert_eq!(model["url.template"], "/models/{model}");
+    assert_eq!(model["http.response.status_code"], "404");
+    assert_eq!(model["openai.request_id"], "req_2");
+    assert_eq!(model["error.type"], "404");
+    assert_eq!(model["otel.status_code"], "ERROR");
+    assert!(!model.contains_key("gen_ai.operation.name"));
+}
+
+#[tokio::test]
+async fn chat_completion_usage_is_recorded() {
+    let recorder = SpanRecorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+    let server = MockServer::with_responses(vec![MockResponse::json(
+        200,
+        r#"{"id":"chatcmpl-1","object":"chat.completion","created":1752653808,"model":"gpt-4o-2024-08-06","choices":[{"index":0,"message":{"role":"assistant","content":"Paris","refusal":null,"annotations":[]},"logprobs":null,"finish_reason":"stop"}],"usage":{"prompt_tokens":12,"completion_tokens":3,"total_tokens":15}}"#,
+    )])
+    .await;
+
+    server
+        .client()
+        .create_chat_completion(
+            serde_json::from_str(
+                r#"{"model":"gpt-4o","messages":[{"role":"user","content":"Capital of France?"}]}"#,
+            )
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let span = &recorder.spans()[0];
+    assert_eq!(span["otel.name"], "chat gpt-4o");
+    assert_eq!(span["url.template"], "/chat/completions");
+    assert_eq!(span["gen_ai.response.id"], "chatcmpl-1");
+    assert_eq!(span["gen_ai.response.model"], "gpt-4o-2024-08-06");
+    assert_eq!(span["gen_ai.response.finish_reasons"], r#"["stop"]"#);
+    assert_eq!(span["gen_ai.usage.input_tokens"], "12");
+    assert_eq!(span["gen_ai.usage.output_tokens"], "3");
+}
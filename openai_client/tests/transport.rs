@@ -0,0 +1,320 @@
+mod common;
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use common::MODEL_LIST;
+use conversa_openai_client::{
+    ConversaError, ConversaResult, OpenAIClient, OpenAIClientBuilder,
+    interceptor::Interceptor,
+    multipart::File,
+    retry::RetryPolicy,
+    transport::{
+        HttpRequest, HttpResponse, RequestBody, Transport, TransportError, TransportErrorKind,
+    },
+    types::{
+        CreateFileRequest, CreateFileRequestPurpose, CreateModerationRequest,
+        CreateModerationRequestInput,
+    },
+};
+use futures::future::BoxFuture;
+use reqwest::{Method, StatusCode, header::HeaderMap};
+use serde::{Serialize, Serializer, ser::Error};
+
+const FILE_OBJECT: &str = r#"{"id":"file-1","object":"file","bytes":10,"created_at":1752653808,"filename":"train.jsonl","purpose":"fine-tune","status":"processed"}"#;
+
+/// What the [`FakeTransport`] answers to a request.
+enum FakeResponse {
+    Chunks(u16, Vec<ConversaResult<Bytes>>),
+    Error(TransportErrorKind),
+}
+
+/// A transport which records the requests and answers with canned responses, without any
+/// network involved.
+#[derive(Clone, Default)]
+struct FakeTransport {
+    responses: Arc<Mutex<VecDeque<FakeResponse>>>,
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+impl FakeTransport {
+    fn with_responses(responses: Vec<FakeResponse>) -> Self {
+        Self {
+            responses: Arc::new(Mutex::new(responses.into())),
+            ..Default::default()
+        }
+    }
+
+    fn client(&self) -> OpenAIClient {
+        OpenAIClientBuilder::new(
+            "https://api.example.com/v1".to_string(),
+            "test-key".to_string(),
+        )
+        .transport(self.clone())
+        .build()
+        .unwrap()
+    }
+
+    fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Transport for FakeTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, ConversaResult<HttpResponse>> {
+        self.requests.lock().unwrap().push(request);
+        let response = self.responses.lock().unwrap().pop_front().unwrap();
+        Box::pin(async move {
+            match response {
+                FakeResponse::Chunks(status, chunks) => {
+                    let mut headers = HeaderMap::new();
+                    headers.insert("content-type", "application/json".parse().unwrap());
+                    Ok(HttpResponse {
+                        status: StatusCode::from_u16(status).unwrap(),
+                        headers,
+                        body: Box::pin(futures::stream::iter(chunks)),
+                    })
+                }
+                FakeResponse::Error(kind) => Err(TransportError::new(kind, "fake failure").into()),
+            }
+        })
+    }
+}
+
+/// A query value whose serialization always fails.
+struct Unserializable;
+
+impl Serialize for Unserializable {
+    fn serialize<S: Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(S::Error::custom("not serializable"))
+    }
+}
+
+/// Adds a query parameter which can not be serialized to every request.
+struct UnserializableQuery;
+
+impl Interceptor for UnserializableQuery {
+    fn before_send(&self, request: &mut HttpRequest) -> ConversaResult<Option<HttpResponse>> {
+        *request = request.clone().query(&[("tenant", Unserializable)])?;
+        Ok(None)
+    }
+}
+
+fn json(status: u16, body: &'static str) -> FakeResponse {
+    FakeResponse::Chunks(status, vec![Ok(Bytes::from_static(body.as_bytes()))])
+}
+
+fn file_request() -> CreateFileRequest {
+    CreateFileRequest {
+        file: File {
+            file_name: "train.jsonl".to_string(),
+            file_data: b"{}\n".to_vec(),
+        },
+        purpose: CreateFileRequestPurpose::FineTune,
+    }
+}
+
+#[tokio::test]
+async fn requests_are_described_to_the_transport() {
+    let transport = FakeTransport::with_responses(vec![json(200, MODEL_LIST)]);
+    let client = OpenAIClientBuilder::new(
+        "https://api.example.com/v1".to_string(),
+        "test-key".to_string(),
+    )
+    .transport(transport.clone())
+    .organization("org-1")
+    .request_timeout(Duration::from_secs(5))
+    .build()
+    .unwrap();
+
+    client.list_models().await.unwrap();
+
+    let request = &transport.requests()[0];
+    assert_eq!(request.method, Method::GET);
+    assert_eq!(request.url, "https://api.example.com/v1/models");
+    assert_eq!(request.operation.id, "listModels");
+    assert_eq!(request.operation.name, "list_models");
+    assert_eq!(request.headers["authorization"], "Bearer test-key");
+    assert_eq!(request.headers["openai-organization"], "org-1");
+    assert_eq!(request.body, RequestBody::Empty);
+    assert_eq!(request.timeout, Some(Duration::from_secs(5)));
+}
+
+#[tokio::test]
+async fn query_parameters_and_json_bodies_are_passed_on() {
+    let transport = FakeTransport::with_responses(vec![
+        json(
+            200,
+            r#"{"object":"list","data":[],"first_id":"","last_id":"","has_more":false}"#,
+        ),
+        json(
+            200,
+            r#"{"id":"modr-1","model":"omni-moderation-latest","results":[]}"#,
+        ),
+    ]);
+    let client = transport.client();
+
+    client
+        .list_files(Some("fine-tune"), Some(10), None, None)
+        .await
+        .unwrap();
+    client
+        .create_moderation(CreateModerationRequest {
+            input: CreateModerationRequestInput::String("Paris".to_string()),
+            model: None,
+        })
+        .await
+        .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(
+        requests[0].query,
+        vec![
+            ("purpose".to_string(), "fine-tune".to_string()),
+            ("limit".to_string(), "10".to_string())
+        ]
+    );
+    assert_eq!(requests[1].method, Method::POST);
+    assert_eq!(requests[1].headers["content-type"], "application/json");
+    assert_eq!(
+        requests[1].body,
+        RequestBody::Json(Bytes::from_static(br#"{"input":"Paris"}"#))
+    );
+}
+
+#[tokio::test]
+async fn multipart_forms_are_passed_as_parts() {
+    let transport = FakeTransport::with_responses(vec![json(200, FILE_OBJECT)]);
+
+    transport
+        .client()
+        .create_file(file_request())
+        .await
+        .unwrap();
+
+    let request = &transport.requests()[0];
+    let RequestBody::Multipart(form) = &request.body else {
+        panic!("Unexpected body {:?}", request.body);
+    };
+    let parts = form.parts();
+    assert_eq!(parts[0].0, "purpose");
+    assert_eq!(parts[0].1.data, "fine-tune");
+    assert_eq!(parts[1].0, "file");
+    assert_eq!(parts[1].1.file_name.as_deref(), Some("train.jsonl"));
+    assert_eq!(parts[1].1.content_type.as_deref(), Some("text/jsonl"));
+    assert_eq!(parts[1].1.data, "{}\n");
+}
+
+#[tokio::test]
+async fn response_bodies_are_read_from_the_chunks() {
+    let (first, second) = MODEL_LIST.split_at(10);
+    let transport = FakeTransport::with_responses(vec![
+        FakeResponse::Chunks(
+            200,
+            vec![
+                Ok(Bytes::from_static(first.as_bytes())),
+                Ok(Bytes::from_static(second.as_bytes())),
+            ],
+        ),
+        FakeResponse::Chunks(
+            200,
+            vec![
+                Ok(Bytes::from_static(first.as_bytes())),
+                Err(TransportError::new(TransportErrorKind::Other, "connection reset").into()),
+            ],
+        ),
+        json(
+            404,
+            r#"{"error":{"message":"Not found","type":"invalid_request_error","param":null,"code":null}}"#,
+        ),
+    ]);
+    let client = transport.client();
+
+    assert!(client.list_models().await.unwrap().data.is_empty());
+    assert!(matches!(
+        client.list_models().await,
+        Err(ConversaError::Transport(_))
+    ));
+    let error = client.list_models().await.unwrap_err();
+    assert_eq!(error.status_code(), Some(404));
+    assert_eq!(error.api_error().unwrap().message, "Not found");
+}
+
+#[tokio::test]
+async fn transport_errors_are_classified() {
+    let transport = FakeTransport::with_responses(vec![
+        FakeResponse::Error(TransportErrorKind::Timeout),
+        FakeResponse::Error(TransportErrorKind::Other),
+    ]);
+    let client = transport.client();
+
+    let timeout = client.list_models().await.unwrap_err();
+    assert!(timeout.is_retryable());
+    assert_eq!(timeout.to_string(), "HTTP error: fake failure");
+
+    let other = client.list_models().await.unwrap_err();
+    assert!(!other.is_retryable());
+}
+
+#[tokio::test]
+async fn multipart_requests_are_retried_after_connection_errors() {
+    let transport = FakeTransport::with_responses(vec![
+        FakeResponse::Error(TransportErrorKind::Connect),
+        json(200, FILE_OBJECT),
+    ]);
+    let client = OpenAIClientBuilder::new(
+        "https://api.example.com/v1".to_string(),
+        "test-key".to_string(),
+    )
+    .transport(transport.clone())
+    .retry_policy(
+        RetryPolicy::default().with_delays(Duration::from_millis(1), Duration::from_millis(1)),
+    )
+    .build()
+    .unwrap();
+
+    let file = client.create_file(file_request()).await.unwrap();
+
+    assert_eq!(file.id, "file-1");
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].body, requests[1].body);
+}
+
+#[tokio::test]
+async fn transport_can_not_be_combined_with_an_http_client() {
+    let result = OpenAIClientBuilder::new("http://localhost".to_string(), "test-key".to_string())
+        .transport(FakeTransport::default())
+        .http_client(reqwest::Client::new())
+        .build();
+    assert!(matches!(result, Err(ConversaError::ClientError(_))));
+
+    let result = OpenAIClientBuilder::new("http://localhost".to_string(), "test-key".to_string())
+        .transport(FakeTransport::default())
+        .connect_timeout(Duration::from_secs(1))
+        .build();
+    assert!(matches!(result, Err(ConversaError::ClientError(_))));
+}
+
+#[tokio::test]
+async fn query_values_failing_to_serialize_are_errors() {
+    let transport = FakeTransport::with_responses(vec![json(200, MODEL_LIST)]);
+    let client = OpenAIClientBuilder::new(
+        "https://api.example.com/v1".to_string(),
+        "test-key".to_string(),
+    )
+    .transport(transport.clone())
+    .interceptor(UnserializableQuery)
+    .build()
+    .unwrap();
+
+    assert!(matches!(
+        client.list_models().await,
+        Err(ConversaError::Json(_))
+    ));
+    assert!(transport.requests().is_empty());
+}
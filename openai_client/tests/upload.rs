@@ -0,0 +1,161 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use conversa_openai_client::{
+    ConversaError,
+    types::{CreateUploadRequestPurpose, OpenAIFileStatus},
+    upload::{MAX_PART_SIZE, UploadOptions},
+};
+
+const FILE_OBJECT: &str = r#"{"id":"file-1","object":"file","bytes":10,"created_at":1752653808,"filename":"train.jsonl","purpose":"fine-tune","status":"processed"}"#;
+
+fn upload_object(status: &str, file: &str) -> String {
+    format!(
+        r#"{{"id":"upload_1","object":"upload","bytes":10,"created_at":1752653808,"filename":"train.jsonl","purpose":"fine-tune","status":"{status}","expires_at":1752657408,"file":{file}}}"#
+    )
+}
+
+async fn upload_server() -> MockServer {
+    MockServer::start(|request| match request.path.as_str() {
+        "/uploads" => MockResponse::json(200, &upload_object("pending", "null")),
+        "/uploads/upload_1/parts" => {
+            // The part id is derived from the content to check the order of the ids
+            let data = String::from_utf8(request.form_parts().remove(0).data).unwrap();
+            MockResponse::json(
+                200,
+                &format!(
+                    r#"{{"id":"part_{data}","object":"upload.part","created_at":1752653808,"upload_id":"upload_1"}}"#
+                ),
+            )
+        }
+        "/uploads/upload_1/complete" => {
+            MockResponse::json(200, &upload_object("completed", FILE_OBJECT))
+        }
+        path => panic!("Unexpected path {path}"),
+    })
+    .await
+}
+
+#[tokio::test]
+async fn upload_reader_sends_parts_and_completes() {
+    let server = upload_server().await;
+    let data = b"aaaabbbbcc";
+
+    let file = server
+        .client()
+        .upload_reader(
+            &data[..],
+            "train.jsonl".to_string(),
+            data.len() as u64,
+            "text/jsonl".to_string(),
+            CreateUploadRequestPurpose::FineTune,
+            UploadOptions {
+                part_size: 4,
+                concurrency: 3,
+                md5: Some("d41d8cd98f00b204e9800998ecf8427e".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(file.id, "file-1");
+    assert_eq!(file.status, OpenAIFileStatus::Processed);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/uploads");
+    assert_eq!(requests[0].json()["bytes"], 10);
+    assert_eq!(requests[0].json()["mime_type"], "text/jsonl");
+    assert_eq!(requests[0].json()["purpose"], "fine-tune");
+    assert_eq!(
+        requests
+            .iter()
+            .filter(|r| r.path == "/uploads/upload_1/parts")
+            .count(),
+        3
+    );
+
+    let complete = requests.last().unwrap();
+    assert_eq!(complete.path, "/uploads/upload_1/complete");
+    assert_eq!(
+        complete.json()["part_ids"],
+        serde_json::json!(["part_aaaa", "part_bbbb", "part_cc"])
+    );
+    assert_eq!(complete.json()["md5"], "d41d8cd98f00b204e9800998ecf8427e");
+}
+
+#[tokio::test]
+async fn upload_file_uses_file_size_and_mime_type() {
+    let server = upload_server().await;
+    let path = std::env::temp_dir().join(format!("conversa-upload-{}.jsonl", std::process::id()));
+    std::fs::write(&path, b"aaaabbbbcc").unwrap();
+
+    let result = server
+        .client()
+        .upload_file(
+            &path,
+            CreateUploadRequestPurpose::FineTune,
+            UploadOptions::default(),
+        )
+        .await;
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(result.unwrap().id, "file-1");
+    let requests = server.requests();
+    assert_eq!(requests[0].json()["bytes"], 10);
+    assert_eq!(requests[0].json()["mime_type"], "text/jsonl");
+    assert_eq!(requests.len(), 3);
+}
+
+#[tokio::test]
+async fn upload_is_cancelled_when_reader_is_short() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/uploads" => MockResponse::json(200, &upload_object("pending", "null")),
+        "/uploads/upload_1/cancel" => MockResponse::json(200, &upload_object("cancelled", "null")),
+        path => panic!("Unexpected path {path}"),
+    })
+    .await;
+
+    let result = server
+        .client()
+        .upload_reader(
+            &b"abc"[..],
+            "data.bin".to_string(),
+            10,
+            "application/octet-stream".to_string(),
+            CreateUploadRequestPurpose::Batch,
+            UploadOptions {
+                part_size: 8,
+                ..Default::default()
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(ConversaError::InvalidData(_))));
+    assert_eq!(
+        server.requests().last().unwrap().path,
+        "/uploads/upload_1/cancel"
+    );
+}
+
+#[tokio::test]
+async fn upload_rejects_oversized_parts() {
+    let server = MockServer::with_responses(vec![]).await;
+
+    let result = server
+        .client()
+        .upload_reader(
+            &b""[..],
+            "data.bin".to_string(),
+            0,
+            "application/octet-stream".to_string(),
+            CreateUploadRequestPurpose::Batch,
+            UploadOptions {
+                part_size: MAX_PART_SIZE + 1,
+                ..Default::default()
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(ConversaError::ClientError(_))));
+    assert!(server.requests().is_empty());
+}